@@ -0,0 +1,204 @@
+// ============================================
+// i18n-wrapper: baseline (check --baseline / --write-baseline)
+// 레거시 코드의 기존 발견 항목을 파일에 기록해두고,
+// 이후 check에서는 baseline에 없는 새 항목만 실패로 처리
+// 줄 번호 대신 Diagnostic::fingerprint(파일 + 컴포넌트 + 문자열 해시)로 비교하므로
+// 위에 코드가 추가되어 줄이 밀려도 같은 항목으로 인식
+//
+// {
+//   "version": 1,
+//   "findings": [
+//     { "fingerprint": "1a2b...", "code": "I18N100", "path": "src/App.tsx", "message": "...", "count": 2 }
+//   ]
+// }
+// ============================================
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::diagnostic::Diagnostic;
+use crate::json::{self, Value};
+
+pub const BASELINE_VERSION: usize = 1;
+pub const DEFAULT_BASELINE: &str = "i18n-baseline.json";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub fingerprint: String,
+    pub code: String,
+    pub path: String,
+    pub message: String,
+    // 같은 컴포넌트에 같은 문자열이 여러 번 나올 수 있음
+    pub count: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct Baseline {
+    pub entries: Vec<Entry>,
+}
+
+// check 결과를 baseline과 비교한 결과
+pub struct Comparison {
+    // baseline에 없는 항목 (실패 대상)
+    pub new: Vec<Diagnostic>,
+    // baseline에 있지만 이제 없어진 항목 (count는 줄어든 개수)
+    pub fixed: Vec<Entry>,
+    // baseline 덕분에 통과한 항목 수
+    pub known: usize,
+}
+
+impl Baseline {
+    // fingerprint가 없는 진단(도구 에러 등)은 기록하지 않음
+    // 경로는 relative_path로 프로젝트 루트 기준 상대 경로로 바꿔 저장 (다른 체크아웃/CI에서도 같은 파일)
+    pub fn from_findings(findings: &[Diagnostic], relative_path: impl Fn(&Path) -> String) -> Self {
+        let mut entries: Vec<Entry> = Vec::new();
+        for finding in findings {
+            let fingerprint = match &finding.fingerprint {
+                Some(fingerprint) => fingerprint,
+                None => continue,
+            };
+            match entries.iter_mut().find(|e| e.fingerprint == *fingerprint) {
+                Some(entry) => entry.count += 1,
+                None => entries.push(Entry {
+                    fingerprint: fingerprint.clone(),
+                    code: finding.code.clone(),
+                    path: relative_path(&finding.path),
+                    message: finding.message.clone(),
+                    count: 1,
+                }),
+            }
+        }
+        // 파일 단위로 모아서 저장 (리뷰할 때 diff가 읽기 쉽도록)
+        entries.sort_by(|a, b| (&a.path, &a.code, &a.fingerprint).cmp(&(&b.path, &b.code, &b.fingerprint)));
+        Self { entries }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let root = json::parse(&text).map_err(|e| invalid(format!("{}:{}", path.display(), e)))?;
+        let version = root.get("version").and_then(Value::as_u64);
+        if version != Some(BASELINE_VERSION as u64) {
+            return Err(invalid(format!("{}: 지원하지 않는 baseline 버전 {:?}", path.display(), version)));
+        }
+        let findings = root
+            .get("findings")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid(format!("{}: findings 배열이 없습니다", path.display())))?;
+        let entries = findings
+            .iter()
+            .map(|item| {
+                let field = |key: &str| item.get(key).and_then(Value::as_str).map(String::from);
+                Some(Entry {
+                    fingerprint: field("fingerprint")?,
+                    code: field("code")?,
+                    path: field("path").unwrap_or_default(),
+                    message: field("message").unwrap_or_default(),
+                    count: item.get("count").and_then(Value::as_u64).unwrap_or(1) as usize,
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid(format!("{}: 잘못된 항목이 있습니다", path.display())))?;
+        Ok(Self { entries })
+    }
+
+    pub fn to_json(&self) -> Value {
+        let findings = self
+            .entries
+            .iter()
+            .map(|entry| {
+                Value::Object(vec![
+                    ("fingerprint".to_string(), entry.fingerprint.as_str().into()),
+                    ("code".to_string(), entry.code.as_str().into()),
+                    ("path".to_string(), entry.path.as_str().into()),
+                    ("message".to_string(), entry.message.as_str().into()),
+                    ("count".to_string(), entry.count.into()),
+                ])
+            })
+            .collect();
+        Value::Object(vec![
+            ("version".to_string(), BASELINE_VERSION.into()),
+            ("findings".to_string(), Value::Array(findings)),
+        ])
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut text = self.to_json().to_pretty_string("  ");
+        text.push('\n');
+        crate::process::write_file(path, &text)
+    }
+
+    pub fn compare(&self, findings: Vec<Diagnostic>) -> Comparison {
+        let mut remaining: Vec<(String, usize)> =
+            self.entries.iter().map(|e| (e.fingerprint.clone(), e.count)).collect();
+        let mut new = Vec::new();
+        let mut known = 0;
+        for finding in findings {
+            let slot = finding
+                .fingerprint
+                .as_ref()
+                .and_then(|fingerprint| remaining.iter_mut().find(|(f, count)| f == fingerprint && *count > 0));
+            match slot {
+                Some((_, count)) => {
+                    *count -= 1;
+                    known += 1;
+                }
+                None => new.push(finding),
+            }
+        }
+        let fixed = self
+            .entries
+            .iter()
+            .zip(&remaining)
+            .filter(|(_, (_, count))| *count > 0)
+            .map(|(entry, (_, count))| Entry {
+                count: *count,
+                ..entry.clone()
+            })
+            .collect();
+        Comparison { new, fixed, known }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic;
+
+    fn finding(component: &str, text: &str) -> Diagnostic {
+        Diagnostic::error(diagnostic::HARDCODED_STRING, format!("번역되지 않은 문자열 {:?}", text), Path::new("src/App.tsx"))
+            .with_fingerprint(&["src/App.tsx", component, text])
+    }
+
+    #[test]
+    fn test_compare_reports_new_and_fixed() {
+        let findings = [finding("App", "Hello"), finding("App", "Hello"), finding("App", "Bye")];
+        let baseline = Baseline::from_findings(&findings, |path| path.to_string_lossy().to_string());
+        assert_eq!(baseline.entries.len(), 2);
+
+        // 줄 위치와 무관: 같은 컴포넌트/문자열이면 기존 항목
+        let current = vec![finding("App", "Hello"), finding("App", "Welcome"), finding("Footer", "Bye")];
+        let comparison = baseline.compare(current);
+        let new: Vec<&str> = comparison.new.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(new, vec!["번역되지 않은 문자열 \"Welcome\"", "번역되지 않은 문자열 \"Bye\""]);
+        assert_eq!(comparison.known, 1);
+        let fixed: Vec<(&str, usize)> = comparison.fixed.iter().map(|e| (e.message.as_str(), e.count)).collect();
+        assert_eq!(fixed.len(), 2);
+        assert!(fixed.contains(&("번역되지 않은 문자열 \"Hello\"", 1)));
+        assert!(fixed.contains(&("번역되지 않은 문자열 \"Bye\"", 1)));
+
+        let reloaded = json::parse(&baseline.to_json().to_compact_string()).unwrap();
+        assert_eq!(reloaded.get("findings").and_then(Value::as_array).map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn test_entries_store_relative_paths() {
+        let absolute = Diagnostic::error(diagnostic::HARDCODED_STRING, "a", Path::new("/work/app/src/App.tsx"))
+            .with_fingerprint(&["src/App.tsx", "App", "Hello"]);
+        let baseline = Baseline::from_findings(&[absolute], |path| {
+            path.strip_prefix("/work/app").unwrap_or(path).to_string_lossy().to_string()
+        });
+        assert_eq!(baseline.entries[0].path, "src/App.tsx");
+    }
+}
@@ -0,0 +1,386 @@
+// ============================================
+// i18n-wrapper: 증분 캐시 (.i18n-wrapper/cache)
+// 파일마다 분석 결과를 JSON으로 저장하고, 다음 실행에서
+// 내용 해시 + 설정 해시 + 도구 버전이 같으면 파싱을 건너뜀
+// 상대 경로로 import한 파일의 해시도 저장해서, 그 파일이 바뀌면 무효화
+// (A -> B -> C처럼 간접 import도 포함: C가 바뀌면 A도 무효)
+// 의존 파일은 한 실행에서 한 번만 읽고 해시해서 모든 항목이 같이 씀
+// ============================================
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::glob;
+use crate::json::{self, Value};
+use crate::transform::{self, FileAnalysis, HardcodedString, StringKind};
+
+pub const CACHE_DIR: &str = ".i18n-wrapper/cache";
+pub const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// import 경로 해석 시 시도할 확장자 (TypeScript moduleResolution과 비슷)
+const RESOLVE_EXTENSIONS: [&str; 4] = ["ts", "tsx", "js", "jsx"];
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    // 설정이 바뀌면 모든 항목이 무효
+    config_hash: String,
+    // 이번 실행에서 읽은 파일 (읽을 수 없으면 None), 복제본끼리 공유 (pipeline의 병렬 작업)
+    // 실행 중에는 파일이 바뀌지 않는다고 보고, 여러 번 실행하는 watch는 실행마다 fresh()
+    files: Arc<Mutex<HashMap<PathBuf, Option<FileInfo>>>>,
+}
+
+#[derive(Debug, Clone)]
+struct FileInfo {
+    hash: String,
+    // 상대 경로로 import한 파일 (해석된 경로)
+    imports: Vec<PathBuf>,
+}
+
+impl Cache {
+    pub fn new(dir: &Path, config_hash: u64) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            config_hash: format!("{:016x}", config_hash),
+            files: Arc::default(),
+        }
+    }
+
+    // 같은 캐시 디렉토리/설정, 기억한 파일 해시는 비움
+    pub fn fresh(&self) -> Self {
+        Self {
+            dir: self.dir.clone(),
+            config_hash: self.config_hash.clone(),
+            files: Arc::default(),
+        }
+    }
+
+    fn entry_path(&self, path: &Path) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", transform::text_hash(&path.to_string_lossy())))
+    }
+
+    // 유효한 항목이 있을 때만 Some
+    pub fn lookup(&self, path: &Path, code: &str) -> Option<FileAnalysis> {
+        let text = fs::read_to_string(self.entry_path(path)).ok()?;
+        let entry = json::parse(&text).ok()?;
+        let matches = |key: &str, expected: &str| entry.get(key).and_then(Value::as_str) == Some(expected);
+        if !matches("version", TOOL_VERSION)
+            || !matches("config", &self.config_hash)
+            || !matches("path", &path.to_string_lossy())
+            || !matches("content", &hash_hex(code))
+        {
+            return None;
+        }
+        for dep in entry.get("dependencies")?.as_array()? {
+            let current = self.file_info(Path::new(dep.get("path")?.as_str()?))?;
+            if dep.get("content")?.as_str()? != current.hash {
+                return None;
+            }
+        }
+        analysis_from_json(entry.get("analysis")?)
+    }
+
+    pub fn store(&self, path: &Path, code: &str, analysis: &FileAnalysis) -> io::Result<()> {
+        let dependencies = self
+            .dependency_closure(path, &analysis.imports)
+            .into_iter()
+            .map(|(dep, hash)| {
+                Value::Object(vec![
+                    ("path".to_string(), dep.to_string_lossy().as_ref().into()),
+                    ("content".to_string(), hash.into()),
+                ])
+            })
+            .collect();
+        let entry = Value::Object(vec![
+            ("version".to_string(), TOOL_VERSION.into()),
+            ("config".to_string(), self.config_hash.as_str().into()),
+            ("path".to_string(), path.to_string_lossy().as_ref().into()),
+            ("content".to_string(), hash_hex(code).into()),
+            ("dependencies".to_string(), Value::Array(dependencies)),
+            ("analysis".to_string(), analysis_to_json(analysis)),
+        ]);
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.entry_path(path), entry.to_compact_string())
+    }
+
+    // 파일마다 한 번만 읽어서 해시 + 상대 import를 기억
+    // 의존 파일은 다시 파싱하지 않고 상대 경로 문자열만 훑음 (많이 잡아도 캐시가 조금 더 자주 무효화될 뿐)
+    fn file_info(&self, path: &Path) -> Option<FileInfo> {
+        if let Some(info) = self.files.lock().unwrap_or_else(PoisonError::into_inner).get(path) {
+            return info.clone();
+        }
+        let info = fs::read_to_string(path).ok().map(|content| FileInfo {
+            hash: hash_hex(&content),
+            imports: resolve_imports(path, &relative_specifiers(&content)),
+        });
+        self.files
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.to_path_buf(), info.clone());
+        info
+    }
+
+    // 직접 import한 파일에서 시작해 그 파일들이 import한 파일까지 (파일 경로, 내용 해시)
+    fn dependency_closure(&self, path: &Path, imports: &[String]) -> Vec<(PathBuf, String)> {
+        let mut seen = HashSet::from([glob::normalize(path)]);
+        let mut pending = resolve_imports(path, imports);
+        let mut closure = Vec::new();
+        while let Some(dep) = pending.pop() {
+            if !seen.insert(dep.clone()) {
+                continue;
+            }
+            let info = match self.file_info(&dep) {
+                Some(info) => info,
+                None => continue,
+            };
+            pending.extend(info.imports);
+            closure.push((dep, info.hash));
+        }
+        closure
+    }
+}
+
+fn hash_hex(text: &str) -> String {
+    format!("{:016x}", transform::text_hash(text))
+}
+
+// 따옴표로 감싼 "./" "../" 문자열 (import/export/require/import() 구분 없이)
+fn relative_specifiers(code: &str) -> Vec<String> {
+    let mut specifiers = Vec::new();
+    let mut rest = code;
+    while let Some(start) = rest.find(['\'', '"']) {
+        let quote = rest[start..].chars().next().unwrap_or('"');
+        let after = &rest[start + 1..];
+        let end = match after.find([quote, '\n']) {
+            Some(end) => end,
+            None => break,
+        };
+        let literal = &after[..end];
+        if is_relative(literal) {
+            specifiers.push(literal.to_string());
+        }
+        rest = &after[end + 1..];
+    }
+    specifiers
+}
+
+// "./Button" -> Button.tsx / Button/index.ts 등 실제로 존재하는 파일
+// 패키지 import ("react")는 캐시 대상이 아님
+pub fn resolve_imports(path: &Path, imports: &[String]) -> Vec<PathBuf> {
+    let mut resolved = Vec::new();
+    for import in imports.iter().filter(|i| is_relative(i)) {
+        if let Some(found) = resolve_import(path, import) {
+            if !resolved.contains(&found) {
+                resolved.push(found);
+            }
+        }
+    }
+    resolved
+}
+
+pub fn is_relative(import: &str) -> bool {
+    import.starts_with("./") || import.starts_with("../")
+}
+
+pub fn resolve_import(path: &Path, import: &str) -> Option<PathBuf> {
+    let base = path.parent().unwrap_or(Path::new("")).join(import);
+    let candidates = std::iter::once(base.clone())
+        .chain(RESOLVE_EXTENSIONS.iter().map(|ext| PathBuf::from(format!("{}.{}", base.display(), ext))))
+        .chain(RESOLVE_EXTENSIONS.iter().map(|ext| base.join(format!("index.{}", ext))));
+    candidates.into_iter().find(|c| c.is_file()).map(|c| glob::normalize(&c))
+}
+
+// ============================================
+// FileAnalysis <-> JSON
+// ============================================
+fn analysis_to_json(analysis: &FileAnalysis) -> Value {
+    let strings = analysis
+        .strings
+        .iter()
+        .map(|s| {
+            Value::Object(vec![
+                ("text".to_string(), s.text.as_str().into()),
+                ("start".to_string(), s.start.into()),
+                ("end".to_string(), s.end.into()),
+                ("line".to_string(), s.line.into()),
+                ("column".to_string(), s.column.into()),
+                ("kind".to_string(), s.kind.name().into()),
+                ("attr".to_string(), s.kind.attribute().map(Value::from).unwrap_or(Value::Null)),
+                (
+                    "component".to_string(),
+                    s.component.as_deref().map(Value::from).unwrap_or(Value::Null),
+                ),
+                ("ignored".to_string(), s.ignored.into()),
+                ("ignoreAt".to_string(), s.ignore_at.into()),
+            ])
+        })
+        .collect();
+    Value::Object(vec![
+        ("path".to_string(), analysis.path.as_str().into()),
+        (
+            "imports".to_string(),
+            Value::Array(analysis.imports.iter().map(|i| i.as_str().into()).collect()),
+        ),
+        (
+            "importSpans".to_string(),
+            Value::Array(
+                analysis
+                    .import_spans
+                    .iter()
+                    .map(|&(start, end)| Value::Array(vec![start.into(), end.into()]))
+                    .collect(),
+            ),
+        ),
+        ("usesTranslation".to_string(), analysis.uses_translation.into()),
+        ("hasTBinding".to_string(), analysis.has_t_binding.into()),
+        ("importAnchor".to_string(), analysis.import_anchor.into()),
+        ("strings".to_string(), Value::Array(strings)),
+    ])
+}
+
+fn analysis_from_json(value: &Value) -> Option<FileAnalysis> {
+    let usize_of = |v: &Value, key: &str| v.get(key).and_then(Value::as_u64).map(|n| n as usize);
+    let mut strings = Vec::new();
+    for s in value.get("strings")?.as_array()? {
+        let attr = s.get("attr").and_then(Value::as_str).map(String::from);
+        let kind = match s.get("kind")?.as_str()? {
+            "text" => StringKind::Text,
+            "attribute" => StringKind::Attribute(attr?),
+            "attributeExpression" => StringKind::AttributeExpression(attr?),
+            "expression" => StringKind::Expression,
+            _ => return None,
+        };
+        strings.push(HardcodedString {
+            text: s.get("text")?.as_str()?.to_string(),
+            start: usize_of(s, "start")?,
+            end: usize_of(s, "end")?,
+            line: usize_of(s, "line")?,
+            column: usize_of(s, "column")?,
+            kind,
+            component: s.get("component").and_then(Value::as_str).map(String::from),
+            ignored: s.get("ignored")?.as_bool()?,
+            ignore_at: usize_of(s, "ignoreAt")?,
+        });
+    }
+    Some(FileAnalysis {
+        path: value.get("path")?.as_str()?.to_string(),
+        imports: value
+            .get("imports")?
+            .as_array()?
+            .iter()
+            .filter_map(|i| i.as_str().map(String::from))
+            .collect(),
+        import_spans: value
+            .get("importSpans")?
+            .as_array()?
+            .iter()
+            .map(|span| {
+                let span = span.as_array()?;
+                Some((span.first()?.as_u64()? as usize, span.get(1)?.as_u64()? as usize))
+            })
+            .collect::<Option<_>>()?,
+        uses_translation: value.get("usesTranslation")?.as_bool()?,
+        has_t_binding: value.get("hasTBinding")?.as_bool()?,
+        import_anchor: usize_of(value, "importAnchor")?,
+        strings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn test_cache_invalidates_on_content_config_and_import_change() {
+        let dir = TempDir::new("cache");
+        let file = dir.join("App.jsx");
+        let dep = dir.join("Button.jsx");
+        let code = "import Button from './Button';\nconst App = () => <p title=\"Hi\">Hello</p>;";
+        fs::write(&file, code).unwrap();
+        fs::write(&dep, "export default 1;").unwrap();
+
+        let analysis = FileAnalysis {
+            path: file.to_string_lossy().to_string(),
+            imports: vec!["./Button".to_string()],
+            strings: vec![HardcodedString {
+                text: "Hi".to_string(),
+                start: 56,
+                end: 60,
+                line: 2,
+                column: 26,
+                kind: StringKind::Attribute("title".to_string()),
+                component: Some("App".to_string()),
+                ignored: false,
+                ignore_at: 50,
+            }],
+            ..Default::default()
+        };
+        let cache = Cache::new(&dir.join("cache"), 1);
+        cache.store(&file, code, &analysis).unwrap();
+
+        let hit = cache.lookup(&file, code).unwrap();
+        assert_eq!(hit.strings[0].kind, StringKind::Attribute("title".to_string()));
+        assert_eq!(hit.imports, analysis.imports);
+
+        assert!(cache.lookup(&file, "changed").is_none());
+        assert!(Cache::new(&dir.join("cache"), 2).lookup(&file, code).is_none());
+        fs::write(&dep, "export default 2;").unwrap();
+        assert!(cache.fresh().lookup(&file, code).is_none());
+    }
+
+    #[test]
+    fn test_cache_invalidates_on_transitive_import_change() {
+        // A -> B -> C (C는 B에서만 import), B <-> A 순환도 포함
+        let dir = TempDir::new("cache-transitive");
+        let code = "import B from './B';\nconst A = () => <p>Hello</p>;";
+        let a = dir.write("A.jsx", code);
+        dir.write("B.jsx", "import C from \"./lib/C\";\nimport A from './A';\nexport default C;");
+        let c = dir.write("lib/C.js", "export default 1;");
+
+        let analysis = FileAnalysis {
+            path: a.to_string_lossy().to_string(),
+            imports: vec!["./B".to_string()],
+            ..Default::default()
+        };
+        let cache = Cache::new(&dir.join("cache"), 1);
+        cache.store(&a, code, &analysis).unwrap();
+        assert!(cache.lookup(&a, code).is_some());
+
+        fs::write(&c, "export default 2;").unwrap();
+        // 같은 실행 안에서는 처음 읽은 해시를 그대로 씀
+        assert!(cache.lookup(&a, code).is_some());
+        assert!(cache.fresh().lookup(&a, code).is_none());
+    }
+
+    #[test]
+    fn test_shared_dependency_is_read_once_per_run() {
+        // 여러 파일이 같은 lib을 import해도 해시는 한 번만 계산해서 복제본끼리 공유
+        let dir = TempDir::new("cache-shared");
+        dir.write("lib/util.js", "export default 1;");
+        let cache = Cache::new(&dir.join("cache"), 1);
+        for name in ["A", "B", "C"] {
+            let code = "import util from './lib/util';";
+            let path = dir.write(&format!("{}.jsx", name), code);
+            let analysis = FileAnalysis {
+                path: path.to_string_lossy().to_string(),
+                imports: vec!["./lib/util".to_string()],
+                ..Default::default()
+            };
+            cache.clone().store(&path, code, &analysis).unwrap();
+            assert!(cache.clone().lookup(&path, code).is_some());
+        }
+        let files = cache.files.lock().unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files.contains_key(&dir.join("lib/util.js")));
+    }
+
+    #[test]
+    fn test_relative_specifiers() {
+        let code = "import x from './x';\nexport * from \"../y\";\nconst z = require('./z.js');\nimport 'react';\nconst s = \"it's\";";
+        assert_eq!(relative_specifiers(code), vec!["./x", "../y", "./z.js"]);
+    }
+}
@@ -0,0 +1,366 @@
+// ============================================
+// i18n-wrapper: 로케일 카탈로그 (locales/en.json 등)
+// TypeScript:
+// const catalog = JSON.parse(fs.readFileSync(path, "utf-8"));
+// catalog[key] = defaultValue;
+// fs.writeFileSync(path, JSON.stringify(catalog, null, 2));
+// ============================================
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::diagnostic::{self, Diagnostic};
+use crate::json::{self, Value};
+
+// 중첩 형태에서 키 경로가 기존 값과 겹침
+// "cart"가 문자열인데 "cart.title"을 넣거나, "cart.title"이 있는데 "cart"에 문자열을 넣는 경우
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyConflict {
+    pub key: String,
+    // 겹치는 기존 키 (문자열인 상위 키, 또는 하위 키가 있는 key 자신)
+    pub existing: String,
+}
+
+impl fmt::Display for KeyConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.existing == self.key {
+            write!(f, "`{}` 아래에 다른 키가 있어 문자열을 넣을 수 없습니다", self.key)
+        } else {
+            write!(f, "`{}`가 문자열이라 `{}`를 넣을 수 없습니다", self.existing, self.key)
+        }
+    }
+}
+
+impl std::error::Error for KeyConflict {}
+
+// 키는 "checkout.title" 처럼 점으로 구분
+// 파일은 평평한 형태({"a.b": ".."})와 중첩 형태({"a": {"b": ".."}}) 모두 지원
+#[derive(Clone)]
+pub struct Catalog {
+    pub path: PathBuf,
+    root: Value,
+    indent: String,
+    trailing_newline: bool,
+}
+
+impl Catalog {
+    pub fn empty(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            root: Value::object(),
+            indent: "  ".to_string(),
+            trailing_newline: true,
+        }
+    }
+
+    // 파일이 없으면 빈 카탈로그로 시작
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::empty(path)),
+            Err(e) => return Err(e),
+        };
+        Self::parse(path, &text)
+    }
+
+    // 명령들이 공통으로 쓰는 형태: 읽기/파싱 실패를 CATALOG_ERROR 진단으로
+    pub fn load_diagnostic(path: &Path) -> Result<Self, Box<Diagnostic>> {
+        Self::load(path).map_err(|e| Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, e.to_string(), path)))
+    }
+
+    pub fn parse(path: &Path, text: &str) -> io::Result<Self> {
+        if text.trim().is_empty() {
+            return Ok(Self::empty(path));
+        }
+        let root = json::parse(text).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}:{}", path.display(), e))
+        })?;
+        if !matches!(root, Value::Object(_)) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: catalog root must be an object", path.display()),
+            ));
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            root,
+            indent: json::detect_indent(text),
+            trailing_newline: text.ends_with('\n'),
+        })
+    }
+
+    fn is_nested(&self) -> bool {
+        self.root
+            .as_object()
+            .map(|entries| entries.iter().any(|(_, v)| matches!(v, Value::Object(_))))
+            .unwrap_or(false)
+    }
+
+    // 파일 순서대로 (key, value) 평탄화
+    pub fn entries(&self) -> Vec<(String, String)> {
+        let mut out = Vec::new();
+        flatten(&self.root, "", &mut out);
+        out
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        self.entries().into_iter().map(|(k, _)| k).collect()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        if let Some(value) = self.root.get(key) {
+            return value.as_str();
+        }
+        let mut node = &self.root;
+        for segment in key.split('.') {
+            node = node.get(segment)?;
+        }
+        node.as_str()
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    // key에 문자열을 넣으면 덮어쓰게 될 기존 키 (평평한 형태는 항상 None)
+    pub fn conflict(&self, key: &str) -> Option<String> {
+        if !self.is_nested() {
+            return None;
+        }
+        if matches!(self.root.get(key), Some(Value::Object(_))) {
+            return Some(key.to_string());
+        }
+        if matches!(self.root.get(key), Some(Value::String(_))) {
+            return None;
+        }
+        let segments: Vec<&str> = key.split('.').collect();
+        let mut node = &self.root;
+        for (i, segment) in segments.iter().enumerate() {
+            match node.get(segment) {
+                Some(child @ Value::Object(_)) => node = child,
+                // 마지막 세그먼트의 문자열은 그 자리에서 교체
+                Some(_) if i + 1 < segments.len() => return Some(segments[..=i].join(".")),
+                _ => return None,
+            }
+        }
+        // 마지막 세그먼트까지 객체
+        Some(key.to_string())
+    }
+
+    // 기존 키는 그 자리에서 교체, 새 키는 끝에 추가
+    pub fn insert(&mut self, key: &str, value: &str) -> Result<(), KeyConflict> {
+        if let Some(existing) = self.conflict(key) {
+            return Err(KeyConflict {
+                key: key.to_string(),
+                existing,
+            });
+        }
+        if self.root.get(key).is_some() || !self.is_nested() {
+            self.root.insert(key, value.into());
+            return Ok(());
+        }
+        self.insert_nested(key, value);
+        Ok(())
+    }
+
+    // conflict를 먼저 확인한 뒤에만 호출 (상위 경로는 없거나 객체)
+    fn insert_nested(&mut self, key: &str, value: &str) {
+        let segments: Vec<&str> = key.split('.').collect();
+        let mut node = &mut self.root;
+        for segment in &segments[..segments.len() - 1] {
+            if node.get(segment).is_none() {
+                node.insert(segment, Value::object());
+            }
+            node = node.get_mut(segment).unwrap();
+        }
+        node.insert(segments[segments.len() - 1], value.into());
+    }
+
+    // sync: 원문 순서에 맞춰 after 키 바로 뒤에 추가 (None이면 맨 앞)
+    // 중첩 형태이거나 after를 찾지 못하면 insert처럼 끝에 추가
+    pub fn insert_after(&mut self, key: &str, value: &str, after: Option<&str>) -> Result<(), KeyConflict> {
+        if self.contains(key) || self.is_nested() {
+            return self.insert(key, value);
+        }
+        let entries = self.root.as_object().map(Vec::as_slice).unwrap_or(&[]);
+        let index = match after {
+            None => Some(0),
+            Some(after) => entries.iter().position(|(k, _)| k == after).map(|i| i + 1),
+        };
+        match index {
+            Some(index) => {
+                self.root.insert_at(index, key, value.into());
+                Ok(())
+            }
+            None => self.insert(key, value),
+        }
+    }
+
+    // 값이 없는 경우에만 추가, 추가했으면 true
+    pub fn insert_default(&mut self, key: &str, value: &str) -> Result<bool, KeyConflict> {
+        if self.contains(key) {
+            return Ok(false);
+        }
+        self.insert(key, value)?;
+        Ok(true)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        if let Some(Value::String(_)) = self.root.get(key) {
+            return self.root.remove(key).and_then(|v| v.as_str().map(String::from));
+        }
+        let segments: Vec<&str> = key.split('.').collect();
+        let removed = remove_nested(&mut self.root, &segments)?;
+        removed.as_str().map(String::from)
+    }
+
+    // 번역과 순서를 유지한 채 키 이름 변경 (rename-key)
+    // 평평한 형태는 그 자리에서, 중첩 형태는 새 경로로 옮김 (비게 된 상위 객체는 정리)
+    // 새 경로가 기존 값과 겹치면 아무것도 바꾸지 않고 Err
+    pub fn rename(&mut self, old: &str, new: &str) -> Result<bool, KeyConflict> {
+        if !self.contains(old) {
+            return Ok(false);
+        }
+        // old 자신이 막고 있는 경우("cart" -> "cart.title")는 옮기면서 풀림
+        if let Some(existing) = self.conflict(new).filter(|existing| existing != old) {
+            return Err(KeyConflict {
+                key: new.to_string(),
+                existing,
+            });
+        }
+        if matches!(self.root.get(old), Some(Value::String(_))) {
+            return Ok(self.root.rename_key(old, new));
+        }
+        let nested = self.is_nested();
+        let value = self.remove(old).unwrap_or_default();
+        // 마지막 키를 옮기느라 객체가 비었어도 중첩 형태 유지
+        if nested {
+            self.insert_nested(new, &value);
+        } else {
+            self.root.insert(new, value.into());
+        }
+        Ok(true)
+    }
+
+    pub fn render(&self) -> String {
+        let mut text = self.root.to_pretty_string(&self.indent);
+        if self.trailing_newline {
+            text.push('\n');
+        }
+        text
+    }
+}
+
+// i18next 보간 자리표시자 이름: "Hi {{name}}, {{count, number}}" -> ["count", "name"]
+pub fn placeholders(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find("{{") {
+        let after = &rest[open + 2..];
+        let close = match after.find("}}") {
+            Some(close) => close,
+            None => break,
+        };
+        let name = after[..close].split(',').next().unwrap_or("").trim();
+        if !name.is_empty() {
+            names.push(name.to_string());
+        }
+        rest = &after[close + 2..];
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn flatten(value: &Value, prefix: &str, out: &mut Vec<(String, String)>) {
+    if let Value::Object(entries) = value {
+        for (key, item) in entries {
+            let full = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match item {
+                Value::Object(_) => flatten(item, &full, out),
+                Value::String(s) => out.push((full, s.clone())),
+                _ => {}
+            }
+        }
+    }
+}
+
+// 중첩 키 제거 후 비어버린 부모 객체도 정리
+fn remove_nested(node: &mut Value, segments: &[&str]) -> Option<Value> {
+    if segments.len() == 1 {
+        return node.remove(segments[0]);
+    }
+    let child = node.get_mut(segments[0])?;
+    let removed = remove_nested(child, &segments[1..])?;
+    if child.as_object().map(|e| e.is_empty()).unwrap_or(false) {
+        node.remove(segments[0]);
+    }
+    Some(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested() -> Catalog {
+        Catalog::parse(Path::new("en.json"), "{\n  \"cart\": \"Cart\",\n  \"checkout\": {\n    \"title\": \"Checkout\"\n  }\n}\n")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_insert_reports_prefix_conflicts() {
+        let mut catalog = nested();
+        let before = catalog.render();
+
+        // 문자열인 상위 키 아래에 넣기
+        let err = catalog.insert("cart.title", "Your cart").unwrap_err();
+        assert_eq!(err.existing, "cart");
+        // 하위 키가 있는 자리에 문자열 넣기
+        let err = catalog.insert("checkout", "Checkout").unwrap_err();
+        assert_eq!(err.existing, "checkout");
+        assert!(catalog.insert_after("cart.title", "", Some("cart")).is_err());
+        assert!(catalog.insert_default("checkout", "").is_err());
+        assert_eq!(catalog.render(), before);
+
+        // 겹치지 않으면 그대로 추가/교체
+        catalog.insert("checkout.pay", "Pay").unwrap();
+        catalog.insert("cart", "Basket").unwrap();
+        assert_eq!(catalog.get("checkout.pay"), Some("Pay"));
+        assert_eq!(catalog.get("cart"), Some("Basket"));
+    }
+
+    #[test]
+    fn test_rename_conflict_leaves_catalog_unchanged() {
+        let mut catalog = nested();
+        let before = catalog.render();
+        assert!(catalog.rename("checkout.title", "cart.title").is_err());
+        assert_eq!(catalog.render(), before);
+        assert_eq!(catalog.rename("missing", "other"), Ok(false));
+        assert_eq!(catalog.rename("checkout.title", "checkout.heading"), Ok(true));
+        assert_eq!(catalog.get("checkout.heading"), Some("Checkout"));
+    }
+
+    #[test]
+    fn test_flat_and_empty_catalogs_never_conflict() {
+        let mut flat = Catalog::parse(Path::new("en.json"), "{\n  \"cart\": \"Cart\"\n}\n").unwrap();
+        assert_eq!(flat.conflict("cart.title"), None);
+        flat.insert("cart.title", "Your cart").unwrap();
+        assert_eq!(flat.keys(), vec!["cart", "cart.title"]);
+
+        let mut empty = Catalog::parse(Path::new("en.json"), "  \n").unwrap();
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.insert_default("a.b", "B"), Ok(true));
+        assert_eq!(empty.insert_default("a.b", "C"), Ok(false));
+        assert_eq!(empty.get("a.b"), Some("B"));
+    }
+}
@@ -15,22 +15,51 @@ pub fn run_cli_examples() {
     println!("  clap = {{ version = \"4\", features = [\"derive\"] }}\n");
 }
 
-// 실제 사용 예제 (clap crate가 있을 때만 컴파일)
 #[cfg(feature = "clap")]
+use clap::Parser;
+
+// 실제 사용 예제 (clap, swc crate가 있을 때만 컴파일)
+#[cfg(all(feature = "clap", feature = "swc"))]
 pub fn cli_examples() {
-    use clap::Parser;
+    use crate::glob;
+    use crate::process::{self, ProcessOptions};
+    use std::path::Path;
 
     // 명령줄 인자 파싱
     let args = CliArgs::parse();
     
     println!("=== CLI 도구 예제 ===\n");
     println!("dry-run: {}", args.dry_run);
-    if let Some(pattern) = &args.pattern {
-        println!("pattern: {}", pattern);
-    }
     if let Some(output) = &args.output {
         println!("output: {}", output);
     }
+    if let Some(pattern) = &args.pattern {
+        println!("pattern: {}", pattern);
+
+        // 패턴을 현재 디렉토리 기준으로 펼침 ("src/**/*.tsx")
+        let files = match glob::expand(Path::new(""), std::slice::from_ref(pattern), &[]) {
+            Ok((files, warnings)) => {
+                for warning in &warnings {
+                    eprintln!("{}", warning);
+                }
+                files
+            }
+            Err(e) => {
+                eprintln!("파일 목록 에러: {}", e);
+                return;
+            }
+        };
+        println!("파일: {}개", files.len());
+
+        // dry-run이면 diff만 출력하고 디스크에는 쓰지 않음
+        let options = ProcessOptions {
+            files,
+            dry_run: args.dry_run,
+            verbose: args.verbose,
+            ..Default::default()
+        };
+        process::run_process(&options);
+    }
 }
 
 // ============================================
@@ -76,7 +105,7 @@ pub fn simple_cli_example() {
     // 명령줄 인자 가져오기
     let args: Vec<String> = env::args().collect();
 
-    println!("프로그램 이름: {:?}", args.get(0));
+    println!("프로그램 이름: {:?}", args.first());
     println!("인자들: {:?}", &args[1..]);
 
     // 환경 변수 읽기
@@ -119,9 +148,21 @@ pub fn logging_example() {
 // ============================================
 // 실전 예제: i18n-wrapper CLI
 // ============================================
-#[cfg(feature = "clap")]
+#[cfg(all(feature = "clap", feature = "swc"))]
 pub fn i18n_wrapper_cli() {
-    use clap::{Parser, Subcommand};
+    use clap::Subcommand;
+    use crate::cache::{Cache, CACHE_DIR};
+    use crate::config::{self, CliOverrides};
+    use crate::diff::DiffFormat;
+    use crate::framework::Framework;
+    use crate::process::{self, ProcessOptions};
+    use crate::review::DECISIONS_PATH;
+    use crate::diagnostic::Severity;
+    use crate::json::Value;
+    use crate::lint;
+    use crate::report::{self, ReportFormat};
+    use crate::sarif;
+    use std::path::PathBuf;
 
     #[derive(Parser)]
     #[command(name = "i18n-wrapper")]
@@ -130,6 +171,10 @@ pub fn i18n_wrapper_cli() {
         #[command(subcommand)]
         command: Option<Commands>,
 
+        /// Config file (default: i18n-wrapper.toml or package.json found from the cwd upwards)
+        #[arg(long, global = true)]
+        config: Option<PathBuf>,
+
         /// Dry run mode
         #[arg(short, long, global = true)]
         dry_run: bool,
@@ -139,36 +184,727 @@ pub fn i18n_wrapper_cli() {
         verbose: bool,
     }
 
+    // process / watch 공통 옵션
+    #[derive(clap::Args)]
+    struct SourceArgs {
+        /// Source glob (repeatable, "!" prefix excludes; default: config include)
+        #[arg(short, long)]
+        pattern: Vec<String>,
+
+        /// Locale catalog to add new keys to (overrides the config file)
+        #[arg(long)]
+        catalog: Option<PathBuf>,
+
+        /// Key naming strategy (slug, hash)
+        #[arg(long, value_parser = ["slug", "hash"])]
+        key_strategy: Option<String>,
+
+        /// Translation library (overrides the config file)
+        #[arg(long, value_parser = ["i18next", "react-i18next", "vue-i18n", "react-intl"])]
+        framework: Option<String>,
+
+        /// Number of files parsed/written concurrently (default: CPU count)
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Re-parse every file instead of using .i18n-wrapper/cache
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Give up on a single file after this many seconds
+        #[arg(long, default_value_t = 30)]
+        file_timeout: u64,
+    }
+
     #[derive(Subcommand)]
     enum Commands {
         /// Process files
         Process {
-            /// Source pattern
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Context lines around each change in dry-run diffs
+            #[arg(long, default_value_t = 3)]
+            context: usize,
+
+            /// Dry-run diff format (text, json)
+            #[arg(long, default_value = "text", value_parser = ["text", "json"])]
+            diff_format: String,
+
+            /// Review each proposed wrap one at a time
             #[arg(short, long)]
-            pattern: Option<String>,
+            interactive: bool,
+
+            /// Report format (text, json)
+            #[arg(long, default_value = "text", value_parser = ["text", "json"])]
+            format: String,
+        },
+        /// Re-process files whenever they change
+        #[cfg(feature = "async")]
+        Watch {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Polling interval in milliseconds (at least 1)
+            #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(u64).range(1..))]
+            poll_interval: u64,
+
+            /// Quiet period after the last change before re-processing, in milliseconds
+            #[arg(long, default_value_t = 200)]
+            debounce: u64,
+        },
+        /// Add keys used in existing t() / <Trans> calls to the source catalog without touching code
+        Extract {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Minimum similarity (0-1) for translation-memory suggestions
+            #[arg(long, default_value_t = crate::memory::DEFAULT_THRESHOLD)]
+            fuzzy_threshold: f64,
+
+            /// Do not read or update the translation memory (.i18n-wrapper/memory.json)
+            #[arg(long)]
+            no_memory: bool,
+        },
+        /// Fail (exit 1) when untranslated strings are found; exit 2 on tool errors
+        Check {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Output format (text, json, sarif)
+            #[arg(long, default_value = "text", value_parser = ["text", "json", "sarif"])]
+            format: String,
+
+            /// Only fail on findings that are not recorded in this baseline file
+            #[arg(long)]
+            baseline: Option<PathBuf>,
+
+            /// Record the current findings as the baseline (default file: i18n-baseline.json)
+            #[arg(long)]
+            write_baseline: bool,
+        },
+        /// Rename a translation key in source files and every locale catalog
+        RenameKey {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Key to rename (or key prefix with --prefix)
+            old: String,
+
+            /// New key (or new prefix with --prefix)
+            new: String,
+
+            /// Rename every key starting with OLD, e.g. `--prefix checkout. payment.`
+            #[arg(long)]
+            prefix: bool,
+        },
+        /// Delete catalog keys that are no longer referenced in source from every locale
+        Prune {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Never delete keys matching this pattern (e.g. `status.*`, `errors.**`); adds to `keep` in config
+            #[arg(long)]
+            keep: Vec<String>,
+
+            /// Append removed translations to this JSON file, grouped by locale
+            #[arg(long)]
+            archive: Option<PathBuf>,
+        },
+        /// Make every locale catalog contain exactly the source locale's keys
+        Sync {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Value for missing translations (empty, source, pseudo)
+            #[arg(long, default_value = "empty", value_parser = ["empty", "source", "pseudo"])]
+            fill: String,
+
+            /// Keys missing from the source locale: report them (flag) or delete them (remove)
+            #[arg(long, default_value = "flag", value_parser = ["flag", "remove"])]
+            orphans: String,
+        },
+        /// Generate a pseudo-localized catalog (accented, expanded, bracketed) for UI testing
+        Pseudo {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Pseudo locale name (default: en-XA, or ar-XB with --mirror)
+            #[arg(long)]
+            locale: Option<String>,
+
+            /// Lengthen each string by this percentage
+            #[arg(long, default_value_t = 30)]
+            expand: usize,
+
+            /// Do not wrap strings in [ ]
+            #[arg(long)]
+            no_brackets: bool,
+
+            /// Wrap text in right-to-left override characters to simulate RTL
+            #[arg(long)]
+            mirror: bool,
+        },
+        /// Pre-fill missing translations with a machine-translation provider (marked as needing review)
+        #[cfg(feature = "async")]
+        Translate {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Translation provider (dictionary, local-http)
+            #[arg(long, default_value = "dictionary", value_parser = ["dictionary", "local-http"])]
+            provider: String,
+
+            /// Offline dictionary file for the dictionary provider: { "ko": { "Save": "저장" } }
+            #[arg(long)]
+            dictionary: Option<PathBuf>,
+
+            /// LibreTranslate-compatible endpoint for the local-http provider (API key from I18N_WRAPPER_API_KEY).
+            /// Plain HTTP to localhost only, no TLS: reach remote translation APIs through a local proxy
+            #[arg(long, default_value = "http://localhost:5000/translate")]
+            url: String,
+
+            /// Maximum requests per second (0 = unlimited)
+            #[arg(long, default_value_t = 2.0)]
+            rate: f64,
+
+            /// Retries for rate-limited or failed requests, with exponential backoff (0-10)
+            #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(0..=crate::translate::MAX_RETRIES as i64))]
+            retries: u32,
         },
-        /// Analyze files
+        /// Write .po or XLIFF files per locale with translator descriptions from extract
+        Export {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Output format (po, xliff)
+            #[arg(long, default_value = "po", value_parser = ["po", "xliff"])]
+            format: String,
+        },
+        /// Group keys whose source text is the same and show where each is used
+        Duplicates {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Treat texts that differ only in letter case as different
+            #[arg(long)]
+            match_case: bool,
+
+            /// Treat texts that differ only in whitespace as different
+            #[arg(long)]
+            match_whitespace: bool,
+
+            /// Treat texts that differ only in punctuation as different
+            #[arg(long)]
+            match_punctuation: bool,
+
+            /// Output format (text, json)
+            #[arg(long, default_value = "text", value_parser = ["text", "json"])]
+            format: String,
+
+            /// Rewrite code to use one key per group (skips groups whose `// i18n:` comments differ)
+            #[arg(long)]
+            consolidate: bool,
+
+            /// With --consolidate, also merge keys whose texts differ only in case, whitespace or punctuation
+            #[arg(long)]
+            normalized: bool,
+        },
+        /// Translated vs hardcoded string counts per directory, file and component
+        Stats {
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Output format (text, json, csv)
+            #[arg(long, default_value = "text", value_parser = ["text", "json", "csv"])]
+            format: String,
+        },
+        /// Report hardcoded strings and planned wraps without changing files
         Analyze {
-            /// File path
-            #[arg(short, long)]
-            file: String,
+            #[command(flatten)]
+            source: SourceArgs,
+
+            /// Report format (text, json, sarif)
+            #[arg(long, default_value = "text", value_parser = ["text", "json", "sarif"])]
+            format: String,
         },
     }
 
     let cli = Cli::parse();
 
+    // 설정 파일 → CLI 플래그 순서로 덮어씀
+    let loaded = match &cli.config {
+        Some(path) => config::load(path),
+        None => std::env::current_dir()
+            .map_err(|e| config::ConfigError {
+                file: PathBuf::from("."),
+                line: 0,
+                key: String::new(),
+                message: e.to_string(),
+            })
+            .and_then(|cwd| config::discover(&cwd)),
+    };
+    let project = match loaded {
+        Ok(project) => project,
+        Err(e) => {
+            eprintln!("설정 에러: {}", e);
+            std::process::exit(lint::EXIT_TOOL_ERROR);
+        }
+    };
+    if cli.verbose {
+        if let Some(source) = &project.source {
+            println!("설정 파일: {}", source.display());
+        }
+    }
+
+    // 설정 + 공통 CLI 옵션 → ProcessOptions
+    let source_options = |project: config::Config, source: &SourceArgs| {
+        let project = project.merge_cli(CliOverrides {
+            key_strategy: source.key_strategy.as_deref().and_then(config::parse_key_strategy),
+            framework: source.framework.as_deref().and_then(Framework::parse),
+        });
+        let files = match project.source_files(&source.pattern) {
+            Ok((files, warnings)) => {
+                let color = crate::diagnostic::use_color();
+                for warning in &warnings {
+                    eprintln!("{}", warning.render(color));
+                }
+                files
+            }
+            Err(e) => {
+                eprintln!("파일 목록 에러: {}", e);
+                std::process::exit(lint::EXIT_TOOL_ERROR);
+            }
+        };
+        let options = ProcessOptions {
+            files,
+            dry_run: cli.dry_run,
+            catalog_path: source.catalog.clone().unwrap_or_else(|| project.source_catalog_path()),
+            key_strategy: project.key_strategy,
+            framework: project.framework,
+            jobs: source.jobs.unwrap_or_else(process::default_jobs),
+            cache: (!source.no_cache).then(|| Cache::new(&project.root.join(CACHE_DIR), project.hash())),
+            decisions_path: project.root.join(DECISIONS_PATH),
+            file_timeout: std::time::Duration::from_secs(source.file_timeout),
+            verbose: cli.verbose,
+            key_naming: project.key_naming.clone(),
+            ..Default::default()
+        };
+        (project, options)
+    };
+    // process/watch는 코드를 감싸므로 t()를 넣을 수 있는 프레임워크만
+    let require_wrapping = |options: &ProcessOptions| {
+        if !options.framework.can_wrap() {
+            eprintln!(
+                "설정 에러: framework = \"{}\"는 코드 변환을 지원하지 않습니다 (i18next, react-i18next만 가능, extract/check는 사용 가능)",
+                options.framework.name()
+            );
+            std::process::exit(lint::EXIT_TOOL_ERROR);
+        }
+    };
+
     match &cli.command {
-        Some(Commands::Process { pattern }) => {
-            println!("Processing files...");
-            if let Some(p) = pattern {
-                println!("Pattern: {}", p);
+        Some(Commands::Process {
+            source,
+            context,
+            diff_format,
+            interactive,
+            format,
+        }) => {
+            let (_, mut options) = source_options(project, source);
+            require_wrapping(&options);
+            options.context = *context;
+            options.diff_format = DiffFormat::parse(diff_format).unwrap_or(DiffFormat::Text);
+            options.format = ReportFormat::parse(format).unwrap_or(ReportFormat::Text);
+            options.interactive = *interactive;
+            // JSON 출력은 파이프로 넘기므로 안내 문구를 섞지 않음
+            if options.diff_format == DiffFormat::Text && options.format == ReportFormat::Text {
+                println!("Processing files...");
+            }
+            process::run_process(&options);
+        }
+        #[cfg(feature = "async")]
+        Some(Commands::Watch {
+            source,
+            poll_interval,
+            debounce,
+        }) => {
+            use crate::watch::{self, WatchOptions};
+            use std::time::Duration;
+
+            let (project, options) = source_options(project, source);
+            require_wrapping(&options);
+            let watch_options = WatchOptions {
+                poll_interval: Duration::from_millis(*poll_interval),
+                debounce: Duration::from_millis(*debounce),
+            };
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(options.jobs.max(1))
+                .enable_all()
+                .build()
+                .expect("tokio 런타임 생성 실패");
+            runtime.block_on(watch::watch(&project, &source.pattern, &options, &watch_options));
+        }
+        Some(Commands::Extract {
+            source,
+            fuzzy_threshold,
+            no_memory,
+        }) => {
+            use crate::memory::{MemoryOptions, MEMORY_PATH};
+
+            let (project, options) = source_options(project, source);
+            let memory = MemoryOptions {
+                path: project.root.join(MEMORY_PATH),
+                source_locale: project.source_locale.clone(),
+                targets: project
+                    .locales
+                    .iter()
+                    .filter(|l| **l != project.source_locale)
+                    .map(|l| (l.clone(), project.catalog_path(l)))
+                    .collect(),
+                threshold: *fuzzy_threshold,
+                dry_run: options.dry_run,
+            };
+            crate::extract::run_extract(&options, (!*no_memory).then_some(&memory), |r| {
+                lint::project_path(&project, std::path::Path::new(&r.path))
+            });
+        }
+        Some(Commands::Check {
+            source,
+            format,
+            baseline,
+            write_baseline,
+        }) => {
+            use crate::baseline::{Baseline, Comparison, DEFAULT_BASELINE};
+
+            let (project, mut options) = source_options(project, source);
+            options.dry_run = true;
+            let report = process::execute(&options);
+            let findings = lint::run(&report, &project, &options.catalog_path);
+
+            if *write_baseline {
+                let path = baseline.clone().unwrap_or_else(|| project.root.join(DEFAULT_BASELINE));
+                let recorded = Baseline::from_findings(&findings, |path| lint::project_path(&project, path));
+                if let Err(e) = recorded.save(&path) {
+                    eprintln!("baseline 저장 실패: {}: {}", path.display(), e);
+                    std::process::exit(lint::EXIT_TOOL_ERROR);
+                }
+                eprintln!("baseline: 항목 {}개를 {}에 기록했습니다", findings.len(), path.display());
+                std::process::exit(lint::exit_code(&[], &report.errors));
+            }
+
+            // baseline이 있으면 새 항목만 남기고, 해결된 항목은 따로 보고
+            let Comparison { new: findings, fixed, known } = match baseline {
+                Some(path) => match Baseline::load(path) {
+                    Ok(recorded) => recorded.compare(findings),
+                    Err(e) => {
+                        eprintln!("baseline 에러: {}", e);
+                        std::process::exit(lint::EXIT_TOOL_ERROR);
+                    }
+                },
+                None => Comparison {
+                    new: findings,
+                    fixed: Vec::new(),
+                    known: 0,
+                },
+            };
+            let code = lint::exit_code(&findings, &report.errors);
+            match ReportFormat::parse(format).unwrap_or(ReportFormat::Text) {
+                ReportFormat::Text => {
+                    let color = crate::diagnostic::use_color();
+                    for diagnostic in findings.iter().chain(&report.errors) {
+                        eprintln!("{}", diagnostic.render(color));
+                    }
+                    let errors = findings.iter().filter(|f| f.severity == Severity::Error).count();
+                    eprintln!(
+                        "check: 파일 {}개, 문제 {}개 (경고 {}개), 도구 에러 {}개",
+                        report.analyses.len(),
+                        errors,
+                        findings.len() - errors,
+                        report.errors.len()
+                    );
+                    if known > 0 {
+                        eprintln!("  baseline에 있는 기존 항목 {}개는 제외했습니다", known);
+                    }
+                    if !fixed.is_empty() {
+                        eprintln!("  해결된 baseline 항목 {}개 (--write-baseline 으로 baseline을 줄이세요):", fixed.len());
+                        for entry in &fixed {
+                            eprintln!("    {}: {} (x{})", entry.path, entry.message, entry.count);
+                        }
+                    }
+                }
+                ReportFormat::Json => {
+                    let mut json = report::to_json("check", &report);
+                    json.insert("findings", Value::Array(findings.iter().map(report::diagnostic_json).collect()));
+                    if baseline.is_some() {
+                        let fixed = Baseline { entries: fixed }.to_json().remove("findings").unwrap_or(Value::Null);
+                        json.insert(
+                            "baseline",
+                            Value::Object(vec![("known".to_string(), known.into()), ("fixed".to_string(), fixed)]),
+                        );
+                    }
+                    json.insert("exitCode", (code as usize).into());
+                    println!("{}", json.to_pretty_string("  "));
+                }
+                ReportFormat::Sarif => {
+                    let diagnostics: Vec<_> = findings.into_iter().chain(report.errors).collect();
+                    println!("{}", sarif::to_sarif(&diagnostics).to_pretty_string("  "));
+                }
             }
-            if cli.dry_run {
-                println!("(Dry run mode)");
+            std::process::exit(code);
+        }
+        Some(Commands::RenameKey {
+            source,
+            old,
+            new,
+            prefix,
+        }) => {
+            use crate::rename::{self, KeyRenamer};
+
+            let (project, options) = source_options(project, source);
+            // --catalog로 바꾼 원문 카탈로그 + 설정의 모든 로케일 카탈로그
+            let mut catalogs = vec![options.catalog_path.clone()];
+            for locale in &project.locales {
+                let path = project.catalog_path(locale);
+                if !catalogs.contains(&path) {
+                    catalogs.push(path);
+                }
+            }
+            let renamer = KeyRenamer {
+                old: old.clone(),
+                new: new.clone(),
+                prefix: *prefix,
+            };
+            if let Err(e) = rename::run_rename(&options, &catalogs, &renamer) {
+                eprintln!("rename-key: {}", e);
+                std::process::exit(lint::EXIT_TOOL_ERROR);
             }
         }
-        Some(Commands::Analyze { file }) => {
-            println!("Analyzing file: {}", file);
+        Some(Commands::Prune { source, keep, archive }) => {
+            let (project, options) = source_options(project, source);
+            // --catalog로 바꾼 원문 카탈로그 + 설정의 나머지 로케일 카탈로그
+            let mut catalogs = vec![(project.source_locale.clone(), options.catalog_path.clone())];
+            for locale in project.locales.iter().filter(|l| **l != project.source_locale) {
+                catalogs.push((locale.clone(), project.catalog_path(locale)));
+            }
+            let patterns: Vec<String> = project.keep.iter().chain(keep).cloned().collect();
+            let errors = crate::prune::run_prune(&options, &catalogs, &patterns, archive.as_deref());
+            if !errors.is_empty() {
+                std::process::exit(lint::EXIT_TOOL_ERROR);
+            }
+        }
+        Some(Commands::Sync { source, fill, orphans }) => {
+            use crate::sync::{self, Fill, Orphans};
+
+            let (project, options) = source_options(project, source);
+            let targets: Vec<_> = project
+                .locales
+                .iter()
+                .filter(|l| **l != project.source_locale)
+                .map(|l| (l.clone(), project.catalog_path(l)))
+                .collect();
+            let fill = Fill::parse(fill).unwrap_or(Fill::Empty);
+            let orphans = Orphans::parse(orphans).unwrap_or(Orphans::Flag);
+            if !sync::run_sync(&options, &targets, fill, orphans).is_empty() {
+                std::process::exit(lint::EXIT_TOOL_ERROR);
+            }
+        }
+        Some(Commands::Export { source, format }) => {
+            use crate::export::{self, ExportFormat};
+
+            let (project, options) = source_options(project, source);
+            let targets: Vec<_> = project
+                .locales
+                .iter()
+                .filter(|l| **l != project.source_locale)
+                .map(|l| (l.clone(), project.catalog_path(l)))
+                .collect();
+            let format = ExportFormat::parse(format).unwrap_or(ExportFormat::Po);
+            if !export::run_export(&options, &project.source_locale, &targets, format).is_empty() {
+                std::process::exit(lint::EXIT_TOOL_ERROR);
+            }
+        }
+        Some(Commands::Duplicates {
+            source,
+            match_case,
+            match_whitespace,
+            match_punctuation,
+            format,
+            consolidate,
+            normalized,
+        }) => {
+            use crate::duplicates::{self, Normalize};
+
+            let (project, options) = source_options(project, source);
+            let normalize = Normalize {
+                case: !*match_case,
+                whitespace: !*match_whitespace,
+                punctuation: !*match_punctuation,
+            };
+            let targets: Vec<PathBuf> = project
+                .locales
+                .iter()
+                .filter(|l| **l != project.source_locale)
+                .map(|l| project.catalog_path(l))
+                .collect();
+            let location = |r: &crate::extract::KeyReference| lint::project_path(&project, std::path::Path::new(&r.path));
+            let errors = duplicates::run_duplicates(
+                &options,
+                &normalize,
+                format == "json",
+                *consolidate,
+                *normalized,
+                &targets,
+                location,
+            );
+            if !errors.is_empty() {
+                std::process::exit(lint::EXIT_TOOL_ERROR);
+            }
+        }
+        Some(Commands::Pseudo {
+            source,
+            locale,
+            expand,
+            no_brackets,
+            mirror,
+        }) => {
+            use crate::pseudo::{self, PseudoOptions};
+
+            let (project, options) = source_options(project, source);
+            let default = if *mirror { pseudo::DEFAULT_MIRROR_LOCALE } else { pseudo::DEFAULT_LOCALE };
+            let locale = locale.as_deref().unwrap_or(default);
+            let pseudo_options = PseudoOptions {
+                expansion: *expand,
+                brackets: !*no_brackets,
+                mirror: *mirror,
+            };
+            let target = project.catalog_path(locale);
+            let change = match pseudo::plan_pseudo(&options.catalog_path, &target, &pseudo_options) {
+                Ok(change) => change,
+                Err(diagnostic) => {
+                    eprintln!("{}", diagnostic.render(crate::diagnostic::use_color()));
+                    std::process::exit(lint::EXIT_TOOL_ERROR);
+                }
+            };
+            if let Some(diagnostic) = process::apply_changes(&options, std::slice::from_ref(&change)).first() {
+                eprintln!("{}", diagnostic.render(crate::diagnostic::use_color()));
+                std::process::exit(lint::EXIT_TOOL_ERROR);
+            }
+            process::print_dry_run_notice(&options);
+            if !options.dry_run {
+                println!("의사 번역 로케일 {}: {}", locale, target.display());
+                if !project.locales.iter().any(|l| l == locale) {
+                    println!("  앱에서 불러오려면 설정의 locales에 \"{}\"를 추가하세요", locale);
+                }
+            }
+        }
+        #[cfg(feature = "async")]
+        Some(Commands::Translate {
+            source,
+            provider,
+            dictionary,
+            url,
+            rate,
+            retries,
+        }) => {
+            use crate::translate::{self, DictionaryProvider, LocalHttpProvider, Limits, TranslatePlan};
+
+            let (project, options) = source_options(project, source);
+            let targets: Vec<_> = project
+                .locales
+                .iter()
+                .filter(|l| **l != project.source_locale)
+                .map(|l| (l.clone(), project.catalog_path(l)))
+                .collect();
+            let limits = Limits {
+                requests_per_second: *rate,
+                max_retries: *retries,
+                ..Default::default()
+            };
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("tokio 런타임 생성 실패");
+            let source_locale = project.source_locale.as_str();
+            let catalog = options.catalog_path.as_path();
+            let result: Result<TranslatePlan, Box<crate::diagnostic::Diagnostic>> = match provider.as_str() {
+                "local-http" => match LocalHttpProvider::new(url, std::env::var(translate::API_KEY_ENV).ok()) {
+                    Ok(http) => runtime.block_on(translate::plan_translate(&http, source_locale, catalog, &targets, &limits)),
+                    Err(message) => {
+                        eprintln!("translate: {}", message);
+                        std::process::exit(lint::EXIT_TOOL_ERROR);
+                    }
+                },
+                _ => {
+                    let path = dictionary.clone().unwrap_or_else(|| project.root.join("i18n-dictionary.json"));
+                    DictionaryProvider::load(&path).and_then(|dictionary| {
+                        runtime.block_on(translate::plan_translate(&dictionary, source_locale, catalog, &targets, &limits))
+                    })
+                }
+            };
+            let plan = match result {
+                Ok(plan) => plan,
+                Err(diagnostic) => {
+                    eprintln!("{}", diagnostic.render(crate::diagnostic::use_color()));
+                    std::process::exit(lint::EXIT_TOOL_ERROR);
+                }
+            };
+            let errors = process::apply_changes(&options, &plan.changes);
+            println!();
+            println!("요약:");
+            for (locale, key, reason) in &plan.skipped {
+                println!("  ? {} {}: {}", locale, key, reason);
+            }
+            println!(
+                "  기계 번역 {}개 (검토 필요로 표시), 건너뜀 {}개",
+                plan.filled.len(),
+                plan.skipped.len()
+            );
+            process::print_dry_run_notice(&options);
+            let color = crate::diagnostic::use_color();
+            for diagnostic in &errors {
+                eprint!("\n{}", diagnostic.render(color));
+            }
+            if !errors.is_empty() {
+                std::process::exit(lint::EXIT_TOOL_ERROR);
+            }
+        }
+        Some(Commands::Stats { source, format }) => {
+            use crate::stats::{self, StatsFormat};
+
+            let (project, mut options) = source_options(project, source);
+            options.dry_run = true;
+            let report = process::execute(&options);
+            let (references, errors) = crate::extract::scan_files(&options.files, options.framework);
+            let stats = stats::collect(&report.analyses, &references, |path| {
+                lint::project_path(&project, std::path::Path::new(path))
+            });
+            match StatsFormat::parse(format).unwrap_or(StatsFormat::Text) {
+                StatsFormat::Text => stats::print_tree(&stats),
+                StatsFormat::Json => println!("{}", stats::to_json(&stats).to_pretty_string("  ")),
+                StatsFormat::Csv => print!("{}", stats::to_csv(&stats)),
+            }
+            // 같은 파일을 두 번 파싱하므로 파싱 에러는 한 번만 보고
+            let color = crate::diagnostic::use_color();
+            let extra = errors.iter().filter(|e| !report.errors.iter().any(|r| r.path == e.path));
+            for diagnostic in report.errors.iter().chain(extra) {
+                eprintln!("{}", diagnostic.render(color));
+            }
+        }
+        Some(Commands::Analyze { source, format }) => {
+            let (project, mut options) = source_options(project, source);
+            // 키는 계산하지만 파일은 건드리지 않음
+            options.dry_run = true;
+            let report = process::execute(&options);
+            match ReportFormat::parse(format).unwrap_or(ReportFormat::Text) {
+                ReportFormat::Text => report::print_text(&report),
+                ReportFormat::Json => println!("{}", report::to_json("analyze", &report).to_pretty_string("  ")),
+                ReportFormat::Sarif => {
+                    let mut diagnostics = lint::run(&report, &project, &options.catalog_path);
+                    diagnostics.extend(report.errors);
+                    println!("{}", sarif::to_sarif(&diagnostics).to_pretty_string("  "));
+                }
+            }
         }
         None => {
             println!("No command specified");
@@ -180,8 +916,6 @@ pub fn i18n_wrapper_cli() {
 // 프로세스 종료 코드
 // ============================================
 pub fn exit_codes_example() {
-    use std::process;
-
     println!("=== 프로세스 종료 코드 예제 ===\n");
 
     // 성공 종료
@@ -193,6 +927,12 @@ pub fn exit_codes_example() {
     println!("  성공: exit code 0");
     println!("  에러: exit code 1");
     println!("  (실제 종료는 하지 않음)");
+
+    // i18n-wrapper check는 "발견 항목"과 "도구 에러"를 다른 코드로 구분 (lint.rs)
+    println!("\n  i18n-wrapper check:");
+    println!("    {}: 문제 없음", crate::lint::EXIT_OK);
+    println!("    {}: 하드코딩된 문자열 등 발견", crate::lint::EXIT_FINDINGS);
+    println!("    {}: 설정/파싱/읽기 에러", crate::lint::EXIT_TOOL_ERROR);
 }
 
 // ============================================
@@ -0,0 +1,731 @@
+// ============================================
+// i18n-wrapper: 프로젝트 설정 파일
+// i18n-wrapper.toml 또는 package.json의 "i18nWrapper" 키를
+// 현재 디렉토리부터 상위로 올라가며 찾음 (cosmiconfig와 비슷)
+// CLI 플래그가 설정 파일 값보다 우선
+// ============================================
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::diagnostic::Diagnostic;
+use crate::framework::{Framework, FRAMEWORK_NAMES};
+use crate::glob;
+use crate::json::{self, Value};
+use crate::lint;
+use crate::naming::{CaseStyle, KeyNaming};
+use crate::transform::{self, KeyStrategy};
+
+pub const CONFIG_FILE: &str = "i18n-wrapper.toml";
+pub const PACKAGE_JSON_KEY: &str = "i18nWrapper";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RuleLevel {
+    Off,
+    Warn,
+    Error,
+}
+
+impl RuleLevel {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "off" => Some(RuleLevel::Off),
+            "warn" => Some(RuleLevel::Warn),
+            "error" => Some(RuleLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    // 설정 파일이 있는 디렉토리 (상대 경로의 기준)
+    pub root: PathBuf,
+    // 설정을 읽어온 파일 (없으면 기본값)
+    pub source: Option<PathBuf>,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub source_locale: String,
+    pub locales: Vec<String>,
+    pub framework: Framework,
+    pub key_strategy: KeyStrategy,
+    // "{locale}" 자리에 로케일 이름이 들어감
+    pub catalog: String,
+    pub rules: Vec<(String, RuleLevel)>,
+    // prune이 지우지 않을 키 패턴 ("errors.**", "status.*"), 동적으로 만드는 키용
+    pub keep: Vec<String>,
+    // [key_naming] 테이블 (naming.rs)
+    pub key_naming: KeyNaming,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            root: PathBuf::new(),
+            source: None,
+            include: vec!["src/**/*.{js,jsx,ts,tsx}".to_string()],
+            exclude: Vec::new(),
+            source_locale: "en".to_string(),
+            locales: vec!["en".to_string()],
+            framework: Framework::default(),
+            key_strategy: KeyStrategy::Slug,
+            catalog: "locales/{locale}.json".to_string(),
+            rules: Vec::new(),
+            keep: Vec::new(),
+            key_naming: KeyNaming::default(),
+        }
+    }
+}
+
+impl Config {
+    pub fn catalog_path(&self, locale: &str) -> PathBuf {
+        self.root.join(self.catalog.replace("{locale}", locale))
+    }
+
+    pub fn source_catalog_path(&self) -> PathBuf {
+        self.catalog_path(&self.source_locale)
+    }
+
+    // 캐시 키에 들어가는 설정 해시 (root/source는 위치 정보라 제외)
+    pub fn hash(&self) -> u64 {
+        transform::text_hash(&format!(
+            "{:?}|{:?}|{}|{:?}|{:?}|{:?}|{}|{:?}",
+            self.include,
+            self.exclude,
+            self.source_locale,
+            self.locales,
+            self.framework,
+            self.key_strategy,
+            self.catalog,
+            self.rules
+        ))
+    }
+
+    pub fn rule_level(&self, rule: &str, default: RuleLevel) -> RuleLevel {
+        self.rules
+            .iter()
+            .find(|(name, _)| name == rule)
+            .map(|(_, level)| *level)
+            .unwrap_or(default)
+    }
+}
+
+// ============================================
+// 에러: 파일, 줄, 문제가 된 키를 함께 보고
+// ============================================
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    pub file: PathBuf,
+    pub line: usize,
+    pub key: String,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)
+        } else {
+            write!(f, "{}:{}: `{}`: {}", self.file.display(), self.line, self.key, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// ============================================
+// 탐색: 현재 디렉토리 → 상위 디렉토리
+// ============================================
+pub fn discover(start: &Path) -> Result<Config, ConfigError> {
+    let mut dir = Some(start);
+    // 출력 경로가 현재 디렉토리 기준이 되도록 root는 "../.." 형태로 둠
+    let mut root = PathBuf::new();
+    while let Some(current) = dir {
+        let toml_path = current.join(CONFIG_FILE);
+        if toml_path.is_file() {
+            return load_toml(&toml_path).map(|config| Config { root, ..config });
+        }
+        let package_path = current.join("package.json");
+        if package_path.is_file() {
+            if let Some(config) = load_package_json(&package_path)? {
+                return Ok(Config { root, ..config });
+            }
+        }
+        dir = current.parent();
+        root.push("..");
+    }
+    Ok(Config::default())
+}
+
+// --config 로 직접 지정한 경우
+pub fn load(path: &Path) -> Result<Config, ConfigError> {
+    if path.file_name().map(|n| n == "package.json").unwrap_or(false) {
+        load_package_json(path)?.ok_or_else(|| ConfigError {
+            file: path.to_path_buf(),
+            line: 1,
+            key: PACKAGE_JSON_KEY.to_string(),
+            message: "키가 없습니다".to_string(),
+        })
+    } else {
+        load_toml(path)
+    }
+}
+
+fn read(path: &Path) -> Result<String, ConfigError> {
+    fs::read_to_string(path).map_err(|e| ConfigError {
+        file: path.to_path_buf(),
+        line: 0,
+        key: String::new(),
+        message: e.to_string(),
+    })
+}
+
+fn base_config(path: &Path) -> Config {
+    Config {
+        root: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        source: Some(path.to_path_buf()),
+        ..Config::default()
+    }
+}
+
+// 설정 값 하나 (TOML/JSON 공통 표현)
+// 불리언 값을 받는 키는 없어서 true/false는 Other로 둠
+enum RawValue {
+    Str(String),
+    List(Vec<String>),
+    // 원본 표기 그대로
+    Number(String),
+    Table(Entries),
+    Other,
+}
+
+// (키, 값, 줄 번호)
+type Entries = Vec<(String, RawValue, usize)>;
+
+// apply 실패: [rules] 같은 테이블 안의 항목이 문제면 그 항목의 키("rules.<이름>")와 줄로 보고
+struct ApplyError {
+    entry: Option<(String, usize)>,
+    message: String,
+}
+
+impl From<String> for ApplyError {
+    fn from(message: String) -> Self {
+        Self { entry: None, message }
+    }
+}
+
+impl ApplyError {
+    fn at(table: &str, key: &str, line: usize, message: String) -> Self {
+        Self {
+            entry: Some((format!("{}.{}", table, key), line)),
+            message,
+        }
+    }
+
+    fn into_config_error(self, file: &Path, key: String, line: usize) -> ConfigError {
+        let (key, line) = self.entry.unwrap_or((key, line));
+        ConfigError {
+            file: file.to_path_buf(),
+            line,
+            key,
+            message: self.message,
+        }
+    }
+}
+
+fn load_toml(path: &Path) -> Result<Config, ConfigError> {
+    let text = read(path)?;
+    let entries = parse_toml(&text).map_err(|(line, message)| ConfigError {
+        file: path.to_path_buf(),
+        line,
+        key: String::new(),
+        message,
+    })?;
+    let mut config = base_config(path);
+    for (key, value, line) in entries {
+        apply(&mut config, &key, value).map_err(|e| e.into_config_error(path, key, line))?;
+    }
+    Ok(config)
+}
+
+fn load_package_json(path: &Path) -> Result<Option<Config>, ConfigError> {
+    let text = read(path)?;
+    let (root, key_lines) = json::parse_with_key_lines(&text).map_err(|e| ConfigError {
+        file: path.to_path_buf(),
+        line: e.line,
+        key: String::new(),
+        message: e.message,
+    })?;
+    let section = match root.get(PACKAGE_JSON_KEY) {
+        Some(section) => section,
+        None => return Ok(None),
+    };
+    let line_of = |key: &str| {
+        key_lines
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, line)| *line)
+            .unwrap_or(1)
+    };
+    let entries = match section {
+        Value::Object(entries) => entries,
+        _ => {
+            return Err(ConfigError {
+                file: path.to_path_buf(),
+                line: line_of(PACKAGE_JSON_KEY),
+                key: PACKAGE_JSON_KEY.to_string(),
+                message: "객체여야 합니다".to_string(),
+            })
+        }
+    };
+
+    let mut config = base_config(path);
+    for (key, value) in entries {
+        let full = format!("{}.{}", PACKAGE_JSON_KEY, key);
+        let raw = from_json(value, &full, &line_of);
+        // package.json은 camelCase 키를 사용
+        apply(&mut config, &camel_to_snake(key), raw)
+            .map_err(|e| e.into_config_error(path, key.clone(), line_of(&full)))?;
+    }
+    Ok(Some(config))
+}
+
+fn from_json(value: &Value, path: &str, line_of: &dyn Fn(&str) -> usize) -> RawValue {
+    match value {
+        Value::String(s) => RawValue::Str(s.clone()),
+        Value::Number(n) => RawValue::Number(n.clone()),
+        Value::Array(items) if items.iter().all(|i| i.as_str().is_some()) => {
+            RawValue::List(items.iter().filter_map(|i| i.as_str().map(String::from)).collect())
+        }
+        Value::Object(entries) => RawValue::Table(
+            entries
+                .iter()
+                .map(|(k, v)| {
+                    let full = format!("{}.{}", path, k);
+                    (k.clone(), from_json(v, &full, line_of), line_of(&full))
+                })
+                .collect(),
+        ),
+        _ => RawValue::Other,
+    }
+}
+
+fn camel_to_snake(key: &str) -> String {
+    let mut out = String::new();
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+// ============================================
+// 키별 검증 및 적용
+// ============================================
+fn apply(config: &mut Config, key: &str, value: RawValue) -> Result<(), ApplyError> {
+    match key {
+        "include" => config.include = expect_list(value)?,
+        "exclude" => config.exclude = expect_list(value)?,
+        "locales" => {
+            config.locales = expect_list(value)?;
+            if config.locales.is_empty() {
+                return Err("로케일이 하나 이상 필요합니다".to_string().into());
+            }
+        }
+        "source_locale" => config.source_locale = expect_str(value)?,
+        "catalog" => {
+            let catalog = expect_str(value)?;
+            if !catalog.contains("{locale}") {
+                return Err("\"{locale}\" 자리표시자가 필요합니다".to_string().into());
+            }
+            config.catalog = catalog;
+        }
+        "framework" => {
+            let name = expect_str(value)?;
+            config.framework = Framework::parse(&name)
+                .ok_or_else(|| format!("지원하지 않는 프레임워크: {} ({})", name, FRAMEWORK_NAMES))?;
+        }
+        "key_strategy" => {
+            let name = expect_str(value)?;
+            config.key_strategy = parse_key_strategy(&name)
+                .ok_or_else(|| format!("알 수 없는 키 전략: {} (slug, hash)", name))?;
+        }
+        "keep" => config.keep = expect_list(value)?,
+        "key_naming" => match value {
+            RawValue::Table(entries) => {
+                for (key, value, line) in entries {
+                    apply_key_naming(&mut config.key_naming, &camel_to_snake(&key), value)
+                        .map_err(|message| ApplyError::at("key_naming", &key, line, message))?;
+                }
+            }
+            _ => return Err("테이블이어야 합니다".to_string().into()),
+        },
+        "rules" => match value {
+            RawValue::Table(entries) => {
+                for (rule, level, line) in entries {
+                    // "hardcoded-strng" 같은 오타가 조용히 무시되지 않도록
+                    if !lint::RULES.iter().any(|known| known.name == rule) {
+                        let names: Vec<&str> = lint::RULES.iter().map(|known| known.name).collect();
+                        let message = format!("알 수 없는 규칙입니다 ({})", names.join(", "));
+                        return Err(ApplyError::at("rules", &rule, line, message));
+                    }
+                    let level = match level {
+                        RawValue::Str(s) => RuleLevel::parse(&s),
+                        _ => None,
+                    }
+                    .ok_or_else(|| {
+                        let message = "\"off\", \"warn\", \"error\" 중 하나여야 합니다".to_string();
+                        ApplyError::at("rules", &rule, line, message)
+                    })?;
+                    config.rules.push((rule, level));
+                }
+            }
+            _ => return Err("테이블이어야 합니다".to_string().into()),
+        },
+        _ => return Err("알 수 없는 설정 키입니다".to_string().into()),
+    }
+    Ok(())
+}
+
+fn apply_key_naming(naming: &mut KeyNaming, key: &str, value: RawValue) -> Result<(), String> {
+    match key {
+        "case" => {
+            let name = expect_str(value)?;
+            naming.case = match name.as_str() {
+                "any" => None,
+                _ => Some(CaseStyle::parse(&name).ok_or_else(|| {
+                    format!("알 수 없는 형식: {} (snake, camel, kebab, pascal, constant, any)", name)
+                })?),
+            };
+        }
+        "segments" => naming.set_segments(expect_list(value)?)?,
+        "max_depth" => {
+            let depth = expect_usize(value)?;
+            if depth == 0 {
+                return Err("1 이상이어야 합니다".to_string());
+            }
+            naming.max_depth = Some(depth);
+        }
+        "reserved" => naming.reserved = expect_list(value)?,
+        _ => return Err("알 수 없는 설정 키입니다".to_string()),
+    }
+    Ok(())
+}
+
+pub fn parse_key_strategy(name: &str) -> Option<KeyStrategy> {
+    match name {
+        "slug" => Some(KeyStrategy::Slug),
+        "hash" => Some(KeyStrategy::Hash),
+        _ => None,
+    }
+}
+
+fn expect_str(value: RawValue) -> Result<String, String> {
+    match value {
+        RawValue::Str(s) => Ok(s),
+        _ => Err("문자열이어야 합니다".to_string()),
+    }
+}
+
+fn expect_usize(value: RawValue) -> Result<usize, String> {
+    match value {
+        RawValue::Number(n) => n.parse().map_err(|_| format!("0 이상의 정수여야 합니다: {}", n)),
+        _ => Err("숫자여야 합니다".to_string()),
+    }
+}
+
+fn expect_list(value: RawValue) -> Result<Vec<String>, String> {
+    match value {
+        RawValue::List(items) => Ok(items),
+        RawValue::Str(s) => Ok(vec![s]),
+        _ => Err("문자열 배열이어야 합니다".to_string()),
+    }
+}
+
+// ============================================
+// TOML 부분 집합 파서
+// 지원: 주석, [table], key = "문자열" | 'literal' | true/false | 숫자 | ["배열"]
+// 결과: (키, 값, 줄 번호). [rules] 같은 테이블은 RawValue::Table로 묶음
+// ============================================
+fn parse_toml(text: &str) -> Result<Entries, (usize, String)> {
+    let mut top: Entries = Vec::new();
+    let mut current_table: Option<usize> = None;
+    let mut lines = text.lines().enumerate().peekable();
+
+    while let Some((index, raw_line)) = lines.next() {
+        let line_no = index + 1;
+        let line = strip_comment(raw_line).trim().to_string();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            let name = line
+                .strip_prefix('[')
+                .and_then(|l| l.strip_suffix(']'))
+                .map(str::trim)
+                .filter(|n| !n.is_empty() && !n.starts_with('['))
+                .ok_or((line_no, format!("잘못된 테이블 헤더: {}", line)))?;
+            if top.iter().any(|(k, _, _)| k == name) {
+                return Err((line_no, format!("중복된 테이블: {}", name)));
+            }
+            top.push((name.to_string(), RawValue::Table(Vec::new()), line_no));
+            current_table = Some(top.len() - 1);
+            continue;
+        }
+
+        let (key, value_text) = line
+            .split_once('=')
+            .ok_or((line_no, format!("`key = value` 형식이 아닙니다: {}", line)))?;
+        let key = key.trim().trim_matches('"').to_string();
+        let mut value_text = value_text.trim().to_string();
+
+        // 여러 줄에 걸친 배열
+        while value_text.starts_with('[') && !brackets_closed(&value_text) {
+            match lines.next() {
+                Some((_, next)) => {
+                    value_text.push(' ');
+                    value_text.push_str(strip_comment(next).trim());
+                }
+                None => return Err((line_no, format!("`{}`: 배열이 닫히지 않았습니다", key))),
+            }
+        }
+
+        let value = parse_toml_value(&value_text).map_err(|message| (line_no, format!("`{}`: {}", key, message)))?;
+        let target = match current_table {
+            Some(i) => match &mut top[i].1 {
+                RawValue::Table(entries) => entries,
+                _ => unreachable!(),
+            },
+            None => &mut top,
+        };
+        if target.iter().any(|(k, _, _)| *k == key) {
+            return Err((line_no, format!("`{}`: 중복된 키", key)));
+        }
+        target.push((key, value, line_no));
+    }
+    Ok(top)
+}
+
+// 문자열 안의 #은 주석이 아님
+fn strip_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn brackets_closed(text: &str) -> bool {
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    for c in text.chars() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            _ => {}
+        }
+    }
+    depth <= 0
+}
+
+fn parse_toml_value(text: &str) -> Result<RawValue, String> {
+    if text == "true" || text == "false" {
+        return Ok(RawValue::Other);
+    }
+    if let Some(s) = parse_toml_string(text) {
+        return s.map(RawValue::Str);
+    }
+    if let Some(inner) = text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        let mut items = Vec::new();
+        for item in split_array(inner) {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            match parse_toml_string(item) {
+                Some(s) => items.push(s?),
+                None => return Err(format!("배열에는 문자열만 허용됩니다: {}", item)),
+            }
+        }
+        return Ok(RawValue::List(items));
+    }
+    if text.parse::<f64>().is_ok() {
+        return Ok(RawValue::Number(text.to_string()));
+    }
+    Err(format!("값을 해석할 수 없습니다: {}", text))
+}
+
+fn parse_toml_string(text: &str) -> Option<Result<String, String>> {
+    if let Some(inner) = text.strip_prefix('\'') {
+        return Some(inner.strip_suffix('\'').map(String::from).ok_or_else(|| "닫히지 않은 문자열".to_string()));
+    }
+    let inner = text.strip_prefix('"')?;
+    let inner = match inner.strip_suffix('"') {
+        Some(inner) => inner,
+        None => return Some(Err("닫히지 않은 문자열".to_string())),
+    };
+    // 기본 문자열의 이스케이프는 JSON과 같음
+    Some(
+        json::parse(&format!("\"{}\"", inner))
+            .ok()
+            .and_then(|v| v.as_str().map(String::from))
+            .ok_or_else(|| "잘못된 이스케이프".to_string()),
+    )
+}
+
+fn split_array(inner: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, ',') => {
+                parts.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&inner[start..]);
+    parts
+}
+
+// ============================================
+// CLI 플래그 병합: 주어진 플래그만 덮어씀
+// ============================================
+#[derive(Default)]
+pub struct CliOverrides {
+    pub key_strategy: Option<KeyStrategy>,
+    pub framework: Option<Framework>,
+}
+
+impl Config {
+    pub fn merge_cli(mut self, overrides: CliOverrides) -> Self {
+        if let Some(strategy) = overrides.key_strategy {
+            self.key_strategy = strategy;
+        }
+        if let Some(framework) = overrides.framework {
+            self.framework = framework;
+        }
+        self
+    }
+
+    // include/exclude 패턴에 맞는 소스 파일
+    // CLI 패턴이 있으면 include 대신 사용 (현재 디렉토리 기준), exclude는 항상 적용
+    // 두 번째 값은 읽지 못해 건너뛴 디렉토리 경고
+    pub fn source_files(&self, cli_patterns: &[String]) -> std::io::Result<(Vec<PathBuf>, Vec<Diagnostic>)> {
+        if cli_patterns.is_empty() {
+            glob::expand(&self.root, &self.include, &self.exclude)
+        } else {
+            glob::expand(Path::new(""), cli_patterns, &self.exclude)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    // 디렉토리는 테스트가 끝날 때까지 들고 있어야 함
+    fn write_temp(name: &str, content: &str) -> (TempDir, PathBuf) {
+        let dir = TempDir::new("config");
+        let path = dir.write(if name.ends_with("json") { "package.json" } else { CONFIG_FILE }, content);
+        (dir, path)
+    }
+
+    #[test]
+    fn test_load_toml() {
+        let (_dir, path) = write_temp(
+            "ok",
+            r#"
+# 프로젝트 설정
+include = ["src/**/*.{ts,tsx}"]
+exclude = [
+  "**/*.test.tsx",  # 테스트 제외
+]
+locales = ["en", "ko"]
+framework = "vue-i18n"
+key_strategy = "hash"
+catalog = "public/locales/{locale}/translation.json"
+
+[rules]
+hardcoded-string = "warn"
+
+[key_naming]
+case = "camel"
+max_depth = 2
+reserved = ["default"]
+"#,
+        );
+        let config = load(&path).unwrap();
+        assert_eq!(config.include, vec!["src/**/*.{ts,tsx}"]);
+        assert_eq!(config.exclude, vec!["**/*.test.tsx"]);
+        assert_eq!(config.key_strategy, KeyStrategy::Hash);
+        assert_eq!(config.framework, Framework::VueI18n);
+        assert_eq!(config.rule_level("hardcoded-string", RuleLevel::Error), RuleLevel::Warn);
+        assert!(config.catalog_path("ko").ends_with("public/locales/ko/translation.json"));
+        assert_eq!(config.key_naming.case, Some(CaseStyle::Camel));
+        assert_eq!(config.key_naming.max_depth, Some(2));
+        assert_eq!(config.key_naming.reserved, vec!["default"]);
+    }
+
+    #[test]
+    fn test_toml_error_reports_line_and_key() {
+        let (_dir, path) = write_temp("bad", "locales = [\"en\"]\n\nkey_strategy = \"md5\"\n");
+        let err = load(&path).unwrap_err();
+        assert_eq!((err.line, err.key.as_str()), (3, "key_strategy"));
+    }
+
+    #[test]
+    fn test_table_entry_error_reports_entry_line_and_key() {
+        let (_dir, path) = write_temp("rule-typo", "[rules]\nhardcoded-string = \"warn\"\nhardcoded-strng = \"off\"\n");
+        let err = load(&path).unwrap_err();
+        assert_eq!((err.line, err.key.as_str()), (3, "rules.hardcoded-strng"));
+        assert!(err.message.contains("알 수 없는 규칙"));
+
+        let (_dir, path) = write_temp("rule-level", "[rules]\n\nhardcoded-string = \"loud\"\n");
+        let err = load(&path).unwrap_err();
+        assert_eq!((err.line, err.key.as_str()), (3, "rules.hardcoded-string"));
+
+        let (_dir, path) = write_temp("naming", "[key_naming]\ncase = \"snake\"\nmax_depth = 0\n");
+        let err = load(&path).unwrap_err();
+        assert_eq!((err.line, err.key.as_str()), (3, "key_naming.max_depth"));
+
+        let (_dir, path) = write_temp("framework", "locales = [\"en\"]\nframework = \"angular\"\n");
+        let err = load(&path).unwrap_err();
+        assert_eq!((err.line, err.key.as_str()), (2, "framework"));
+    }
+
+    #[test]
+    fn test_package_json_error_reports_line_and_key() {
+        let (_dir, path) = write_temp(
+            "pkg.json",
+            "{\n  \"name\": \"app\",\n  \"i18nWrapper\": {\n    \"sourceLocale\": \"en\",\n    \"keyStrategy\": 3\n  }\n}\n",
+        );
+        let err = load(&path).unwrap_err();
+        assert_eq!((err.line, err.key.as_str()), (5, "keyStrategy"));
+
+        let (_dir, path) = write_temp(
+            "pkg-rules.json",
+            "{\n  \"i18nWrapper\": {\n    \"rules\": {\n      \"no-such-rule\": \"warn\"\n    }\n  }\n}\n",
+        );
+        let err = load(&path).unwrap_err();
+        assert_eq!((err.line, err.key.as_str()), (4, "rules.no-such-rule"));
+    }
+}
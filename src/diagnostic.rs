@@ -0,0 +1,339 @@
+// ============================================
+// i18n-wrapper: 진단 메시지 (rustc 스타일)
+// error_handling.rs의 parse_number처럼 String 에러를 그대로 출력하는 대신
+// 심각도, 에러 코드, 위치(span), 보조 라벨, 도움말을 함께 보관하고
+// swc_common::SourceMap으로 줄/열을 찾아 소스 일부와 캐럿(^)을 그려줌
+//
+// error[I18N001]: 파싱 실패: Unexpected token
+//   --> src/App.tsx:3:10
+//    |
+//  3 |   return <p>Hello</p
+//    |                     ^ 여기
+//    |
+//    = help: JSX 태그가 닫혔는지 확인하세요
+// ============================================
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::transform;
+
+// 에러 코드 (JSON/SARIF 리포트에서도 같은 값 사용)
+pub const PARSE_ERROR: &str = "I18N001";
+pub const IO_ERROR: &str = "I18N002";
+pub const TIMEOUT: &str = "I18N003";
+pub const CATALOG_ERROR: &str = "I18N004";
+pub const WRITE_ERROR: &str = "I18N005";
+pub const TRANSLATE_ERROR: &str = "I18N006";
+// lint 규칙 (lint::RULES)
+pub const HARDCODED_STRING: &str = "I18N100";
+pub const UNRESOLVED_IMPORT: &str = "I18N101";
+pub const PLACEHOLDER_MISMATCH: &str = "I18N102";
+pub const KEY_NAMING: &str = "I18N103";
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+
+    fn color(&self) -> &'static str {
+        match self {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+        }
+    }
+}
+
+// 원본 소스 기준 바이트 오프셋
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: String,
+    pub message: String,
+    pub path: PathBuf,
+    pub primary: Option<Label>,
+    pub secondary: Vec<Label>,
+    pub help: Vec<String>,
+    // 스니펫을 그리려면 필요 (없으면 헤더와 경로만)
+    pub source: Option<Arc<str>>,
+    // 줄이 밀려도 같은 문제를 같은 값으로 식별 (baseline, SARIF partialFingerprints)
+    pub fingerprint: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &str, message: impl Into<String>, path: &Path) -> Self {
+        Self {
+            severity,
+            code: code.to_string(),
+            message: message.into(),
+            path: path.to_path_buf(),
+            primary: None,
+            secondary: Vec::new(),
+            help: Vec::new(),
+            source: None,
+            fingerprint: None,
+        }
+    }
+
+    pub fn error(code: &str, message: impl Into<String>, path: &Path) -> Self {
+        Self::new(Severity::Error, code, message, path)
+    }
+
+    pub fn warning(code: &str, message: impl Into<String>, path: &Path) -> Self {
+        Self::new(Severity::Warning, code, message, path)
+    }
+
+    pub fn with_source(mut self, source: Arc<str>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn with_primary(mut self, start: usize, end: usize, message: impl Into<String>) -> Self {
+        self.primary = Some(Label {
+            start,
+            end,
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn with_label(mut self, start: usize, end: usize, message: impl Into<String>) -> Self {
+        self.secondary.push(Label {
+            start,
+            end,
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help.push(help.into());
+        self
+    }
+
+    // 위치(줄/열) 대신 의미 있는 값들로 만든 해시
+    pub fn with_fingerprint(mut self, parts: &[&str]) -> Self {
+        let joined = std::iter::once(self.code.as_str()).chain(parts.iter().copied()).collect::<Vec<_>>().join("\0");
+        self.fingerprint = Some(format!("{:016x}", transform::text_hash(&joined)));
+        self
+    }
+
+    // 주 위치의 (줄, 열), 1부터 시작
+    pub fn location(&self) -> Option<(usize, usize)> {
+        let source = self.source.as_deref()?;
+        let primary = self.primary.as_ref()?;
+        Some(transform::line_col(source, primary.start.min(source.len())))
+    }
+
+    // 주 위치 끝의 (줄, 열), 끝 위치는 포함하지 않음
+    pub fn end_location(&self) -> Option<(usize, usize)> {
+        let source = self.source.as_deref()?;
+        let primary = self.primary.as_ref()?;
+        Some(transform::line_col(source, primary.end.min(source.len())))
+    }
+
+    // ============================================
+    // 출력: color가 false면 ANSI 코드 없이 (CI 로그용)
+    // ============================================
+    pub fn render(&self, color: bool) -> String {
+        let paint = |code: &str, text: &str| {
+            if color {
+                format!("{}{}{}", code, text, RESET)
+            } else {
+                text.to_string()
+            }
+        };
+
+        let mut out = String::new();
+        out.push_str(&paint(self.severity.color(), &format!("{}[{}]", self.severity.as_str(), self.code)));
+        out.push_str(&paint(BOLD, &format!(": {}", self.message)));
+        out.push('\n');
+
+        let lines = self.snippet_lines();
+        let gutter = lines.iter().map(|l| l.line.to_string().len()).max().unwrap_or(1);
+        let pad = " ".repeat(gutter);
+        let location = match self.location() {
+            Some((line, column)) => format!("{}:{}:{}", self.path.display(), line, column),
+            None => self.path.display().to_string(),
+        };
+        out.push_str(&format!("{}{} {}\n", pad, paint(BLUE, "-->"), location));
+
+        if !lines.is_empty() {
+            out.push_str(&format!("{} {}\n", pad, paint(BLUE, "|")));
+            let mut previous: Option<usize> = None;
+            for line in &lines {
+                if previous != Some(line.line) {
+                    if previous.is_some_and(|p| line.line > p + 1) {
+                        out.push_str(&format!("{}\n", paint(BLUE, "...")));
+                    }
+                    let number = format!("{:>width$} |", line.line, width = gutter);
+                    out.push_str(&format!("{} {}\n", paint(BLUE, &number), line.text));
+                }
+                previous = Some(line.line);
+                let marker = if line.primary { "^" } else { "-" };
+                let underline = format!("{}{}", " ".repeat(line.column), marker.repeat(line.width));
+                let underline = if line.message.is_empty() {
+                    underline
+                } else {
+                    format!("{} {}", underline, line.message)
+                };
+                let marker_color = if line.primary { self.severity.color() } else { BLUE };
+                out.push_str(&format!("{} {} {}\n", pad, paint(BLUE, "|"), paint(marker_color, &underline)));
+            }
+        }
+
+        if !self.help.is_empty() {
+            out.push_str(&format!("{} {}\n", pad, paint(BLUE, "|")));
+            for help in &self.help {
+                out.push_str(&format!("{} {} {}\n", pad, paint(BLUE, "="), paint(BOLD, &format!("help: {}", help))));
+            }
+        }
+        out
+    }
+
+    #[cfg(feature = "swc")]
+    fn snippet_lines(&self) -> Vec<SnippetLine> {
+        use swc_common::{sync::Lrc, BytePos, FileName, SourceMap};
+
+        let source = match &self.source {
+            Some(source) => source,
+            None => return Vec::new(),
+        };
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(
+            Lrc::new(FileName::Custom(self.path.to_string_lossy().to_string())),
+            source.to_string(),
+        );
+        // SourceMap은 BOM을 제거하므로 원본 오프셋에서 빼줌
+        let bom = if source.starts_with('\u{feff}') { 3 } else { 0 };
+        let max = fm.end_pos.0 - fm.start_pos.0;
+        let pos = |offset: usize| BytePos(fm.start_pos.0 + (offset.saturating_sub(bom) as u32).min(max));
+
+        let labels = self.primary.iter().map(|l| (l, true)).chain(self.secondary.iter().map(|l| (l, false)));
+        let mut lines: Vec<SnippetLine> = labels
+            .map(|(label, primary)| {
+                let lo = cm.lookup_char_pos(pos(label.start));
+                let hi = cm.lookup_char_pos(pos(label.end));
+                let text = fm.get_line(lo.line - 1).map(|l| l.trim_end().to_string()).unwrap_or_default();
+                // 여러 줄에 걸친 span은 첫 줄 끝까지만 표시
+                let end = if hi.line == lo.line { hi.col.0 } else { text.chars().count() };
+                SnippetLine {
+                    line: lo.line,
+                    column: lo.col.0,
+                    width: end.saturating_sub(lo.col.0).max(1),
+                    text,
+                    primary,
+                    message: label.message.clone(),
+                }
+            })
+            .collect();
+        lines.sort_by_key(|l| (l.line, !l.primary, l.column));
+        lines
+    }
+
+    #[cfg(not(feature = "swc"))]
+    fn snippet_lines(&self) -> Vec<SnippetLine> {
+        Vec::new()
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.location() {
+            Some((line, column)) => write!(f, "{}:{}:{}: {}", self.path.display(), line, column, self.message),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+struct SnippetLine {
+    line: usize,
+    // 0부터 시작하는 문자 단위 열
+    column: usize,
+    width: usize,
+    text: String,
+    primary: bool,
+    message: String,
+}
+
+// 진단은 stderr로 출력하므로 stderr 기준으로 판단
+pub fn use_color() -> bool {
+    use std::io::IsTerminal;
+    std::env::var_os("NO_COLOR").is_none() && std::io::stderr().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_render_snippet() {
+        let source: Arc<str> = Arc::from("const a = 1;\nconst title = <h1>Hello</h1>;\n");
+        let start = source.find("Hello").unwrap();
+        let diagnostic = Diagnostic::warning("I18N100", "하드코딩된 문자열", Path::new("src/App.jsx"))
+            .with_source(source.clone())
+            .with_primary(start, start + 5, "번역되지 않음")
+            .with_label(6, 7, "관련 없는 변수")
+            .with_help("t('app.hello')로 감싸세요");
+
+        let expected = "\
+warning[I18N100]: 하드코딩된 문자열
+ --> src/App.jsx:2:19
+  |
+1 | const a = 1;
+  |       - 관련 없는 변수
+2 | const title = <h1>Hello</h1>;
+  |                   ^^^^^ 번역되지 않음
+  |
+  = help: t('app.hello')로 감싸세요
+";
+        assert_eq!(diagnostic.render(false), expected);
+        assert_eq!(diagnostic.to_string(), "src/App.jsx:2:19: 하드코딩된 문자열");
+    }
+
+    #[test]
+    fn test_render_without_source() {
+        // 도구 에러(파일 읽기 실패 등)는 소스 없이 경로만
+        let diagnostic = Diagnostic::error(IO_ERROR, "파일을 읽을 수 없습니다", Path::new("src/Gone.jsx"))
+            .with_primary(3, 8, "무시됨")
+            .with_help("경로를 확인하세요");
+        assert_eq!(diagnostic.location(), None);
+        assert_eq!(
+            diagnostic.render(false),
+            "error[I18N002]: 파일을 읽을 수 없습니다\n --> src/Gone.jsx\n  |\n  = help: 경로를 확인하세요\n"
+        );
+        assert_eq!(diagnostic.to_string(), "src/Gone.jsx: 파일을 읽을 수 없습니다");
+
+        // 지문은 위치와 무관 (baseline 비교용)
+        let a = Diagnostic::error(HARDCODED_STRING, "a", Path::new("a.jsx")).with_fingerprint(&["a.jsx", "Save"]);
+        let b = Diagnostic::error(HARDCODED_STRING, "b", Path::new("a.jsx"))
+            .with_source(Arc::from("\n\nSave"))
+            .with_primary(2, 6, "")
+            .with_fingerprint(&["a.jsx", "Save"]);
+        assert_eq!(a.fingerprint, b.fingerprint);
+    }
+}
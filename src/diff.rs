@@ -0,0 +1,366 @@
+// ============================================
+// i18n-wrapper: unified diff 생성
+// TypeScript의 `diff` 패키지 (createTwoFilesPatch) 대체
+// Myers 알고리즘으로 줄 단위 편집 스크립트를 구한 뒤 hunk로 묶음
+// ============================================
+
+use crate::json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hunk {
+    // 1부터 시작하는 줄 번호 (unified diff 표기와 동일)
+    pub old_start: usize,
+    pub old_len: usize,
+    pub new_start: usize,
+    pub new_len: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffFormat {
+    Text,
+    Json,
+}
+
+impl DiffFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(DiffFormat::Text),
+            "json" => Some(DiffFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    Keep(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+// ============================================
+// 줄 단위 diff → hunk 목록
+// ============================================
+pub fn diff_lines(old: &str, new: &str, context: usize) -> Vec<Hunk> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let edits = myers(&old_lines, &new_lines);
+
+    // 변경된 edit의 인덱스 주변 context 줄만 남겨 hunk로 묶기
+    let changed: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !matches!(e, Edit::Keep(..)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut hunks = Vec::new();
+    let mut i = 0;
+    while i < changed.len() {
+        let start = changed[i].saturating_sub(context);
+        let mut end = changed[i];
+        // 다음 변경이 context*2 이내면 같은 hunk로 합침
+        while i + 1 < changed.len() && changed[i + 1] <= end + 2 * context + 1 {
+            i += 1;
+            end = changed[i];
+        }
+        let end = (end + context + 1).min(edits.len());
+        hunks.push(build_hunk(
+            &edits[start..end],
+            &old_lines,
+            &new_lines,
+            position_before(&edits, start),
+        ));
+        i += 1;
+    }
+    hunks
+}
+
+fn build_hunk(edits: &[Edit], old_lines: &[&str], new_lines: &[&str], before: (usize, usize)) -> Hunk {
+    let mut hunk = Hunk {
+        old_start: before.0 + 1,
+        old_len: 0,
+        new_start: before.1 + 1,
+        new_len: 0,
+        lines: Vec::new(),
+    };
+
+    for edit in edits {
+        match *edit {
+            Edit::Keep(o, _) => {
+                hunk.lines.push(DiffLine::Context(old_lines[o].to_string()));
+                hunk.old_len += 1;
+                hunk.new_len += 1;
+            }
+            Edit::Delete(o) => {
+                hunk.lines.push(DiffLine::Removed(old_lines[o].to_string()));
+                hunk.old_len += 1;
+            }
+            Edit::Insert(n) => {
+                hunk.lines.push(DiffLine::Added(new_lines[n].to_string()));
+                hunk.new_len += 1;
+            }
+        }
+    }
+
+    // 빈 쪽의 시작 줄은 관례상 "직전 줄" 번호를 사용
+    if hunk.old_len == 0 {
+        hunk.old_start -= 1;
+    }
+    if hunk.new_len == 0 {
+        hunk.new_start -= 1;
+    }
+    hunk
+}
+
+// edits[..index] 까지 소비한 (old, new) 줄 수
+fn position_before(edits: &[Edit], index: usize) -> (usize, usize) {
+    edits[..index].iter().fold((0, 0), |(o, n), edit| match edit {
+        Edit::Keep(..) => (o + 1, n + 1),
+        Edit::Delete(_) => (o + 1, n),
+        Edit::Insert(_) => (o, n + 1),
+    })
+}
+
+// ============================================
+// Myers O(ND) diff
+// 역추적용으로 단계 d가 끝난 v에서 대각선 -d..=d 구간만 보관 (O(D²) 메모리)
+// v 전체를 단계마다 복사하면 O(D·N)이라 큰 파일에서 메모리가 커짐
+// ============================================
+fn myers(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    let n = a.len() as isize;
+    let m = b.len() as isize;
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'outer: for d in 0..=max as isize {
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'outer;
+            }
+            k += 2;
+        }
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+    }
+
+    // 역추적으로 편집 스크립트 복원: 단계 d의 끝점에서 단계 d - 1의 구간(trace[d - 1])으로
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (1..=trace.len() as isize).rev() {
+        let frontier = &trace[(d - 1) as usize];
+        // frontier[0]은 대각선 -(d - 1)
+        let at = |k: isize| frontier[(k + d - 1) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Keep(x as usize, y as usize));
+        }
+        if x == prev_x {
+            y -= 1;
+            edits.push(Edit::Insert(y as usize));
+        } else {
+            x -= 1;
+            edits.push(Edit::Delete(x as usize));
+        }
+    }
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        edits.push(Edit::Keep(x as usize, y as usize));
+    }
+    edits.reverse();
+    edits
+}
+
+// ============================================
+// 출력: 터미널용 unified diff (색상 선택)
+// ============================================
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+pub fn render_unified(path: &str, hunks: &[Hunk], color: bool) -> String {
+    let paint = |code: &str, text: &str| {
+        if color {
+            format!("{}{}{}", code, text, RESET)
+        } else {
+            text.to_string()
+        }
+    };
+
+    let mut out = String::new();
+    out.push_str(&paint(BOLD, &format!("--- a/{}", path)));
+    out.push('\n');
+    out.push_str(&paint(BOLD, &format!("+++ b/{}", path)));
+    out.push('\n');
+    for hunk in hunks {
+        let header = format!(
+            "@@ -{},{} +{},{} @@",
+            hunk.old_start, hunk.old_len, hunk.new_start, hunk.new_len
+        );
+        out.push_str(&paint(CYAN, &header));
+        out.push('\n');
+        for line in &hunk.lines {
+            match line {
+                DiffLine::Context(text) => out.push_str(&format!(" {}", text)),
+                DiffLine::Removed(text) => out.push_str(&paint(RED, &format!("-{}", text))),
+                DiffLine::Added(text) => out.push_str(&paint(GREEN, &format!("+{}", text))),
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+// ============================================
+// 출력: 도구 연동용 JSON hunk
+// ============================================
+pub fn hunks_to_json(path: &str, hunks: &[Hunk]) -> Value {
+    let hunks = hunks
+        .iter()
+        .map(|hunk| {
+            let lines = hunk
+                .lines
+                .iter()
+                .map(|line| {
+                    let (kind, text) = match line {
+                        DiffLine::Context(t) => ("context", t),
+                        DiffLine::Removed(t) => ("removed", t),
+                        DiffLine::Added(t) => ("added", t),
+                    };
+                    Value::Object(vec![
+                        ("type".to_string(), kind.into()),
+                        ("text".to_string(), text.as_str().into()),
+                    ])
+                })
+                .collect();
+            Value::Object(vec![
+                ("oldStart".to_string(), hunk.old_start.into()),
+                ("oldLines".to_string(), hunk.old_len.into()),
+                ("newStart".to_string(), hunk.new_start.into()),
+                ("newLines".to_string(), hunk.new_len.into()),
+                ("lines".to_string(), Value::Array(lines)),
+            ])
+        })
+        .collect();
+    Value::Object(vec![
+        ("path".to_string(), path.into()),
+        ("hunks".to_string(), Value::Array(hunks)),
+    ])
+}
+
+// NO_COLOR 환경 변수를 존중하고, 터미널일 때만 색상 사용
+pub fn use_color() -> bool {
+    use std::io::IsTerminal;
+    std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_change() {
+        let old = "a\nb\nc\nd\ne\n";
+        let new = "a\nb\nC\nd\ne\n";
+        let hunks = diff_lines(old, new, 1);
+        assert_eq!(hunks.len(), 1);
+        let hunk = &hunks[0];
+        assert_eq!((hunk.old_start, hunk.old_len, hunk.new_start, hunk.new_len), (2, 3, 2, 3));
+        assert_eq!(
+            hunk.lines,
+            vec![
+                DiffLine::Context("b".into()),
+                DiffLine::Removed("c".into()),
+                DiffLine::Added("C".into()),
+                DiffLine::Context("d".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_far_changes_split_into_hunks() {
+        let old: String = (0..20).map(|i| format!("{}\n", i)).collect();
+        let new = old.replace("2\n", "two\n").replace("17\n", "seventeen\n");
+        assert_eq!(diff_lines(&old, &new, 3).len(), 2);
+        assert_eq!(diff_lines(&old, &new, 10).len(), 1);
+    }
+
+    #[test]
+    fn test_insert_at_start() {
+        let hunks = diff_lines("a\nb\n", "import x;\na\nb\n", 0);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!((hunks[0].old_start, hunks[0].old_len, hunks[0].new_start, hunks[0].new_len), (0, 0, 1, 1));
+    }
+
+    #[test]
+    fn test_edit_script_rebuilds_both_sides() {
+        let old: Vec<String> = (0..200).map(|i| format!("line {}", i % 7)).collect();
+        let new: Vec<String> = (0..180).map(|i| format!("line {}", (i * 3) % 11)).collect();
+        let a: Vec<&str> = old.iter().map(String::as_str).collect();
+        let b: Vec<&str> = new.iter().map(String::as_str).collect();
+        let (mut kept_a, mut kept_b) = (Vec::new(), Vec::new());
+        let (mut deleted, mut inserted) = (0, 0);
+        for edit in myers(&a, &b) {
+            match edit {
+                Edit::Keep(i, j) => {
+                    assert_eq!(a[i], b[j]);
+                    kept_a.push(i);
+                    kept_b.push(j);
+                }
+                Edit::Delete(_) => deleted += 1,
+                Edit::Insert(_) => inserted += 1,
+            }
+        }
+        assert_eq!(kept_a.len() + deleted, a.len());
+        assert_eq!(kept_b.len() + inserted, b.len());
+        assert!(kept_a.windows(2).all(|w| w[0] < w[1]) && kept_b.windows(2).all(|w| w[0] < w[1]));
+
+        // 최소 편집: 남긴 줄 수가 최장 공통 부분 수열 길이와 같음
+        let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+        for i in (0..a.len()).rev() {
+            for j in (0..b.len()).rev() {
+                lcs[i][j] = if a[i] == b[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+            }
+        }
+        assert_eq!(kept_a.len(), lcs[0][0]);
+    }
+
+    #[test]
+    fn test_identical_has_no_hunks() {
+        assert!(diff_lines("same\n", "same\n", 3).is_empty());
+    }
+}
@@ -0,0 +1,557 @@
+// ============================================
+// i18n-wrapper: 중복 원문 찾기 (duplicates 명령)
+// 같은 원문("Cancel")이 여러 키에 흩어져 있으면 번역도 여러 번 해야 하므로
+// 원문을 정규화해서 묶고, 묶음마다 키와 코드 사용 위치를 보고
+//   정규화: 대소문자 / 공백 / 구두점 차이를 무시 (각각 --match-* 로 구분하게 바꿀 수 있음)
+//   --consolidate: 코드의 키 참조를 묶음마다 대표 키 하나로 바꿈 (rename-key와 같은 리터럴 교체)
+//                  `// i18n: ...` 설명이 서로 다르면 뜻이 다른 것이므로 그 묶음은 건드리지 않음
+//                  대표 키에 없는 번역은 이전 키에서 복사, 두 키의 번역이 다르면 그 키는 합치지 않고 보고
+// 카탈로그에 남은 옛 키는 prune으로 정리
+// TypeScript:
+// const groups = _.groupBy(Object.entries(en), ([, text]) => text.trim().toLowerCase());
+// ============================================
+
+use std::path::PathBuf;
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+use crate::extract::KeyReference;
+use crate::json::Value;
+use crate::process::FileChange;
+use crate::rename;
+// run_duplicates 전용
+#[cfg(feature = "swc")]
+use std::fs;
+#[cfg(feature = "swc")]
+use std::path::Path;
+#[cfg(feature = "swc")]
+use crate::extract;
+#[cfg(feature = "swc")]
+use crate::json;
+#[cfg(feature = "swc")]
+use crate::process::{self, ProcessOptions};
+
+// true면 그 차이를 무시하고 같은 원문으로 봄
+#[derive(Debug, Clone, Copy)]
+pub struct Normalize {
+    pub case: bool,
+    pub whitespace: bool,
+    pub punctuation: bool,
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self {
+            case: true,
+            whitespace: true,
+            punctuation: true,
+        }
+    }
+}
+
+// {{name}} 안은 구두점이 아니므로 그대로 둠
+pub fn normalize(text: &str, options: &Normalize) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") {
+            let len = rest.find("}}").map(|close| close + 2).unwrap_or(rest.len());
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+        let is_punctuation = !c.is_alphanumeric() && !c.is_whitespace();
+        if !(options.punctuation && is_punctuation) {
+            out.push(c);
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    if options.whitespace {
+        out = out.split_whitespace().collect::<Vec<_>>().join(" ");
+    }
+    if options.case {
+        out = out.to_lowercase();
+    }
+    out
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateKey {
+    pub key: String,
+    pub text: String,
+    // 코드의 `// i18n:` 주석, 없으면 sidecar의 comment
+    pub context: Option<String>,
+    // "src/App.tsx:12"
+    pub locations: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    pub normalized: String,
+    pub keys: Vec<DuplicateKey>,
+    // 가장 많이 쓰이는 키 (같으면 카탈로그에서 먼저 나온 키)
+    pub canonical: String,
+    // 설명이 서로 다르면 (한쪽에만 설명이 있는 경우 포함) 뜻이 다른 것으로 보고 합치지 않음
+    pub contexts_differ: bool,
+}
+
+impl Cluster {
+    pub fn references(&self) -> usize {
+        self.keys.iter().map(|k| k.locations.len()).sum()
+    }
+
+    // 대표 키로 합칠 키: 기본은 원문이 대표 키와 글자 그대로 같은 키만
+    // normalized면 대소문자/공백/구두점만 다른 키도 ("Save!" -> "save"처럼 어조가 바뀔 수 있어서 직접 켤 때만)
+    pub fn mergeable(&self, normalized: bool) -> Vec<&DuplicateKey> {
+        if self.contexts_differ {
+            return Vec::new();
+        }
+        let canonical = self.keys.iter().find(|k| k.key == self.canonical).map(|k| k.text.as_str());
+        self.keys
+            .iter()
+            .filter(|k| k.key != self.canonical)
+            .filter(|k| normalized || Some(k.text.as_str()) == canonical)
+            .collect()
+    }
+}
+
+// entries: 원문 카탈로그 (키, 원문). 카탈로그에 없는 코드의 키는 기본값을 원문으로 사용
+// comments: sidecar의 (키, comment)
+pub fn find_duplicates(
+    entries: &[(String, String)],
+    references: &[KeyReference],
+    comments: &[(String, String)],
+    options: &Normalize,
+    location: impl Fn(&KeyReference) -> String,
+) -> Vec<Cluster> {
+    let mut keys: Vec<DuplicateKey> = entries
+        .iter()
+        .map(|(key, text)| DuplicateKey {
+            key: key.clone(),
+            text: text.clone(),
+            context: None,
+            locations: Vec::new(),
+        })
+        .collect();
+    for reference in references {
+        let index = match keys.iter().position(|k| k.key == reference.key) {
+            Some(index) => index,
+            None => match &reference.default {
+                Some(default) => {
+                    keys.push(DuplicateKey {
+                        key: reference.key.clone(),
+                        text: default.clone(),
+                        context: None,
+                        locations: Vec::new(),
+                    });
+                    keys.len() - 1
+                }
+                None => continue,
+            },
+        };
+        let entry = &mut keys[index];
+        let found = format!("{}:{}", location(reference), reference.line);
+        if !entry.locations.contains(&found) {
+            entry.locations.push(found);
+        }
+        if entry.context.is_none() {
+            entry.context = reference.context.clone();
+        }
+    }
+    for entry in keys.iter_mut().filter(|k| k.context.is_none()) {
+        entry.context = comments.iter().find(|(k, _)| *k == entry.key).map(|(_, c)| c.clone());
+    }
+
+    // 처음 나온 순서대로 묶음
+    let mut groups: Vec<(String, Vec<DuplicateKey>)> = Vec::new();
+    for entry in keys {
+        let normalized = normalize(&entry.text, options);
+        if normalized.is_empty() {
+            continue;
+        }
+        match groups.iter_mut().find(|(n, _)| *n == normalized) {
+            Some((_, members)) => members.push(entry),
+            None => groups.push((normalized, vec![entry])),
+        }
+    }
+
+    let mut clusters: Vec<Cluster> = groups
+        .into_iter()
+        .filter(|(_, members)| members.len() > 1)
+        .map(|(normalized, keys)| {
+            let canonical = keys
+                .iter()
+                .enumerate()
+                .max_by_key(|(index, k)| (k.locations.len(), std::cmp::Reverse(*index)))
+                .map(|(_, k)| k.key.clone())
+                .unwrap_or_default();
+            let mut contexts: Vec<Option<&str>> = keys.iter().map(|k| k.context.as_deref()).collect();
+            contexts.sort_unstable();
+            contexts.dedup();
+            Cluster {
+                normalized,
+                canonical,
+                contexts_differ: contexts.len() > 1,
+                keys,
+            }
+        })
+        .collect();
+    // 키가 많은 묶음부터 (같으면 처음 나온 순서, 안정 정렬)
+    clusters.sort_by_key(|c| std::cmp::Reverse(c.keys.len()));
+    clusters
+}
+
+// ============================================
+// --consolidate: 대표 키로 코드 참조 교체
+// ============================================
+pub struct ConsolidatePlan {
+    // 코드 파일, 그 다음 번역을 옮긴 카탈로그
+    pub changes: Vec<FileChange>,
+    // (이전 키, 대표 키), 코드에서 실제로 바뀐 키만
+    pub merged: Vec<(String, String)>,
+    pub references: usize,
+    // 대표 키로 복사한 번역 수
+    pub copied: usize,
+    // (이전 키, 대표 키, 카탈로그): 두 키의 번역이 서로 달라서 합치지 않은 키
+    pub skipped: Vec<(String, String, PathBuf)>,
+}
+
+// normalized: Cluster::mergeable 참고 (--normalized)
+// targets: 원문 카탈로그를 뺀 번역 카탈로그
+pub fn plan_consolidate(
+    clusters: &[Cluster],
+    references: &[KeyReference],
+    targets: &[Catalog],
+    normalized: bool,
+) -> Result<ConsolidatePlan, Box<Diagnostic>> {
+    let mapping: Vec<(String, String)> = clusters
+        .iter()
+        .flat_map(|c| {
+            c.mergeable(normalized)
+                .into_iter()
+                .map(|k| (k.key.clone(), c.canonical.clone()))
+        })
+        .filter(|(old, _)| references.iter().any(|r| r.key == *old))
+        .collect();
+
+    // 번역: 대표 키에 없으면 이전 키의 번역을 복사, 둘 다 있는데 다르면 그 키는 합치지 않음
+    // (앞에서 복사한 번역과도 비교하므로 같은 대표 키로 모이는 키끼리도 확인됨)
+    let translated = |catalog: &Catalog, key: &str| catalog.get(key).filter(|t| !t.is_empty()).map(String::from);
+    let mut catalogs = targets.to_vec();
+    let mut merged = Vec::new();
+    let mut skipped = Vec::new();
+    let mut copied = 0;
+    for (old, canonical) in mapping {
+        let conflict = catalogs.iter().find(|catalog| {
+            matches!(
+                (translated(catalog, &old), translated(catalog, &canonical)),
+                (Some(from), Some(to)) if from != to
+            )
+        });
+        if let Some(catalog) = conflict {
+            skipped.push((old, canonical, catalog.path.clone()));
+            continue;
+        }
+        for catalog in &mut catalogs {
+            if let (Some(text), None) = (translated(catalog, &old), translated(catalog, &canonical)) {
+                catalog.insert(&canonical, &text).map_err(|e| {
+                    Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, e.to_string(), &catalog.path))
+                })?;
+                copied += 1;
+            }
+        }
+        merged.push((old, canonical));
+    }
+
+    let target = |key: &str| merged.iter().find(|(old, _)| old == key).map(|(_, new)| new.clone());
+    let (mut changes, count) = rename::apply_literal_edits(references, target)?;
+    for (catalog, original) in catalogs.iter().zip(targets) {
+        let (before, after) = (original.render(), catalog.render());
+        if after != before {
+            changes.push(FileChange {
+                path: catalog.path.clone(),
+                original: before,
+                updated: after,
+                wraps: Vec::new(),
+            });
+        }
+    }
+    Ok(ConsolidatePlan {
+        changes,
+        merged,
+        references: count,
+        copied,
+        skipped,
+    })
+}
+
+// ============================================
+// 출력
+// ============================================
+pub fn print_clusters(clusters: &[Cluster], normalized: bool) {
+    for cluster in clusters {
+        println!("{:?} (키 {}개, 참조 {}개)", cluster.keys[0].text, cluster.keys.len(), cluster.references());
+        for entry in &cluster.keys {
+            let mark = if entry.key == cluster.canonical { '*' } else { ' ' };
+            let locations = if entry.locations.is_empty() {
+                "(코드에서 사용 안 함)".to_string()
+            } else {
+                entry.locations.join(", ")
+            };
+            println!("  {} {} {:?}  {}", mark, entry.key, entry.text, locations);
+            if let Some(context) = &entry.context {
+                println!("      i18n: {}", context);
+            }
+        }
+        if cluster.contexts_differ {
+            println!("  ! 번역 설명이 서로 달라서 합치지 않습니다");
+        } else if cluster.mergeable(normalized).len() < cluster.keys.len() - 1 {
+            println!("  ! 원문이 대표 키와 글자 그대로 같지 않은 키는 --normalized 없이 합치지 않습니다");
+        }
+        println!();
+    }
+    let keys: usize = clusters.iter().map(|c| c.keys.len()).sum();
+    let mergeable: usize = clusters.iter().map(|c| c.mergeable(normalized).len()).sum();
+    println!("요약: 중복 묶음 {}개, 키 {}개 (대표 키로 합칠 수 있는 키 {}개)", clusters.len(), keys, mergeable);
+}
+
+pub fn to_json(clusters: &[Cluster]) -> Value {
+    Value::Array(
+        clusters
+            .iter()
+            .map(|cluster| {
+                let keys = cluster
+                    .keys
+                    .iter()
+                    .map(|entry| {
+                        let mut fields = vec![
+                            ("key".to_string(), entry.key.as_str().into()),
+                            ("text".to_string(), entry.text.as_str().into()),
+                        ];
+                        if let Some(context) = &entry.context {
+                            fields.push(("context".to_string(), context.as_str().into()));
+                        }
+                        let locations = entry.locations.iter().map(|l| l.as_str().into()).collect();
+                        fields.push(("locations".to_string(), Value::Array(locations)));
+                        Value::Object(fields)
+                    })
+                    .collect();
+                Value::Object(vec![
+                    ("normalized".to_string(), cluster.normalized.as_str().into()),
+                    ("canonical".to_string(), cluster.canonical.as_str().into()),
+                    ("contextsDiffer".to_string(), Value::Bool(cluster.contexts_differ)),
+                    ("keys".to_string(), Value::Array(keys)),
+                ])
+            })
+            .collect(),
+    )
+}
+
+// ============================================
+// CLI 진입점: 스캔 → 묶기 → 보고 (--consolidate면 process::apply_changes)
+// ============================================
+#[cfg(feature = "swc")]
+pub fn run_duplicates(
+    options: &ProcessOptions,
+    normalize: &Normalize,
+    json_output: bool,
+    consolidate: bool,
+    normalized: bool,
+    // 번역 카탈로그 (--consolidate가 번역을 대표 키로 옮김)
+    targets: &[PathBuf],
+    location: impl Fn(&KeyReference) -> String,
+) -> Vec<Diagnostic> {
+    let (references, mut errors) = extract::scan_files(&options.files, options.framework);
+    let color = diagnostic::use_color();
+    // 파싱하지 못한 파일의 참조는 바꿀 수 없으므로 합치지 않음
+    if consolidate && !errors.is_empty() {
+        for diagnostic in &errors {
+            eprintln!("{}", diagnostic.render(color));
+        }
+        eprintln!("duplicates: 파싱 에러가 있어서 --consolidate를 중단합니다");
+        return errors;
+    }
+    let catalog = match Catalog::load_diagnostic(&options.catalog_path) {
+        Ok(catalog) => catalog,
+        Err(e) => {
+            errors.push(*e);
+            for diagnostic in &errors {
+                eprintln!("{}", diagnostic.render(color));
+            }
+            return errors;
+        }
+    };
+    let comments = sidecar_comments(&extract::meta_path(&options.catalog_path));
+    let clusters = find_duplicates(&catalog.entries(), &references, &comments, normalize, location);
+
+    if json_output {
+        println!("{}", to_json(&clusters).to_pretty_string("  "));
+    } else {
+        print_clusters(&clusters, normalized);
+    }
+
+    if consolidate {
+        let loaded: Result<Vec<Catalog>, Box<Diagnostic>> =
+            targets.iter().map(|path| Catalog::load_diagnostic(path)).collect();
+        match loaded.and_then(|loaded| plan_consolidate(&clusters, &references, &loaded, normalized)) {
+            Ok(plan) => {
+                errors.extend(process::apply_changes(options, &plan.changes));
+                println!();
+                println!("합치기:");
+                for (old, new) in &plan.merged {
+                    println!("  {} -> {}", old, new);
+                }
+                for (old, new, path) in &plan.skipped {
+                    println!("  ! {} -> {}: {}의 번역이 서로 달라서 합치지 않음", old, new, path.display());
+                }
+                println!(
+                    "  코드 참조 {}개, 대표 키로 옮긴 번역 {}개, 변경 파일 {}개",
+                    plan.references,
+                    plan.copied,
+                    plan.changes.len()
+                );
+                println!("  (카탈로그에 남은 이전 키는 i18n-wrapper prune 으로 지울 수 있습니다)");
+                process::print_dry_run_notice(options);
+            }
+            Err(diagnostic) => errors.push(*diagnostic),
+        }
+    }
+    for diagnostic in &errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+    errors
+}
+
+// sidecar가 없거나 깨졌으면 설명 없이 진행
+#[cfg(feature = "swc")]
+fn sidecar_comments(path: &Path) -> Vec<(String, String)> {
+    let meta = match fs::read_to_string(path).ok().and_then(|text| json::parse(&text).ok()) {
+        Some(meta) => meta,
+        None => return Vec::new(),
+    };
+    meta.as_object()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|(key, entry)| {
+                    let comment = entry.get("comment").and_then(Value::as_str)?;
+                    Some((key.clone(), comment.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "swc")]
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn test_normalize_options() {
+        let all = Normalize::default();
+        assert_eq!(normalize("  Cancel! ", &all), "cancel");
+        assert_eq!(normalize("Hi,  {{user.name}}", &all), "hi {{user.name}}");
+        let strict = Normalize {
+            case: false,
+            whitespace: false,
+            punctuation: false,
+        };
+        assert_eq!(normalize("  Cancel! ", &strict), "  Cancel! ");
+    }
+
+    #[test]
+    fn test_merge_rules() {
+        let entries: Vec<(String, String)> = [
+            ("a.save", "Save"),
+            ("b.save", "Save"),
+            ("c.save", "Save!"),
+            ("a.close", "Close"),
+            ("b.close", "Close"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        // 한쪽에만 설명이 있어도 뜻이 다를 수 있음
+        let comments = vec![("a.close".to_string(), "창 닫기".to_string())];
+        let clusters = find_duplicates(&entries, &[], &comments, &Normalize::default(), |_| String::new());
+        assert_eq!(clusters.len(), 2);
+        assert!(!clusters[0].contexts_differ);
+        assert!(clusters[1].contexts_differ);
+        assert!(clusters[1].mergeable(true).is_empty());
+
+        let keys = |normalized| -> Vec<String> {
+            clusters[0].mergeable(normalized).iter().map(|k| k.key.clone()).collect()
+        };
+        assert_eq!(keys(false), vec!["b.save"]);
+        assert_eq!(keys(true), vec!["b.save", "c.save"]);
+
+        // 코드 참조가 없으면 바꿀 파일도 없음
+        let plan = plan_consolidate(&clusters, &[], &[], true).unwrap();
+        assert!(plan.changes.is_empty() && plan.merged.is_empty());
+        // 빈 카탈로그
+        assert!(find_duplicates(&[], &[], &[], &Normalize::default(), |_| String::new()).is_empty());
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_duplicates_cluster_and_consolidate() {
+        let dir = TempDir::new("duplicates");
+        let source = dir.join("App.jsx");
+        let code = "const App = () => (\n  <div>\n    {t('dialog.cancel')}\n    {t(\"form.cancel\")}\n    {t('form.cancel')}\n    {t('order.close')} {/* i18n: 주문 닫기 (동사) */}\n    {t('menu.close')} {/* i18n: 메뉴 닫기 버튼 */}\n  </div>\n);\n";
+        fs::write(&source, code).unwrap();
+        let entries: Vec<(String, String)> = [
+            ("dialog.cancel", "Cancel"),
+            ("form.cancel", "cancel."),
+            ("order.close", "Close"),
+            ("menu.close", "Close"),
+            ("unique", "Only once"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let references = extract::scan_references(&source.to_string_lossy(), code, crate::framework::Framework::default()).unwrap();
+
+        let clusters = find_duplicates(&entries, &references, &[], &Normalize::default(), |_| "App.jsx".to_string());
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].normalized, "cancel");
+        // 참조가 더 많은 키가 대표
+        assert_eq!(clusters[0].canonical, "form.cancel");
+        assert_eq!(clusters[0].keys[1].locations, vec!["App.jsx:4", "App.jsx:5"]);
+        assert!(clusters[1].contexts_differ);
+
+        // "Cancel"과 "cancel."은 글자 그대로 같지 않아서 기본으로는 합치지 않음
+        let plan = plan_consolidate(&clusters, &references, &[], false).unwrap();
+        assert!(plan.merged.is_empty() && plan.changes.is_empty());
+
+        // 대표 키에 없는 번역은 이전 키에서 옮김
+        let ko = Catalog::parse(&dir.join("ko.json"), "{\n  \"dialog.cancel\": \"취소\"\n}\n").unwrap();
+        let plan = plan_consolidate(&clusters, &references, &[ko], true).unwrap();
+        assert_eq!(plan.merged, vec![("dialog.cancel".to_string(), "form.cancel".to_string())]);
+        assert!(plan.changes[0].updated.contains("{t('form.cancel')}\n    {t(\"form.cancel\")}"));
+        assert!(plan.changes[0].updated.contains("t('order.close')"));
+        assert_eq!(plan.copied, 1);
+        assert_eq!(plan.changes[1].updated, "{\n  \"dialog.cancel\": \"취소\",\n  \"form.cancel\": \"취소\"\n}\n");
+
+        // 두 키의 번역이 다르면 합치지 않고 보고
+        let ko = Catalog::parse(
+            &dir.join("ko.json"),
+            "{\n  \"dialog.cancel\": \"취소\",\n  \"form.cancel\": \"취소하기\"\n}\n",
+        )
+        .unwrap();
+        let plan = plan_consolidate(&clusters, &references, &[ko], true).unwrap();
+        assert!(plan.merged.is_empty() && plan.changes.is_empty());
+        assert_eq!(
+            plan.skipped,
+            vec![("dialog.cancel".to_string(), "form.cancel".to_string(), dir.join("ko.json"))]
+        );
+
+        let strict = Normalize {
+            case: false,
+            ..Normalize::default()
+        };
+        let clusters = find_duplicates(&entries, &references, &[], &strict, |_| String::new());
+        assert_eq!(clusters.len(), 1);
+    }
+}
@@ -0,0 +1,328 @@
+// ============================================
+// i18n-wrapper: export 명령 (번역 업체/도구용 .po, XLIFF 1.2 파일 만들기)
+// 로케일마다 카탈로그 옆에 locales/ko.po 또는 locales/ko.xliff 를 생성 (매번 다시 생성)
+// extract가 sidecar(en.meta.json)에 남긴 정보를 같이 넘김:
+//   comment, description -> .po `#.` 주석 / XLIFF <note>
+//   references           -> .po `#:` 주석 / XLIFF <context-group purpose="location">
+//   maxLength            -> XLIFF maxwidth (.po는 description의 "Max length:" 줄)
+// TypeScript (i18next-conv):
+// i18nextToPo("ko", JSON.stringify(ko), { project: "app" });
+// ============================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+use crate::extract;
+use crate::json::{self, Value};
+use crate::process::{self, FileChange, ProcessOptions};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Po,
+    Xliff,
+}
+
+impl ExportFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "po" => Some(ExportFormat::Po),
+            "xliff" => Some(ExportFormat::Xliff),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Po => "po",
+            ExportFormat::Xliff => "xliff",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportEntry {
+    pub key: String,
+    pub source: String,
+    // 없거나 빈 번역은 None
+    pub translation: Option<String>,
+    // 사람이 적은 sidecar comment
+    pub comment: Option<String>,
+    // extract가 코드에서 모은 번역자용 설명
+    pub description: Option<String>,
+    pub references: Vec<String>,
+    pub max_length: Option<usize>,
+}
+
+// 원문 카탈로그 순서대로. meta는 원문 카탈로그의 sidecar (없으면 빈 객체)
+pub fn entries(source: &Catalog, target: &Catalog, meta: &Value) -> Vec<ExportEntry> {
+    source
+        .entries()
+        .into_iter()
+        .map(|(key, text)| {
+            let entry = meta.get(&key);
+            let field = |name: &str| entry.and_then(|e| e.get(name));
+            ExportEntry {
+                translation: target.get(&key).filter(|t| !t.is_empty()).map(String::from),
+                comment: field("comment").and_then(Value::as_str).map(String::from),
+                description: field("description").and_then(Value::as_str).map(String::from),
+                references: field("references")
+                    .and_then(Value::as_array)
+                    .map(|items| items.iter().filter_map(|i| i.as_str().map(String::from)).collect())
+                    .unwrap_or_default(),
+                max_length: field("maxLength").and_then(Value::as_u64).map(|n| n as usize),
+                key,
+                source: text,
+            }
+        })
+        .collect()
+}
+
+// ============================================
+// gettext .po: 키는 msgctxt, 원문은 msgid
+// ============================================
+pub fn to_po(entries: &[ExportEntry], source_locale: &str, locale: &str) -> String {
+    let mut out = String::new();
+    out.push_str("msgid \"\"\nmsgstr \"\"\n");
+    out.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    out.push_str(&format!("\"Language: {}\\n\"\n", po_escape(locale)));
+    out.push_str(&format!("\"X-Source-Language: {}\\n\"\n", po_escape(source_locale)));
+    for entry in entries {
+        out.push('\n');
+        let notes = entry.comment.iter().chain(&entry.description);
+        for line in notes.flat_map(|note| note.lines()) {
+            out.push_str(&format!("#. {}\n", line));
+        }
+        for reference in &entry.references {
+            out.push_str(&format!("#: {}\n", reference));
+        }
+        out.push_str(&format!("msgctxt \"{}\"\n", po_escape(&entry.key)));
+        out.push_str(&format!("msgid \"{}\"\n", po_escape(&entry.source)));
+        out.push_str(&format!("msgstr \"{}\"\n", po_escape(entry.translation.as_deref().unwrap_or(""))));
+    }
+    out
+}
+
+fn po_escape(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// ============================================
+// XLIFF 1.2: trans-unit id는 키, 번역이 없으면 <target> 생략
+// ============================================
+pub fn to_xliff(entries: &[ExportEntry], source_locale: &str, locale: &str, original: &str) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n");
+    out.push_str(&format!(
+        "  <file source-language=\"{}\" target-language=\"{}\" datatype=\"plaintext\" original=\"{}\">\n",
+        xml_escape(source_locale),
+        xml_escape(locale),
+        xml_escape(original)
+    ));
+    out.push_str("    <body>\n");
+    for entry in entries {
+        let maxwidth = entry
+            .max_length
+            .map(|max| format!(" maxwidth=\"{}\" size-unit=\"char\"", max))
+            .unwrap_or_default();
+        out.push_str(&format!("      <trans-unit id=\"{}\"{}>\n", xml_escape(&entry.key), maxwidth));
+        out.push_str(&format!("        <source>{}</source>\n", xml_escape(&entry.source)));
+        if let Some(translation) = &entry.translation {
+            out.push_str(&format!("        <target>{}</target>\n", xml_escape(translation)));
+        }
+        if let Some(comment) = &entry.comment {
+            out.push_str(&format!("        <note from=\"developer\">{}</note>\n", xml_escape(comment)));
+        }
+        if let Some(description) = &entry.description {
+            out.push_str(&format!("        <note from=\"extractor\">{}</note>\n", xml_escape(description)));
+        }
+        for reference in &entry.references {
+            // "src/App.tsx:12" -> 파일 + 줄 번호
+            let (file, line) = match reference.rsplit_once(':') {
+                Some((file, line)) if line.chars().all(|c| c.is_ascii_digit()) => (file, Some(line)),
+                _ => (reference.as_str(), None),
+            };
+            out.push_str("        <context-group purpose=\"location\">\n");
+            out.push_str(&format!(
+                "          <context context-type=\"sourcefile\">{}</context>\n",
+                xml_escape(file)
+            ));
+            if let Some(line) = line {
+                out.push_str(&format!("          <context context-type=\"linenumber\">{}</context>\n", line));
+            }
+            out.push_str("        </context-group>\n");
+        }
+        out.push_str("      </trans-unit>\n");
+    }
+    out.push_str("    </body>\n");
+    out.push_str("  </file>\n");
+    out.push_str("</xliff>\n");
+    out
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// ============================================
+// 계획: 로케일마다 카탈로그 경로의 확장자만 바꾼 파일 (locales/ko.json -> locales/ko.po)
+// ============================================
+pub fn plan_export(
+    source: &Path,
+    source_locale: &str,
+    targets: &[(String, PathBuf)],
+    format: ExportFormat,
+) -> Result<Vec<FileChange>, Box<Diagnostic>> {
+    let source_catalog = Catalog::load_diagnostic(source)?;
+    let meta_file = extract::meta_path(source);
+    let meta = match fs::read_to_string(&meta_file) {
+        Ok(text) if !text.trim().is_empty() => json::parse(&text).map_err(|e| {
+            Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, e.to_string(), &meta_file))
+        })?,
+        _ => Value::object(),
+    };
+
+    let mut changes = Vec::new();
+    for (locale, path) in targets {
+        let found = entries(&source_catalog, &Catalog::load_diagnostic(path)?, &meta);
+        let updated = match format {
+            ExportFormat::Po => to_po(&found, source_locale, locale),
+            ExportFormat::Xliff => to_xliff(&found, source_locale, locale, &source.to_string_lossy()),
+        };
+        let output = path.with_extension(format.extension());
+        let original = fs::read_to_string(&output).unwrap_or_default();
+        if updated != original {
+            changes.push(FileChange {
+                path: output,
+                original,
+                updated,
+                wraps: Vec::new(),
+            });
+        }
+    }
+    Ok(changes)
+}
+
+// ============================================
+// CLI 진입점: 계획 → process::apply_changes → 요약
+// ============================================
+pub fn run_export(
+    options: &ProcessOptions,
+    source_locale: &str,
+    targets: &[(String, PathBuf)],
+    format: ExportFormat,
+) -> Vec<Diagnostic> {
+    let changes = match plan_export(&options.catalog_path, source_locale, targets, format) {
+        Ok(changes) => changes,
+        Err(diagnostic) => {
+            eprintln!("{}", diagnostic.render(diagnostic::use_color()));
+            return vec![*diagnostic];
+        }
+    };
+
+    let errors = process::apply_changes(options, &changes);
+    println!();
+    println!("요약:");
+    if targets.is_empty() {
+        println!("  내보낼 번역 로케일이 없습니다 (설정의 locales 확인)");
+    }
+    for change in &changes {
+        println!("  저장: {}", change.path.display());
+    }
+    println!("  로케일 {}개, 변경 파일 {}개", targets.len(), changes.len());
+    process::print_dry_run_notice(options);
+    let color = diagnostic::use_color();
+    for diagnostic in &errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn test_export_po_and_xliff_carry_descriptions() {
+        let dir = TempDir::new("export");
+        fs::write(dir.join("en.json"), "{\n  \"pay\": \"Pay \\\"now\\\"\",\n  \"back\": \"Back\"\n}\n").unwrap();
+        fs::write(dir.join("ko.json"), "{\n  \"pay\": \"지금 결제\"\n}\n").unwrap();
+        fs::write(
+            dir.join("en.meta.json"),
+            r#"{ "pay": { "comment": "Checkout", "description": "Button label\nMax length: 12", "references": ["src/Pay.tsx:3"], "maxLength": 12 } }"#,
+        )
+        .unwrap();
+        let targets = vec![("ko".to_string(), dir.join("ko.json"))];
+
+        let changes = plan_export(&dir.join("en.json"), "en", &targets, ExportFormat::Po).unwrap();
+        assert_eq!(changes[0].path, dir.join("ko.po"));
+        assert!(changes[0].updated.contains(
+            "#. Checkout\n#. Button label\n#. Max length: 12\n#: src/Pay.tsx:3\nmsgctxt \"pay\"\nmsgid \"Pay \\\"now\\\"\"\nmsgstr \"지금 결제\"\n"
+        ));
+        assert!(changes[0].updated.ends_with("msgctxt \"back\"\nmsgid \"Back\"\nmsgstr \"\"\n"));
+
+        let changes = plan_export(&dir.join("en.json"), "en", &targets, ExportFormat::Xliff).unwrap();
+        let xliff = &changes[0].updated;
+        assert!(xliff.contains("<trans-unit id=\"pay\" maxwidth=\"12\" size-unit=\"char\">"));
+        assert!(xliff.contains("<source>Pay &quot;now&quot;</source>\n        <target>지금 결제</target>"));
+        assert!(xliff.contains("<note from=\"extractor\">Button label\nMax length: 12</note>"));
+        assert!(xliff.contains("<context context-type=\"linenumber\">3</context>"));
+        // 번역이 없으면 target 없음
+        assert!(xliff.contains("<source>Back</source>\n      </trans-unit>"));
+    }
+
+    #[test]
+    fn test_export_nested_keys_escaping_and_unchanged_output() {
+        let dir = TempDir::new("export-edge");
+        let en = dir.write(
+            "en.json",
+            "{\n  \"cart\": {\n    \"terms\": \"Tom & Jerry's <b>deal</b>\",\n    \"note\": \"Line 1\\nLine\\t2\"\n  }\n}\n",
+        );
+        // 빈 문자열 번역은 번역 없음으로 내보냄
+        let ko = dir.write("ko.json", "{\n  \"cart\": {\n    \"terms\": \"\",\n    \"note\": \"첫 줄\\n둘째 줄\"\n  }\n}\n");
+        dir.write("en.meta.json", r#"{ "cart.terms": { "references": ["src/Cart.tsx"] } }"#);
+        let targets = vec![("ko".to_string(), ko)];
+
+        let changes = plan_export(&en, "en", &targets, ExportFormat::Po).unwrap();
+        let po = &changes[0].updated;
+        assert!(po.contains("#: src/Cart.tsx\nmsgctxt \"cart.terms\"\nmsgid \"Tom & Jerry's <b>deal</b>\"\nmsgstr \"\"\n"));
+        assert!(po.contains("msgctxt \"cart.note\"\nmsgid \"Line 1\\nLine\\t2\"\nmsgstr \"첫 줄\\n둘째 줄\"\n"));
+
+        let changes = plan_export(&en, "en", &targets, ExportFormat::Xliff).unwrap();
+        let xliff = &changes[0].updated;
+        assert!(xliff.contains(
+            "<trans-unit id=\"cart.terms\">\n        <source>Tom &amp; Jerry&apos;s &lt;b&gt;deal&lt;/b&gt;</source>\n        <context-group"
+        ));
+        // 줄 번호가 없는 참조는 파일만
+        assert!(xliff.contains("<context context-type=\"sourcefile\">src/Cart.tsx</context>\n        </context-group>"));
+
+        // 이미 내보낸 파일과 같으면 변경 없음
+        dir.write("ko.xliff", xliff);
+        assert!(plan_export(&en, "en", &targets, ExportFormat::Xliff).unwrap().is_empty());
+    }
+}
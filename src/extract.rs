@@ -0,0 +1,872 @@
+// ============================================
+// i18n-wrapper: extract 명령 (코드는 건드리지 않고 카탈로그만 갱신)
+// 이미 직접 감싼 t('key', 'default') / i18n.t(...) / <Trans i18nKey="..."> 를 찾아서
+// (호출 이름과 컴포넌트는 설정의 framework: $t(...), formatMessage({ id }), <FormattedMessage id> 등)
+// 원문 로케일 카탈로그에 없는 키를 기본값과 함께 추가하고,
+// 키별 참조 위치와 설명(comment)은 카탈로그 옆의 sidecar 파일(en.meta.json)에 기록
+// 번역자용 설명(description)은 코드에서 모아서 매번 다시 계산:
+//   `// i18n: ...` / `/** @translator ... */` 주석, 컴포넌트, JSX 요소/속성, max-length 힌트
+// (export 명령이 .po의 `#.` 주석과 XLIFF <note>로 그대로 옮김)
+// swc_examples.rs의 find_use_translation_example은 found: bool만 세우지만
+// 여기서는 같은 Visit 방식으로 키/기본값/위치를 모두 모음
+// TypeScript (i18next-parser):
+// parser.parseFuncFromString(code, { list: ["t", "i18n.t"] }, (key, options) => { ... });
+// ============================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+#[cfg(feature = "swc")]
+use crate::framework::{CallStyle, Framework};
+use crate::json::{self, Value};
+use crate::process::FileChange;
+// run_extract 전용
+#[cfg(feature = "swc")]
+use crate::memory::{self, MemoryOptions};
+#[cfg(feature = "swc")]
+use crate::naming;
+#[cfg(feature = "swc")]
+use crate::process::{self, ProcessOptions};
+
+// 번역자에게 남기는 설명: `t('save') // i18n: 저장 버튼 (동사)`
+pub const CONTEXT_DIRECTIVE: &str = "i18n:";
+// JSDoc 형식: /** @translator 결제 버튼, max-length: 12 */
+pub const TRANSLATOR_TAG: &str = "@translator";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferenceKind {
+    // t('key'), i18n.t('key'), formatMessage({ id: 'key' })
+    Call,
+    // <Trans i18nKey="key">, <FormattedMessage id="key" />
+    Trans,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyReference {
+    pub key: String,
+    pub default: Option<String>,
+    pub path: String,
+    // 키 문자열 리터럴의 원본 범위 (따옴표 포함, 바이트 오프셋)
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    pub kind: ReferenceKind,
+    // 감싸고 있는 React 컴포넌트 (transform::HardcodedString과 같은 기준)
+    pub component: Option<String>,
+    // 같은 줄이나 바로 윗줄의 `// i18n: ...` / `/** @translator ... */` 주석
+    pub context: Option<String>,
+    // 감싸고 있는 JSX 요소 ("button")와, 속성 값 안이면 그 속성 ("title")
+    pub element: Option<String>,
+    pub attribute: Option<String>,
+    // 주석의 `max-length: 12` 힌트
+    pub max_length: Option<usize>,
+}
+
+// ============================================
+// 참조 찾기 (prune/rename-key도 같은 스캔을 사용)
+// ============================================
+#[cfg(feature = "swc")]
+pub fn scan_references(path: &str, code: &str, framework: Framework) -> Result<Vec<KeyReference>, Box<Diagnostic>> {
+    use swc_ecma_visit::VisitWith;
+
+    let parsed = crate::transform::parse_source(path, code).map_err(|e| Box::new(e.to_diagnostic(code)))?;
+    let mut collector = ReferenceCollector {
+        code,
+        path,
+        parsed: &parsed,
+        framework,
+        calls: framework.call_names().iter().map(|name| name.to_string()).collect(),
+        trans: framework.component().map(|c| c.name.to_string()).into_iter().collect(),
+        components: Vec::new(),
+        elements: Vec::new(),
+        attribute: None,
+        references: Vec::new(),
+    };
+    parsed.module.visit_with(&mut collector);
+    Ok(collector.references)
+}
+
+// 파일을 읽고 스캔. 읽기/파싱 실패는 진단으로 모아서 반환
+#[cfg(feature = "swc")]
+pub fn scan_files(files: &[PathBuf], framework: Framework) -> (Vec<KeyReference>, Vec<Diagnostic>) {
+    let mut references = Vec::new();
+    let mut errors = Vec::new();
+    for path in files {
+        let result = fs::read_to_string(path)
+            .map_err(|e| Box::new(Diagnostic::error(diagnostic::IO_ERROR, e.to_string(), path)))
+            .and_then(|code| scan_references(&path.to_string_lossy(), &code, framework));
+        match result {
+            Ok(found) => references.extend(found),
+            Err(diagnostic) => errors.push(*diagnostic),
+        }
+    }
+    (references, errors)
+}
+
+#[cfg(feature = "swc")]
+struct ReferenceCollector<'a> {
+    code: &'a str,
+    path: &'a str,
+    parsed: &'a crate::transform::ParsedSource,
+    framework: Framework,
+    // 번역 함수/컴포넌트 이름 + 파일 안에서 붙인 별칭 (import { t as tr }, const { t: tr } = ...)
+    calls: Vec<String>,
+    trans: Vec<String>,
+    components: Vec<String>,
+    elements: Vec<String>,
+    // 지금 보고 있는 속성 값 (안쪽 JSX 요소에 들어가면 None)
+    attribute: Option<String>,
+    references: Vec<KeyReference>,
+}
+
+#[cfg(feature = "swc")]
+impl ReferenceCollector<'_> {
+    fn record(&mut self, key: &swc_ecma_ast::Str, default: Option<String>, kind: ReferenceKind) {
+        let start = self.parsed.offset(key.span.lo);
+        let (line, column) = crate::transform::line_col(self.code, start);
+        let context = context_comment(self.code, line);
+        self.references.push(KeyReference {
+            key: key.value.to_string(),
+            default,
+            path: self.path.to_string(),
+            start,
+            end: self.parsed.offset(key.span.hi),
+            line,
+            column,
+            kind,
+            component: self.components.last().cloned(),
+            max_length: context.as_deref().and_then(max_length_hint),
+            context,
+            element: self.elements.last().cloned(),
+            attribute: self.attribute.clone(),
+        });
+    }
+
+    fn with_component<F: FnOnce(&mut Self)>(&mut self, name: Option<String>, f: F) {
+        let name = name.filter(|name| crate::transform::is_component_name(name));
+        let pushed = name.is_some();
+        self.components.extend(name);
+        f(self);
+        if pushed {
+            self.components.pop();
+        }
+    }
+}
+
+// transform::is_ignored처럼 주석은 줄 단위로 찾음 (같은 줄이 우선)
+// `// i18n: 설명`, `/* i18n: 설명 */`, JSX 안의 `{/* i18n: 설명 */}`,
+// 윗줄에서 끝나는 여러 줄 `/** ... @translator 설명 ... */`
+pub fn context_comment(code: &str, line: usize) -> Option<String> {
+    let lines: Vec<&str> = code.lines().collect();
+    let index = line.checked_sub(1)?;
+    if let Some(text) = lines.get(index).and_then(|l| comment_text(l)) {
+        return Some(text);
+    }
+    let above = index.checked_sub(1)?;
+    if let Some(text) = comment_text(lines.get(above)?) {
+        return Some(text);
+    }
+    if !lines[above].trim_end().trim_end_matches('}').trim_end().ends_with("*/") {
+        return None;
+    }
+    let mut start = above;
+    while !lines[start].contains("/*") {
+        start = start.checked_sub(1)?;
+    }
+    translator_text(&lines[start..=above].join("\n"))
+}
+
+fn comment_text(line: &str) -> Option<String> {
+    if line.contains(TRANSLATOR_TAG) {
+        return translator_text(line);
+    }
+    let index = line.find(CONTEXT_DIRECTIVE)?;
+    let before = line[..index].trim_end();
+    if !(before.ends_with("//") || before.ends_with("/*")) {
+        return None;
+    }
+    let rest = &line[index + CONTEXT_DIRECTIVE.len()..];
+    let text = rest.split("*/").next().unwrap_or(rest).trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+// @translator 뒤부터 다음 @태그나 주석 끝까지, 줄 앞의 `*`는 빼고 한 줄로
+fn translator_text(block: &str) -> Option<String> {
+    let index = block.find(TRANSLATOR_TAG)?;
+    let rest = &block[index + TRANSLATOR_TAG.len()..];
+    let rest = rest.split("*/").next().unwrap_or(rest);
+    let mut words = Vec::new();
+    for (i, line) in rest.lines().enumerate() {
+        let line = line.trim().trim_start_matches('*').trim();
+        if i > 0 && line.starts_with('@') {
+            break;
+        }
+        words.extend(line.split_whitespace());
+    }
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+// "max-length: 12", "maxLength 12", "@max-length 12"
+pub fn max_length_hint(text: &str) -> Option<usize> {
+    let lower = text.to_lowercase();
+    let index = lower.find("max-length").or_else(|| lower.find("maxlength"))?;
+    let rest = lower[index..].trim_start_matches(|c: char| c.is_alphabetic() || c == '-');
+    let digits: String = rest
+        .trim_start_matches(|c: char| c == ':' || c == '=' || c.is_whitespace())
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+// 키 하나의 참조들 -> 번역자용 설명 (줄마다 한 가지 정보)
+pub fn describe(references: &[&KeyReference]) -> Option<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut push = |line: String| {
+        if !lines.contains(&line) {
+            lines.push(line);
+        }
+    };
+    for reference in references {
+        if let Some(context) = &reference.context {
+            push(context.clone());
+        }
+    }
+    let distinct = |values: Vec<String>| {
+        let mut unique: Vec<String> = Vec::new();
+        for value in values {
+            if !unique.contains(&value) {
+                unique.push(value);
+            }
+        }
+        unique.join(", ")
+    };
+    let components = distinct(references.iter().filter_map(|r| r.component.clone()).collect());
+    if !components.is_empty() {
+        push(format!("Component: {}", components));
+    }
+    let elements = distinct(
+        references
+            .iter()
+            .filter_map(|r| {
+                let element = r.element.as_ref()?;
+                Some(match &r.attribute {
+                    Some(attribute) => format!("<{}> {}", element, attribute),
+                    None => format!("<{}>", element),
+                })
+            })
+            .collect(),
+    );
+    if !elements.is_empty() {
+        push(format!("Element: {}", elements));
+    }
+    if let Some(max) = references.iter().filter_map(|r| r.max_length).min() {
+        push(format!("Max length: {}", max));
+    }
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+// t('key', 'Default') 또는 t('key', { defaultValue: 'Default' })
+#[cfg(feature = "swc")]
+fn default_value(expr: &swc_ecma_ast::Expr) -> Option<String> {
+    use swc_ecma_ast::{Expr, Lit};
+
+    match expr {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        _ => string_prop(expr, "defaultValue").map(|s| s.value.to_string()),
+    }
+}
+
+// { name: 'value' } 객체 리터럴의 문자열 속성
+#[cfg(feature = "swc")]
+fn string_prop<'a>(expr: &'a swc_ecma_ast::Expr, name: &str) -> Option<&'a swc_ecma_ast::Str> {
+    use swc_ecma_ast::{Expr, Lit, Prop, PropName, PropOrSpread};
+
+    let object = match expr {
+        Expr::Object(object) => object,
+        _ => return None,
+    };
+    object.props.iter().find_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(kv) => {
+                let matches = match &kv.key {
+                    PropName::Ident(ident) => ident.sym == name,
+                    PropName::Str(s) => s.value == name,
+                    _ => false,
+                };
+                match &*kv.value {
+                    Expr::Lit(Lit::Str(s)) if matches => Some(s),
+                    _ => None,
+                }
+            }
+            _ => None,
+        },
+        PropOrSpread::Spread(_) => None,
+    })
+}
+
+#[cfg(feature = "swc")]
+impl swc_ecma_visit::Visit for ReferenceCollector<'_> {
+    fn visit_fn_decl(&mut self, decl: &swc_ecma_ast::FnDecl) {
+        use swc_ecma_visit::VisitWith;
+        self.with_component(Some(decl.ident.sym.to_string()), |this| decl.visit_children_with(this));
+    }
+
+    fn visit_fn_expr(&mut self, expr: &swc_ecma_ast::FnExpr) {
+        use swc_ecma_visit::VisitWith;
+        let name = expr.ident.as_ref().map(|i| i.sym.to_string());
+        self.with_component(name, |this| expr.visit_children_with(this));
+    }
+
+    fn visit_class_decl(&mut self, decl: &swc_ecma_ast::ClassDecl) {
+        use swc_ecma_visit::VisitWith;
+        self.with_component(Some(decl.ident.sym.to_string()), |this| decl.visit_children_with(this));
+    }
+
+    // import { t as translate } from 'i18next', import { FormattedMessage as Message } from 'react-intl'
+    fn visit_import_decl(&mut self, import: &swc_ecma_ast::ImportDecl) {
+        use swc_ecma_ast::{ImportSpecifier, ModuleExportName};
+
+        for specifier in &import.specifiers {
+            if let ImportSpecifier::Named(named) = specifier {
+                let imported = match &named.imported {
+                    Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                    Some(ModuleExportName::Str(s)) => s.value.to_string(),
+                    None => continue,
+                };
+                let local = named.local.sym.to_string();
+                if self.calls.contains(&imported) && !self.calls.contains(&local) {
+                    self.calls.push(local);
+                } else if self.trans.contains(&imported) && !self.trans.contains(&local) {
+                    self.trans.push(local);
+                }
+            }
+        }
+    }
+
+    fn visit_var_declarator(&mut self, decl: &swc_ecma_ast::VarDeclarator) {
+        use swc_ecma_ast::{Expr, ObjectPatProp, Pat, PropName};
+        use swc_ecma_visit::VisitWith;
+
+        // const { t: translate } = useTranslation(), const { formatMessage: fm } = useIntl()
+        if let Pat::Object(object) = &decl.name {
+            for prop in &object.props {
+                if let ObjectPatProp::KeyValue(kv) = prop {
+                    if let (PropName::Ident(key), Pat::Ident(alias)) = (&kv.key, &*kv.value) {
+                        let alias = alias.id.sym.to_string();
+                        if self.calls.iter().any(|name| key.sym == **name) && !self.calls.contains(&alias) {
+                            self.calls.push(alias);
+                        }
+                    }
+                }
+            }
+        }
+
+        let name = match (&decl.name, decl.init.as_deref()) {
+            (Pat::Ident(ident), Some(Expr::Arrow(_) | Expr::Fn(_) | Expr::Call(_))) => {
+                Some(ident.id.sym.to_string())
+            }
+            _ => None,
+        };
+        self.with_component(name, |this| decl.visit_children_with(this));
+    }
+
+    fn visit_call_expr(&mut self, call: &swc_ecma_ast::CallExpr) {
+        use swc_ecma_ast::{Callee, Expr, Lit, MemberProp};
+        use swc_ecma_visit::VisitWith;
+
+        let is_call = match &call.callee {
+            Callee::Expr(expr) => match &**expr {
+                Expr::Ident(ident) => self.calls.iter().any(|name| ident.sym == **name),
+                // i18n.t(...), this.props.t(...), this.$t(...), intl.formatMessage(...)
+                // 멤버 호출은 별칭이 아니라 라이브러리의 원래 이름으로
+                Expr::Member(member) => matches!(&member.prop, MemberProp::Ident(prop)
+                    if self.framework.call_names().iter().any(|name| prop.sym == **name)),
+                _ => false,
+            },
+            _ => false,
+        };
+        let first = call.args.first().filter(|a| a.spread.is_none()).map(|a| &*a.expr);
+        if is_call {
+            // 키가 변수나 템플릿 문자열이면 (동적 키) 추출할 수 없음
+            match (self.framework.call_style(), first) {
+                (CallStyle::Positional, Some(Expr::Lit(Lit::Str(key)))) => {
+                    let default = call.args.get(1).and_then(|arg| default_value(&arg.expr));
+                    self.record(key, default, ReferenceKind::Call);
+                }
+                (CallStyle::Descriptor, Some(descriptor)) => {
+                    if let Some(key) = string_prop(descriptor, "id") {
+                        let default = string_prop(descriptor, "defaultMessage").map(|s| s.value.to_string());
+                        self.record(key, default, ReferenceKind::Call);
+                    }
+                }
+                _ => {}
+            }
+        }
+        call.visit_children_with(self);
+    }
+
+    // react-i18next: <Trans i18nKey="key" defaults="...">Default text</Trans>
+    // react-intl: <FormattedMessage id="key" defaultMessage="..." />
+    fn visit_jsx_element(&mut self, element: &swc_ecma_ast::JSXElement) {
+        use swc_ecma_ast::{JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElementChild, JSXElementName, Lit};
+        use swc_ecma_visit::VisitWith;
+
+        let component = self.framework.component().filter(|_| {
+            matches!(&element.opening.name, JSXElementName::Ident(ident) if self.trans.iter().any(|name| ident.sym == **name))
+        });
+        if let Some(component) = component {
+            let attr = |name: &str| {
+                element.opening.attrs.iter().find_map(|attr| match attr {
+                    JSXAttrOrSpread::JSXAttr(attr) => match (&attr.name, &attr.value) {
+                        (JSXAttrName::Ident(ident), Some(JSXAttrValue::Lit(Lit::Str(s)))) if ident.sym == name => {
+                            Some(s)
+                        }
+                        _ => None,
+                    },
+                    JSXAttrOrSpread::SpreadElement(_) => None,
+                })
+            };
+            if let Some(key) = attr(component.key) {
+                // 자식이 텍스트뿐일 때만 기본값으로 사용 (<b> 등이 섞이면 <1>..</1> 형식이 필요)
+                let children: Option<Vec<String>> = element
+                    .children
+                    .iter()
+                    .map(|child| match child {
+                        JSXElementChild::JSXText(text) => Some(text.value.to_string()),
+                        _ => None,
+                    })
+                    .collect();
+                let children = children
+                    .map(|texts| texts.join(" ").split_whitespace().collect::<Vec<_>>().join(" "))
+                    .filter(|text| !text.is_empty());
+                let default = attr(component.default).map(|s| s.value.to_string()).or(children);
+                self.record(key, default, ReferenceKind::Trans);
+            }
+        }
+
+        // <Trans>는 자기 자신이 아니라 바깥 요소가 문맥
+        let name = match &element.opening.name {
+            JSXElementName::Ident(ident) => ident.sym.to_string(),
+            JSXElementName::JSXMemberExpr(member) => member.prop.sym.to_string(),
+            JSXElementName::JSXNamespacedName(ns) => format!("{}:{}", ns.ns.sym, ns.name.sym),
+        };
+        self.elements.push(name);
+        let attribute = self.attribute.take();
+        element.visit_children_with(self);
+        self.attribute = attribute;
+        self.elements.pop();
+    }
+
+    fn visit_jsx_attr(&mut self, attr: &swc_ecma_ast::JSXAttr) {
+        use swc_ecma_ast::JSXAttrName;
+        use swc_ecma_visit::VisitWith;
+
+        let name = match &attr.name {
+            JSXAttrName::Ident(ident) => ident.sym.to_string(),
+            JSXAttrName::JSXNamespacedName(ns) => format!("{}:{}", ns.ns.sym, ns.name.sym),
+        };
+        let previous = self.attribute.replace(name);
+        attr.visit_children_with(self);
+        self.attribute = previous;
+    }
+}
+
+// ============================================
+// 카탈로그 + sidecar 병합 (디스크에는 쓰지 않음)
+// ============================================
+pub struct ExtractPlan {
+    pub changes: Vec<FileChange>,
+    pub keys: usize,
+    // (키, 카탈로그에 넣은 원문)
+    pub new_keys: Vec<(String, String)>,
+}
+
+// locales/en.json -> locales/en.meta.json
+pub fn meta_path(catalog: &Path) -> PathBuf {
+    catalog.with_extension("meta.json")
+}
+
+// reference: KeyReference.path를 표시용 경로로 바꾸는 함수 (설정 루트 기준)
+pub fn plan_extract(
+    references: &[KeyReference],
+    catalog_path: &Path,
+    reference: impl Fn(&KeyReference) -> String,
+) -> Result<ExtractPlan, Box<Diagnostic>> {
+    let catalog_error = |message: String| Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, message, catalog_path));
+    let mut catalog = Catalog::load_diagnostic(catalog_path)?;
+    let before = catalog.render();
+
+    // 처음 나온 순서대로, 기본값은 처음으로 지정된 것을 사용
+    let mut keys: Vec<(&str, Option<&str>, Vec<&KeyReference>)> = Vec::new();
+    for found in references {
+        match keys.iter_mut().find(|(key, _, _)| *key == found.key) {
+            Some((_, default, found_at)) => {
+                if default.is_none() {
+                    *default = found.default.as_deref();
+                }
+                found_at.push(found);
+            }
+            None => keys.push((&found.key, found.default.as_deref(), vec![found])),
+        }
+    }
+
+    let mut new_keys = Vec::new();
+    for (key, default, _) in &keys {
+        // 기본값이 없으면 키를 그대로 (i18next가 번역이 없을 때 키를 보여주는 것과 같음)
+        let text = default.unwrap_or(key);
+        if catalog.insert_default(key, text).map_err(|e| catalog_error(e.to_string()))? {
+            new_keys.push((key.to_string(), text.to_string()));
+        }
+    }
+
+    let mut changes = Vec::new();
+    let after = catalog.render();
+    if after != before {
+        changes.push(FileChange {
+            path: catalog_path.to_path_buf(),
+            original: before,
+            updated: after,
+            wraps: Vec::new(),
+        });
+    }
+
+    // sidecar: 사람이 적은 comment 등은 유지하고 references/description/maxLength만 새로 계산
+    let meta_file = meta_path(catalog_path);
+    let meta_before = fs::read_to_string(&meta_file).unwrap_or_default();
+    let mut meta = if meta_before.trim().is_empty() {
+        Value::object()
+    } else {
+        json::parse(&meta_before).map_err(|e| catalog_error(format!("{}:{}", meta_file.display(), e)))?
+    };
+    let existing: Vec<String> = meta.as_object().map(|e| e.iter().map(|(k, _)| k.clone()).collect()).unwrap_or_default();
+    for key in &existing {
+        if let Some(entry) = meta.get_mut(key) {
+            for generated in ["references", "description", "maxLength"] {
+                entry.remove(generated);
+            }
+            if entry.as_object().map(|e| e.is_empty()).unwrap_or(true) {
+                meta.remove(key);
+            }
+        }
+    }
+    for (key, _, found_at) in &keys {
+        if meta.get(key).is_none() {
+            meta.insert(key, Value::object());
+        }
+        let mut locations: Vec<String> = Vec::new();
+        for found in found_at {
+            let location = format!("{}:{}", reference(found), found.line);
+            if !locations.contains(&location) {
+                locations.push(location);
+            }
+        }
+        let references = Value::Array(locations.iter().map(|l| l.as_str().into()).collect());
+        if let Some(entry) = meta.get_mut(key) {
+            entry.insert("references", references);
+            if let Some(description) = describe(found_at) {
+                entry.insert("description", description.as_str().into());
+            }
+            if let Some(max) = found_at.iter().filter_map(|r| r.max_length).min() {
+                entry.insert("maxLength", max.into());
+            }
+        }
+    }
+    let mut meta_after = meta.to_pretty_string(&json::detect_indent(&meta_before));
+    meta_after.push('\n');
+    // 키가 하나도 없으면 빈 sidecar를 새로 만들지 않음
+    let empty = meta.as_object().map(|e| e.is_empty()).unwrap_or(true) && meta_before.trim().is_empty();
+    if meta_after != meta_before && !empty {
+        changes.push(FileChange {
+            path: meta_file,
+            original: meta_before,
+            updated: meta_after,
+            wraps: Vec::new(),
+        });
+    }
+
+    Ok(ExtractPlan {
+        changes,
+        keys: keys.len(),
+        new_keys,
+    })
+}
+
+// ============================================
+// CLI 진입점: 스캔 → 병합 → process::apply_changes → 요약
+// ============================================
+// memory가 있으면 새 키에 번역 메모리를 적용해서 대상 로케일 카탈로그도 함께 갱신
+#[cfg(feature = "swc")]
+pub fn run_extract(
+    options: &ProcessOptions,
+    memory: Option<&MemoryOptions>,
+    reference: impl Fn(&KeyReference) -> String,
+) {
+    let (references, mut errors) = scan_files(&options.files, options.framework);
+    match plan_extract(&references, &options.catalog_path, reference) {
+        Ok(mut plan) => {
+            let mut reuse = memory.and_then(|memory| {
+                match memory::update_and_reuse(memory, &options.catalog_path, &plan.new_keys) {
+                    Ok(reuse) => Some(reuse),
+                    Err(diagnostic) => {
+                        errors.push(*diagnostic);
+                        None
+                    }
+                }
+            });
+            if let Some(reuse) = &mut reuse {
+                plan.changes.append(&mut reuse.changes);
+            }
+
+            errors.extend(process::apply_changes(options, &plan.changes));
+            println!();
+            println!("요약:");
+            for (key, _) in &plan.new_keys {
+                println!("  + {}", key);
+            }
+            naming::print_issues(&naming::issues(
+                &options.key_naming,
+                plan.new_keys.iter().map(|(key, _)| key.as_str()),
+            ));
+            if let Some(reuse) = &reuse {
+                for (locale, key) in &reuse.reused {
+                    println!("  = {} {} (번역 메모리 재사용)", locale, key);
+                }
+                for (locale, key, suggestion) in &reuse.suggestions {
+                    println!(
+                        "  ~ {} {}: {:.0}% {:?} -> {:?}",
+                        locale,
+                        key,
+                        suggestion.score * 100.0,
+                        suggestion.source,
+                        suggestion.translation
+                    );
+                }
+            }
+            println!(
+                "  파일 {}개에서 키 {}개, 새 키 {}개, 변경 파일 {}개",
+                options.files.len(),
+                plan.keys,
+                plan.new_keys.len(),
+                plan.changes.len()
+            );
+            process::print_dry_run_notice(options);
+        }
+        Err(diagnostic) => errors.push(*diagnostic),
+    }
+    let color = diagnostic::use_color();
+    for diagnostic in &errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::{self, TempDir};
+
+    #[test]
+    fn test_plan_extract_edge_cases() {
+        let dir = TempDir::new("extract-edge");
+        let catalog = dir.write("en.json", "{\n  \"save\": \"Save changes\"\n}\n");
+        // 사람이 적은 comment는 유지, 코드에서 사라진 키의 생성 항목은 정리
+        dir.write(
+            "en.meta.json",
+            r#"{ "save": { "comment": "verb", "references": ["src/Old.jsx:1"] }, "gone": { "references": ["src/Old.jsx:2"] } }"#,
+        );
+        let at = |key: &str, default: Option<&str>, line: usize, max_length: Option<usize>| KeyReference {
+            default: default.map(String::from),
+            line,
+            max_length,
+            ..testutil::reference(key)
+        };
+        let references = vec![
+            // 첫 참조에 기본값이 없으면 나중에 처음 지정된 기본값을 사용
+            at("cart.title", None, 3, Some(20)),
+            at("cart.title", Some("Cart"), 9, Some(12)),
+            at("cart.title", Some("Basket"), 9, None),
+            // 이미 있는 번역은 코드의 기본값으로 덮어쓰지 않음
+            at("save", Some("Save"), 5, None),
+        ];
+
+        let plan = plan_extract(&references, &catalog, |r| r.path.clone()).unwrap();
+        assert_eq!(plan.keys, 2);
+        assert_eq!(plan.new_keys, vec![("cart.title".to_string(), "Cart".to_string())]);
+        assert_eq!(
+            plan.changes[0].updated,
+            "{\n  \"save\": \"Save changes\",\n  \"cart.title\": \"Cart\"\n}\n"
+        );
+        let meta = json::parse(&plan.changes[1].updated).unwrap();
+        assert!(meta.get("gone").is_none());
+        let save = meta.get("save").unwrap();
+        assert_eq!(save.get("comment").and_then(Value::as_str), Some("verb"));
+        assert_eq!(save.get("references"), Some(&Value::Array(vec!["src/App.jsx:5".into()])));
+        // 같은 위치는 한 번만, maxLength는 가장 작은 값
+        let title = meta.get("cart.title").unwrap();
+        assert_eq!(
+            title.get("references"),
+            Some(&Value::Array(vec!["src/App.jsx:3".into(), "src/App.jsx:9".into()]))
+        );
+        assert_eq!(title.get("maxLength").and_then(Value::as_u64), Some(12));
+
+        // 중첩 카탈로그에서 하위 키가 있는 자리에 문자열을 넣으려 함
+        let nested = dir.write("nested.json", "{\n  \"cart\": {\n    \"title\": \"Cart\"\n  }\n}\n");
+        let err = plan_extract(&[at("cart", Some("Cart"), 1, None)], &nested, |r| r.path.clone()).err().unwrap();
+        assert_eq!(err.code, diagnostic::CATALOG_ERROR);
+        assert!(err.message.contains("`cart`"));
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_scan_references() {
+        let code = r#"
+import { Trans } from 'react-i18next';
+const App = () => (
+  <div title={t('app.title', 'Title')}>
+    {/**
+      * @translator Main paragraph, max-length: 40
+      */}
+    {i18n.t('app.body', { defaultValue: 'Body text' })}
+    {t(dynamicKey)}
+    <Trans i18nKey="app.welcome">
+      Welcome <b>back</b>
+    </Trans>
+    <Trans i18nKey="app.bye">
+      See you
+      soon
+    </Trans>
+  </div>
+);
+"#;
+        let found = scan_references("src/App.jsx", code, Framework::ReactI18next).unwrap();
+        let summary: Vec<(&str, Option<&str>, ReferenceKind)> =
+            found.iter().map(|r| (r.key.as_str(), r.default.as_deref(), r.kind.clone())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("app.title", Some("Title"), ReferenceKind::Call),
+                ("app.body", Some("Body text"), ReferenceKind::Call),
+                ("app.welcome", None, ReferenceKind::Trans),
+                ("app.bye", Some("See you soon"), ReferenceKind::Trans),
+            ]
+        );
+        assert_eq!(&code[found[0].start..found[0].end], "'app.title'");
+        assert_eq!((found[0].line, found[0].column), (4, 17));
+        assert_eq!(found[0].component.as_deref(), Some("App"));
+        assert_eq!((found[0].element.as_deref(), found[0].attribute.as_deref()), (Some("div"), Some("title")));
+        assert_eq!((found[1].element.as_deref(), found[1].attribute.as_deref()), (Some("div"), None));
+        assert_eq!(found[1].context.as_deref(), Some("Main paragraph, max-length: 40"));
+        assert_eq!(found[1].max_length, Some(40));
+        assert_eq!(context_comment("// i18n: 저장 버튼 (동사)\nt('save')", 2).as_deref(), Some("저장 버튼 (동사)"));
+        assert_eq!(context_comment("<p>{/* i18n: 제목 */}{t('x')}</p>", 1).as_deref(), Some("제목"));
+        assert_eq!(context_comment("t('x') // i18n-ignore", 1), None);
+
+        let dir = TempDir::new("extract");
+        let catalog = dir.join("en.json");
+        fs::write(&catalog, "{\n  \"app.title\": \"Existing\"\n}\n").unwrap();
+        fs::write(meta_path(&catalog), "{\n  \"app.title\": { \"comment\": \"Page title\" }\n}\n").unwrap();
+        let plan = plan_extract(&found, &catalog, |r| r.path.clone()).unwrap();
+        let new_keys: Vec<&str> = plan.new_keys.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(new_keys, vec!["app.body", "app.welcome", "app.bye"]);
+        assert_eq!(plan.new_keys[0].1, "Body text");
+        let meta = json::parse(&plan.changes[1].updated).unwrap();
+        let title = meta.get("app.title").unwrap();
+        assert_eq!(title.get("comment").and_then(Value::as_str), Some("Page title"));
+        assert_eq!(
+            title.get("references").and_then(Value::as_array).map(|r| r[0].clone()),
+            Some(Value::from("src/App.jsx:4"))
+        );
+        assert_eq!(
+            meta.get("app.body").and_then(|e| e.get("description")).and_then(Value::as_str),
+            Some("Main paragraph, max-length: 40\nComponent: App\nElement: <div>\nMax length: 40")
+        );
+        assert_eq!(meta.get("app.body").and_then(|e| e.get("maxLength")).and_then(Value::as_u64), Some(40));
+    }
+
+    // (키, 기본값, 종류)
+    #[cfg(feature = "swc")]
+    fn scan(code: &str, framework: Framework) -> Vec<(String, Option<String>, ReferenceKind)> {
+        let found = scan_references("src/App.jsx", code, framework).unwrap();
+        found.into_iter().map(|r| (r.key, r.default, r.kind)).collect()
+    }
+
+    #[cfg(feature = "swc")]
+    fn call(key: &str, default: Option<&str>) -> (String, Option<String>, ReferenceKind) {
+        (key.to_string(), default.map(String::from), ReferenceKind::Call)
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_scan_i18next_calls_and_aliases() {
+        let code = r#"
+import i18next, { t as translate } from 'i18next';
+const title = translate('app.title', 'Title');
+const body = i18next.t('app.body');
+const ignored = <Trans i18nKey="app.trans" />;
+"#;
+        assert_eq!(
+            scan(code, Framework::I18next),
+            vec![call("app.title", Some("Title")), call("app.body", None)]
+        );
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_scan_react_i18next_hook_alias_and_trans() {
+        let code = r#"
+import { Trans as T, useTranslation } from 'react-i18next';
+function App() {
+  const { t: tr } = useTranslation();
+  return <p title={tr('app.title')}><T i18nKey="app.welcome" defaults="Welcome" /></p>;
+}
+"#;
+        let found = scan(code, Framework::ReactI18next);
+        assert_eq!(found[0], call("app.title", None));
+        assert_eq!(found[1], ("app.welcome".to_string(), Some("Welcome".to_string()), ReferenceKind::Trans));
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_scan_vue_i18n_calls() {
+        let code = r#"
+const { t } = useI18n();
+export default {
+  render() {
+    return [this.$t('app.title'), $t('app.body', 'Body'), t('app.setup'), i18n.global.t('app.global'), this.$tc('app.items', 2)];
+  },
+};
+"#;
+        let keys: Vec<String> = scan(code, Framework::VueI18n).into_iter().map(|(key, _, _)| key).collect();
+        assert_eq!(keys, vec!["app.title", "app.body", "app.setup", "app.global", "app.items"]);
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_scan_react_intl_descriptors_and_formatted_message() {
+        let code = r#"
+import { FormattedMessage, useIntl } from 'react-intl';
+function App() {
+  const intl = useIntl();
+  const { formatMessage: fm } = intl;
+  return (
+    <div title={intl.formatMessage({ id: 'app.title', defaultMessage: 'Title' })}>
+      {fm({ id: 'app.body' })}
+      <FormattedMessage id="app.welcome" defaultMessage="Welcome" />
+      {t('not.intl')}
+    </div>
+  );
+}
+"#;
+        assert_eq!(
+            scan(code, Framework::ReactIntl),
+            vec![
+                call("app.title", Some("Title")),
+                call("app.body", None),
+                ("app.welcome".to_string(), Some("Welcome".to_string()), ReferenceKind::Trans),
+            ]
+        );
+    }
+}
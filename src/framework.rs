@@ -0,0 +1,101 @@
+// ============================================
+// i18n-wrapper: 번역 라이브러리 (설정의 framework)
+// extract/rename/prune 등이 찾는 호출 이름과 컴포넌트, process가 코드를 감쌀 수 있는지를 결정
+// JSX를 다루는 도구라 기본값은 react-i18next
+// TypeScript:
+// type Framework = "i18next" | "react-i18next" | "vue-i18n" | "react-intl";
+// ============================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Framework {
+    // t('key'), i18next.t('key')
+    I18next,
+    // i18next + useTranslation() + <Trans i18nKey="key">
+    #[default]
+    ReactI18next,
+    // $t('key'), this.$t('key'), useI18n()의 t('key')
+    VueI18n,
+    // intl.formatMessage({ id: 'key' }), <FormattedMessage id="key" />
+    ReactIntl,
+}
+
+// 번역 호출의 인자 형식
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CallStyle {
+    // t('key', 'Default'), t('key', { defaultValue: 'Default' })
+    Positional,
+    // formatMessage({ id: 'key', defaultMessage: 'Default' })
+    Descriptor,
+}
+
+// <Trans i18nKey="key" defaults="Default"> / <FormattedMessage id="key" defaultMessage="Default" />
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Component {
+    pub name: &'static str,
+    pub key: &'static str,
+    pub default: &'static str,
+}
+
+pub const FRAMEWORK_NAMES: &str = "i18next, react-i18next, vue-i18n, react-intl";
+
+impl Framework {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "i18next" => Some(Framework::I18next),
+            "react-i18next" => Some(Framework::ReactI18next),
+            "vue-i18n" => Some(Framework::VueI18n),
+            "react-intl" => Some(Framework::ReactIntl),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Framework::I18next => "i18next",
+            Framework::ReactI18next => "react-i18next",
+            Framework::VueI18n => "vue-i18n",
+            Framework::ReactIntl => "react-intl",
+        }
+    }
+
+    // 키를 받는 번역 함수: 이름으로 부르거나 (t) 멤버로 부름 (i18n.t, this.$t, intl.formatMessage)
+    // `import { t as tr }`, `const { t: tr } = useTranslation()` 같은 별칭은 extract가 따로 추적
+    pub fn call_names(self) -> &'static [&'static str] {
+        match self {
+            Framework::I18next | Framework::ReactI18next => &["t"],
+            // tc/$tc: vue-i18n v8의 복수형 호출
+            Framework::VueI18n => &["t", "$t", "tc", "$tc"],
+            Framework::ReactIntl => &["formatMessage", "defineMessage"],
+        }
+    }
+
+    pub fn call_style(self) -> CallStyle {
+        match self {
+            Framework::ReactIntl => CallStyle::Descriptor,
+            Framework::I18next | Framework::ReactI18next | Framework::VueI18n => CallStyle::Positional,
+        }
+    }
+
+    // 키를 속성으로 받는 JSX 컴포넌트
+    pub fn component(self) -> Option<Component> {
+        match self {
+            Framework::ReactI18next => Some(Component {
+                name: "Trans",
+                key: "i18nKey",
+                default: "defaults",
+            }),
+            Framework::ReactIntl => Some(Component {
+                name: "FormattedMessage",
+                key: "id",
+                default: "defaultMessage",
+            }),
+            Framework::I18next | Framework::VueI18n => None,
+        }
+    }
+
+    // process는 t('key') + `import { t } from 'i18next'` 로만 감쌈
+    // vue-i18n/react-intl은 컴포넌트마다 훅(useI18n/useIntl)이 필요해서 extract/check 등만 지원
+    pub fn can_wrap(self) -> bool {
+        matches!(self, Framework::I18next | Framework::ReactI18next)
+    }
+}
@@ -0,0 +1,487 @@
+// ============================================
+// i18n-wrapper: glob 매칭 + 디렉토리 순회
+// TypeScript:
+// const files = await glob("src/**/*.{ts,tsx}", { ignore: ["**/*.test.tsx"] });
+// file_io.rs의 directory_operations처럼 fs::read_dir로 직접 순회
+// .gitignore / .i18nignore 를 따르고 node_modules는 기본으로 건너뜀
+// ============================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::diagnostic::{self, Diagnostic};
+
+pub const IGNORE_FILES: [&str; 2] = [".gitignore", ".i18nignore"];
+pub const SKIPPED_DIRS: [&str; 2] = ["node_modules", ".git"];
+
+// ============================================
+// 패턴 하나 ("src/**/*.{ts,tsx}")
+// 중괄호는 미리 펼쳐서 여러 개의 세그먼트 목록으로 보관
+// ============================================
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    alternatives: Vec<Vec<String>>,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Self {
+        let pattern = pattern.trim_start_matches("./");
+        let alternatives = expand_braces(pattern)
+            .into_iter()
+            .map(|p| p.split('/').filter(|s| !s.is_empty()).map(String::from).collect())
+            .collect();
+        Self { alternatives }
+    }
+
+    // path는 '/'로 구분된 상대 경로
+    pub fn matches(&self, path: &str) -> bool {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        self.alternatives.iter().any(|alt| {
+            let alt: Vec<&str> = alt.iter().map(String::as_str).collect();
+            match_segments(&alt, &segments)
+        })
+    }
+}
+
+pub fn has_magic(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '[', '{'])
+}
+
+// "a{b,c{d,e}}" -> ["ab", "acd", "ace"]
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let open = match pattern.find('{') {
+        Some(open) => open,
+        None => return vec![pattern.to_string()],
+    };
+    let mut depth = 0;
+    let mut close = None;
+    let mut commas = Vec::new();
+    for (i, c) in pattern[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(open + i);
+                    break;
+                }
+            }
+            ',' if depth == 1 => commas.push(open + i),
+            _ => {}
+        }
+    }
+    // 닫히지 않은 중괄호는 문자 그대로
+    let close = match close {
+        Some(close) => close,
+        None => return vec![pattern.to_string()],
+    };
+
+    let mut bounds = vec![open];
+    bounds.extend(commas);
+    bounds.push(close);
+    let (prefix, suffix) = (&pattern[..open], &pattern[close + 1..]);
+    bounds
+        .windows(2)
+        .flat_map(|w| expand_braces(&format!("{}{}{}", prefix, &pattern[w[0] + 1..w[1]], suffix)))
+        .collect()
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        // **는 0개 이상의 디렉토리
+        Some(&"**") => (0..=path.len()).any(|skip| match_segments(&pattern[1..], &path[skip..])),
+        Some(segment) => {
+            !path.is_empty() && match_segment(segment, path[0]) && match_segments(&pattern[1..], &path[1..])
+        }
+    }
+}
+
+// 세그먼트 하나: *, ?, [abc], [a-z], [!a]
+fn match_segment(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    match_chars(&pattern, &name)
+}
+
+fn match_chars(pattern: &[char], name: &[char]) -> bool {
+    match pattern.first() {
+        None => name.is_empty(),
+        Some('*') => (0..=name.len()).any(|skip| match_chars(&pattern[1..], &name[skip..])),
+        Some('?') => !name.is_empty() && match_chars(&pattern[1..], &name[1..]),
+        Some('[') => match parse_class(pattern) {
+            Some((matcher, len)) => {
+                !name.is_empty() && matcher(name[0]) && match_chars(&pattern[len..], &name[1..])
+            }
+            None => name.first() == Some(&'[') && match_chars(&pattern[1..], &name[1..]),
+        },
+        Some(c) => name.first() == Some(c) && match_chars(&pattern[1..], &name[1..]),
+    }
+}
+
+// [..] 를 해석해서 (매처, 패턴에서 차지하는 길이) 반환
+fn parse_class(pattern: &[char]) -> Option<(impl Fn(char) -> bool, usize)> {
+    let close = pattern.iter().skip(2).position(|&c| c == ']')? + 2;
+    let mut body = &pattern[1..close];
+    let negated = matches!(body.first(), Some('!' | '^'));
+    if negated {
+        body = &body[1..];
+    }
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < body.len() {
+        if i + 2 < body.len() && body[i + 1] == '-' {
+            ranges.push((body[i], body[i + 2]));
+            i += 3;
+        } else {
+            ranges.push((body[i], body[i]));
+            i += 1;
+        }
+    }
+    let matcher = move |c: char| ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != negated;
+    Some((matcher, close + 1))
+}
+
+// ============================================
+// 여러 패턴: "!"로 시작하면 제외 패턴
+// ============================================
+#[derive(Debug, Clone, Default)]
+pub struct GlobSet {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl GlobSet {
+    pub fn new(includes: &[String], excludes: &[String]) -> Self {
+        let mut set = Self::default();
+        for pattern in includes {
+            match pattern.strip_prefix('!') {
+                Some(negated) => set.exclude.push(Pattern::new(negated)),
+                None => set.include.push(Pattern::new(pattern)),
+            }
+        }
+        set.exclude.extend(excludes.iter().map(|p| Pattern::new(p.trim_start_matches('!'))));
+        set
+    }
+
+    pub fn matches(&self, path: &str) -> bool {
+        self.include.iter().any(|p| p.matches(path)) && !self.excludes(path)
+    }
+
+    pub fn excludes(&self, path: &str) -> bool {
+        self.exclude.iter().any(|p| p.matches(path))
+    }
+}
+
+// ============================================
+// .gitignore 규칙
+// - "#" 주석, "!" 되살리기, 끝의 "/"는 디렉토리만
+// - 중간에 "/"가 있으면 그 파일이 있는 디렉토리 기준, 없으면 모든 깊이
+// ============================================
+#[derive(Debug, Clone)]
+struct IgnoreRule {
+    // 규칙 파일이 있는 디렉토리 (루트 기준 상대 경로)
+    base: String,
+    pattern: Pattern,
+    negated: bool,
+    dir_only: bool,
+}
+
+fn parse_ignore_file(text: &str, base: &str) -> Vec<IgnoreRule> {
+    let mut rules = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let anchored = line.trim_start_matches('/').contains('/') || line.starts_with('/');
+        let pattern = if anchored {
+            line.trim_start_matches('/').to_string()
+        } else {
+            format!("**/{}", line)
+        };
+        rules.push(IgnoreRule {
+            base: base.to_string(),
+            pattern: Pattern::new(&pattern),
+            negated,
+            dir_only,
+        });
+    }
+    rules
+}
+
+// 마지막으로 매칭된 규칙이 결과를 결정
+fn is_ignored(rules: &[IgnoreRule], path: &str, is_dir: bool) -> bool {
+    let mut ignored = false;
+    for rule in rules {
+        if rule.dir_only && !is_dir {
+            continue;
+        }
+        let relative = if rule.base.is_empty() {
+            Some(path)
+        } else {
+            path.strip_prefix(rule.base.as_str()).and_then(|p| p.strip_prefix('/'))
+        };
+        if let Some(relative) = relative {
+            if rule.pattern.matches(relative) {
+                ignored = !rule.negated;
+            }
+        }
+    }
+    ignored
+}
+
+// ============================================
+// 디렉토리 순회
+// root 아래에서 GlobSet에 맞는 파일을 정렬된 순서로 반환
+// root를 읽지 못하면 Err, 읽지 못한 하위 디렉토리는 건너뛰고 경고로 반환
+// ============================================
+pub fn walk(root: &Path, set: &GlobSet) -> io::Result<(Vec<PathBuf>, Vec<Diagnostic>)> {
+    let mut files = Vec::new();
+    let mut warnings = Vec::new();
+    walk_dir(root, "", set, &mut Vec::new(), &mut files, &mut warnings)?;
+    files.sort();
+    Ok((files, warnings))
+}
+
+fn walk_dir(
+    root: &Path,
+    relative: &str,
+    set: &GlobSet,
+    rules: &mut Vec<IgnoreRule>,
+    files: &mut Vec<PathBuf>,
+    warnings: &mut Vec<Diagnostic>,
+) -> io::Result<()> {
+    let dir = root.join(relative);
+    let dir = if dir.as_os_str().is_empty() { PathBuf::from(".") } else { dir };
+
+    // 이 디렉토리의 ignore 파일은 하위 디렉토리에만 적용
+    let rules_before = rules.len();
+    for name in IGNORE_FILES {
+        if let Ok(text) = fs::read_to_string(dir.join(name)) {
+            rules.extend(parse_ignore_file(&text, relative));
+        }
+    }
+
+    let skipped = |e: io::Error, path: &Path| {
+        Diagnostic::warning(diagnostic::IO_ERROR, format!("읽지 못해 건너뜀: {}", e), path)
+    };
+    let mut entries: Vec<_> = match fs::read_dir(&dir).and_then(|entries| entries.collect::<Result<_, _>>()) {
+        Ok(entries) => entries,
+        Err(e) if relative.is_empty() => return Err(e),
+        Err(e) => {
+            warnings.push(skipped(e, &dir));
+            rules.truncate(rules_before);
+            return Ok(());
+        }
+    };
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = if relative.is_empty() {
+            name.clone()
+        } else {
+            format!("{}/{}", relative, name)
+        };
+        let is_dir = match entry.file_type() {
+            Ok(file_type) => file_type.is_dir(),
+            Err(e) => {
+                warnings.push(skipped(e, &entry.path()));
+                continue;
+            }
+        };
+        if is_ignored(rules, &path, is_dir) {
+            continue;
+        }
+        if is_dir {
+            if !SKIPPED_DIRS.contains(&name.as_str()) {
+                walk_dir(root, &path, set, rules, files, warnings)?;
+            }
+        } else if set.matches(&path) {
+            files.push(root.join(&path));
+        }
+    }
+    rules.truncate(rules_before);
+    Ok(())
+}
+
+// ============================================
+// CLI/설정의 패턴 목록을 파일 목록으로
+// glob 문자가 없는 경로는 그대로 사용 (파일을 직접 지정한 경우, exclude와 "!" 패턴은 적용)
+// 패턴은 root 아래만 순회하며 맞춰보므로 "../"나 절대 경로 패턴은 에러
+// ============================================
+pub fn expand(root: &Path, includes: &[String], excludes: &[String]) -> io::Result<(Vec<PathBuf>, Vec<Diagnostic>)> {
+    let is_literal = |p: &&String| !p.starts_with('!') && !has_magic(p);
+    let outside = includes.iter().filter(|p| !is_literal(p)).chain(excludes).find(|p| escapes_root(p));
+    if let Some(pattern) = outside {
+        let message = format!("패턴은 기준 디렉토리 안의 상대 경로여야 합니다: {}", pattern);
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+    }
+
+    let (literal, patterns): (Vec<&String>, Vec<&String>) = includes.iter().partition(is_literal);
+    let negations: Vec<String> = includes.iter().filter(|p| p.starts_with('!')).cloned().collect();
+    let excluded = GlobSet::new(&negations, excludes);
+    let mut files: Vec<PathBuf> = literal
+        .into_iter()
+        .filter(|p| relative_to_root(root, p).is_none_or(|relative| !excluded.excludes(&relative)))
+        .map(|p| root.join(p))
+        .collect();
+
+    let mut warnings = Vec::new();
+    if !patterns.is_empty() {
+        let patterns: Vec<String> = patterns.into_iter().cloned().chain(negations).collect();
+        let (found, skipped) = walk(root, &GlobSet::new(&patterns, excludes))?;
+        for file in found {
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+        warnings = skipped;
+    }
+    Ok((files, warnings))
+}
+
+// "../src/**", "/abs/**" 처럼 root 밖을 가리키는 패턴 ("!" 제외 표시는 무시)
+fn escapes_root(pattern: &str) -> bool {
+    let path = Path::new(pattern.trim_start_matches('!'));
+    path.has_root() || normalize(path).starts_with("..")
+}
+
+// exclude와 맞춰볼 root 기준 경로 ("src/App.tsx"), root 밖의 파일이면 None
+fn relative_to_root(root: &Path, path: &str) -> Option<String> {
+    let path = Path::new(path);
+    let relative = if path.is_absolute() {
+        let root = if root.as_os_str().is_empty() { Path::new(".") } else { root };
+        let root = normalize(&std::path::absolute(root).ok()?);
+        normalize(path).strip_prefix(root).ok()?.to_path_buf()
+    } else {
+        normalize(path)
+    };
+    if relative.starts_with("..") {
+        return None;
+    }
+    Some(relative.to_string_lossy().replace('\\', "/"))
+}
+
+// "src/./a/../b.ts" -> "src/b.ts" (파일 시스템을 보지 않고 경로 문자열만 정리)
+pub fn normalize(path: &Path) -> PathBuf {
+    use std::path::Component;
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(out.components().next_back(), Some(Component::Normal(_))) {
+                    out.pop();
+                } else {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn test_pattern_matching() {
+        let pattern = Pattern::new("src/**/*.{ts,tsx}");
+        assert!(pattern.matches("src/App.tsx"));
+        assert!(pattern.matches("src/components/deep/Button.ts"));
+        assert!(!pattern.matches("src/App.jsx"));
+        assert!(!pattern.matches("lib/App.tsx"));
+
+        assert!(Pattern::new("file?.[jt]s").matches("file1.js"));
+        assert!(!Pattern::new("file?.[!jt]s").matches("file1.js"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(Path::new("src/./a/../b.ts")), PathBuf::from("src/b.ts"));
+        assert_eq!(normalize(Path::new("../x/./y")), PathBuf::from("../x/y"));
+    }
+
+    #[test]
+    fn test_globset_negation() {
+        let set = GlobSet::new(&["src/**/*.tsx".to_string(), "!**/*.test.tsx".to_string()], &[]);
+        assert!(set.matches("src/App.tsx"));
+        assert!(!set.matches("src/App.test.tsx"));
+    }
+
+    #[test]
+    fn test_walk_honours_gitignore() {
+        let root = TempDir::new("glob");
+        root.write(".gitignore", "generated/\n*.gen.tsx\n");
+        root.write("src/.i18nignore", "keep/Legacy.tsx\n");
+        for file in [
+            "src/App.tsx",
+            "src/App.gen.tsx",
+            "src/generated/Api.tsx",
+            "src/keep/Legacy.tsx",
+            "src/keep/Modern.tsx",
+            "node_modules/lib/index.tsx",
+        ] {
+            root.write(file, "");
+        }
+
+        let (files, warnings) = walk(&root, &GlobSet::new(&["**/*.tsx".to_string()], &[])).unwrap();
+        assert!(warnings.is_empty());
+        let relative: Vec<String> = files
+            .iter()
+            .map(|f| f.strip_prefix(&*root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        assert_eq!(relative, vec!["src/App.tsx", "src/keep/Modern.tsx"]);
+    }
+
+    #[test]
+    fn test_unreadable_subdirectory_is_skipped_with_warning() {
+        let root = TempDir::new("glob-unreadable");
+        root.write("src/App.tsx", "");
+        let set = GlobSet::new(&["**/*.tsx".to_string()], &[]);
+        // 루트로 실행하면 권한으로 막을 수 없으므로 사라진 하위 디렉토리로 흉내냄
+        let mut files = Vec::new();
+        let mut warnings = Vec::new();
+        walk_dir(&root, "gone", &set, &mut Vec::new(), &mut files, &mut warnings).unwrap();
+        assert!(files.is_empty());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, diagnostic::IO_ERROR);
+        assert_eq!(warnings[0].path, root.join("gone"));
+
+        assert!(walk(&root.join("missing"), &set).is_err());
+    }
+
+    #[test]
+    fn test_expand_rejects_outside_patterns_and_excludes_literals() {
+        let root = TempDir::new("glob-expand");
+        let app = root.write("src/App.tsx", "");
+        let legacy = root.write("src/Legacy.tsx", "");
+        root.write("src/App.test.tsx", "");
+        let patterns = |list: &[&str]| list.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+
+        for outside in ["../shared/**/*.tsx", "/abs/**/*.tsx", "src/../../x/*.ts", "!../x/*.ts"] {
+            let error = expand(&root, &patterns(&[outside]), &[]).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", outside);
+        }
+        assert!(expand(&root, &patterns(&["src/*.tsx"]), &patterns(&["../x/**"])).is_err());
+
+        // 직접 지정한 파일도 exclude / "!" 패턴을 따름 (절대 경로 포함)
+        let includes = patterns(&["src/App.test.tsx", &legacy.to_string_lossy(), "src/App.tsx", "!src/Legacy.tsx"]);
+        let (files, warnings) = expand(&root, &includes, &patterns(&["**/*.test.tsx"])).unwrap();
+        assert_eq!(files, vec![app]);
+        assert!(warnings.is_empty());
+    }
+}
@@ -0,0 +1,550 @@
+// ============================================
+// i18n-wrapper: 최소 JSON 파서 / 직렬화
+// 키 순서를 보존해야 하므로 (로케일 파일 diff가 깔끔하도록)
+// Object를 HashMap이 아닌 Vec<(String, Value)>로 표현
+// ============================================
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    // 원본 표기를 그대로 보존 (1.0 -> 1 같은 변형 방지)
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl Value {
+    pub fn object() -> Self {
+        Value::Object(Vec::new())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        match self {
+            Value::Object(entries) => entries.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    // 키가 이미 있으면 그 자리에서 값만 교체 (순서 유지)
+    pub fn insert(&mut self, key: &str, value: Value) {
+        if let Value::Object(entries) = self {
+            match entries.iter_mut().find(|(k, _)| k == key) {
+                Some((_, existing)) => *existing = value,
+                None => entries.push((key.to_string(), value)),
+            }
+        }
+    }
+
+    // index 위치에 새 키 추가 (이미 있으면 insert처럼 값만 교체)
+    pub fn insert_at(&mut self, index: usize, key: &str, value: Value) {
+        if self.get(key).is_some() {
+            self.insert(key, value);
+        } else if let Value::Object(entries) = self {
+            entries.insert(index.min(entries.len()), (key.to_string(), value));
+        }
+    }
+
+    // 값과 위치는 그대로 두고 키 이름만 변경, 바꿨으면 true
+    pub fn rename_key(&mut self, old: &str, new: &str) -> bool {
+        match self {
+            Value::Object(entries) => match entries.iter_mut().find(|(k, _)| k == old) {
+                Some(entry) => {
+                    entry.0 = new.to_string();
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        match self {
+            Value::Object(entries) => {
+                let index = entries.iter().position(|(k, _)| k == key)?;
+                Some(entries.remove(index).1)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Value>> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&Vec<(String, Value)>> {
+        match self {
+            Value::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(raw) => raw.parse().ok(),
+            _ => None,
+        }
+    }
+
+    // 한 줄짜리 출력 (도구 연동용)
+    pub fn to_compact_string(&self) -> String {
+        let mut out = String::new();
+        write_value(&mut out, self, None, 0);
+        out
+    }
+
+    // 들여쓰기 출력 (로케일 파일 저장용)
+    pub fn to_pretty_string(&self, indent: &str) -> String {
+        let mut out = String::new();
+        write_value(&mut out, self, Some(indent), 0);
+        out
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(n: usize) -> Self {
+        Value::Number(n.to_string())
+    }
+}
+
+// ============================================
+// 직렬화
+// ============================================
+fn write_value(out: &mut String, value: &Value, indent: Option<&str>, depth: usize) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(raw) => out.push_str(raw),
+        Value::String(s) => write_string(out, s),
+        Value::Array(items) => {
+            if items.is_empty() {
+                out.push_str("[]");
+                return;
+            }
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(out, indent, depth + 1);
+                write_value(out, item, indent, depth + 1);
+            }
+            newline(out, indent, depth);
+            out.push(']');
+        }
+        Value::Object(entries) => {
+            if entries.is_empty() {
+                out.push_str("{}");
+                return;
+            }
+            out.push('{');
+            for (i, (key, item)) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(out, indent, depth + 1);
+                write_string(out, key);
+                out.push(':');
+                if indent.is_some() {
+                    out.push(' ');
+                }
+                write_value(out, item, indent, depth + 1);
+            }
+            newline(out, indent, depth);
+            out.push('}');
+        }
+    }
+}
+
+fn newline(out: &mut String, indent: Option<&str>, depth: usize) {
+    if let Some(indent) = indent {
+        out.push('\n');
+        for _ in 0..depth {
+            out.push_str(indent);
+        }
+    }
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// ============================================
+// 파싱
+// TypeScript: JSON.parse(text)
+// ============================================
+pub fn parse(text: &str) -> Result<Value, JsonError> {
+    let mut parser = JsonParser::new(text);
+    parser.skip_whitespace();
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+    Ok(value)
+}
+
+// 파싱하면서 각 객체 키가 나온 줄 번호도 기록 ("a.b" -> 3)
+// 설정 파일 에러를 "파일:줄: 키" 형태로 보고할 때 사용
+pub fn parse_with_key_lines(text: &str) -> Result<(Value, Vec<(String, usize)>), JsonError> {
+    let mut parser = JsonParser::new(text);
+    parser.key_lines = Some(Vec::new());
+    parser.skip_whitespace();
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+    Ok((value, parser.key_lines.unwrap_or_default()))
+}
+
+// 파일의 들여쓰기 단위를 추측 (첫 번째로 들여쓴 줄 기준)
+pub fn detect_indent(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let trimmed = line.trim_start_matches([' ', '\t']);
+            &line[..line.len() - trimmed.len()]
+        })
+        .find(|indent| !indent.is_empty())
+        .unwrap_or("  ")
+        .to_string()
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    pos: usize,
+    path: Vec<String>,
+    key_lines: Option<Vec<(String, usize)>>,
+}
+
+impl JsonParser {
+    // Windows 편집기가 붙이는 UTF-8 BOM은 건너뜀 (다시 쓸 때는 붙이지 않음)
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.strip_prefix('\u{feff}').unwrap_or(text).chars().collect(),
+            pos: 0,
+            path: Vec::new(),
+            key_lines: None,
+        }
+    }
+
+    fn error(&self, message: &str) -> JsonError {
+        let (line, column) = self.line_col(self.pos);
+        JsonError {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    fn line_col(&self, pos: usize) -> (usize, usize) {
+        let mut line = 1;
+        let mut column = 1;
+        for c in &self.chars[..pos.min(self.chars.len())] {
+            if *c == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        (line, column)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected)))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, JsonError> {
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => Ok(Value::String(self.parse_string()?)),
+            Some('t') => self.parse_keyword("true", Value::Bool(true)),
+            Some('f') => self.parse_keyword("false", Value::Bool(false)),
+            Some('n') => self.parse_keyword("null", Value::Null),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_keyword(&mut self, word: &str, value: Value) -> Result<Value, JsonError> {
+        for expected in word.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.error(&format!("invalid literal, expected '{}'", word)));
+            }
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> Result<Value, JsonError> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let raw: String = self.chars[start..self.pos].iter().collect();
+        if raw.parse::<f64>().is_err() {
+            return Err(self.error(&format!("invalid number '{}'", raw)));
+        }
+        Ok(Value::Number(raw))
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            let c = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+            self.pos += 1;
+            match c {
+                '"' => return Ok(out),
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.error("unterminated escape"))?;
+                    self.pos += 1;
+                    match escaped {
+                        '"' => out.push('"'),
+                        '\\' => out.push('\\'),
+                        '/' => out.push('/'),
+                        'b' => out.push('\u{8}'),
+                        'f' => out.push('\u{c}'),
+                        'n' => out.push('\n'),
+                        'r' => out.push('\r'),
+                        't' => out.push('\t'),
+                        'u' => out.push(self.parse_unicode_escape()?),
+                        _ => return Err(self.error("invalid escape sequence")),
+                    }
+                }
+                c => out.push(c),
+            }
+        }
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, JsonError> {
+        let high = self.parse_hex4()?;
+        // 서로게이트 쌍 처리 (이모지 등)
+        if (0xD800..0xDC00).contains(&high) {
+            if self.peek() == Some('\\') && self.chars.get(self.pos + 1) == Some(&'u') {
+                self.pos += 2;
+                let low = self.parse_hex4()?;
+                let combined = 0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF);
+                return char::from_u32(combined).ok_or_else(|| self.error("invalid surrogate pair"));
+            }
+            return Err(self.error("unpaired surrogate"));
+        }
+        char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, JsonError> {
+        if self.pos + 4 > self.chars.len() {
+            return Err(self.error("invalid unicode escape"));
+        }
+        let hex: String = self.chars[self.pos..self.pos + 4].iter().collect();
+        self.pos += 4;
+        u32::from_str_radix(&hex, 16).map_err(|_| self.error("invalid unicode escape"))
+    }
+
+    fn parse_array(&mut self) -> Result<Value, JsonError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            self.skip_whitespace();
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, JsonError> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Value::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key_pos = self.pos;
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            self.path.push(key.clone());
+            if self.key_lines.is_some() {
+                let line = self.line_col(key_pos).0;
+                let full = self.path.join(".");
+                if let Some(lines) = self.key_lines.as_mut() {
+                    lines.push((full, line));
+                }
+            }
+            let value = self.parse_value();
+            self.path.pop();
+            entries.push((key, value?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(entries));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_preserves_order() {
+        let text = "{\n  \"b\": 1,\n  \"a\": [true, null, \"x\"]\n}";
+        let value = parse(text).unwrap();
+        assert_eq!(value.to_pretty_string("  "), "{\n  \"b\": 1,\n  \"a\": [\n    true,\n    null,\n    \"x\"\n  ]\n}");
+    }
+
+    #[test]
+    fn test_escapes() {
+        let value = parse(r#""line\n\"q\" é 😀""#).unwrap();
+        assert_eq!(value.as_str(), Some("line\n\"q\" é 😀"));
+        assert_eq!(value.to_compact_string(), "\"line\\n\\\"q\\\" é 😀\"");
+    }
+
+    #[test]
+    fn test_leading_bom_is_ignored() {
+        let value = parse("\u{feff}{\n  \"a\": \"x\"\n}").unwrap();
+        assert_eq!(value.get("a").and_then(Value::as_str), Some("x"));
+        let (_, lines) = parse_with_key_lines("\u{feff}{\n  \"a\": 1\n}").unwrap();
+        assert_eq!(lines, vec![("a".to_string(), 2)]);
+        // 중간의 BOM은 여전히 에러
+        assert!(parse("{\"a\": \u{feff}1}").is_err());
+    }
+
+    #[test]
+    fn test_error_position() {
+        let err = parse("{\n  \"a\": tru\n}").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_key_lines() {
+        let (_, lines) = parse_with_key_lines("{\n  \"a\": {\n    \"b\": 1\n  }\n}").unwrap();
+        assert_eq!(lines, vec![("a".to_string(), 2), ("a.b".to_string(), 3)]);
+    }
+
+    #[test]
+    fn test_detect_indent() {
+        assert_eq!(detect_indent("{\n    \"a\": 1\n}"), "    ");
+        assert_eq!(detect_indent("{}"), "  ");
+    }
+}
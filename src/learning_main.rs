@@ -14,6 +14,43 @@ mod cli_examples;
 mod generics_traits;
 mod testing;
 
+// i18n-wrapper 도구 모듈
+mod json;
+mod diff;
+mod catalog;
+mod transform;
+mod process;
+mod review;
+mod config;
+mod glob;
+mod cache;
+mod diagnostic;
+mod report;
+mod lint;
+mod sarif;
+mod baseline;
+mod extract;
+mod memory;
+mod naming;
+mod stats;
+mod rename;
+mod prune;
+mod pseudo;
+mod sync;
+mod duplicates;
+mod export;
+mod framework;
+#[cfg(test)]
+mod testutil;
+#[cfg(all(feature = "async", feature = "swc"))]
+mod pipeline;
+#[cfg(feature = "async")]
+mod progress;
+#[cfg(all(feature = "async", feature = "swc"))]
+mod watch;
+#[cfg(feature = "async")]
+mod translate;
+
 // 모듈에서 함수 사용
 use collections::run_collections_examples;
 use error_handling::run_error_handling_examples;
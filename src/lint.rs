@@ -0,0 +1,379 @@
+// ============================================
+// i18n-wrapper: lint 규칙 (analyze --format sarif / check)
+// 분석 결과(ProcessReport)를 다시 훑어서 규칙별 Diagnostic을 만듦
+// 규칙 수준은 설정 파일의 [rules] 테이블로 바꿀 수 있음:
+//   [rules]
+//   hardcoded-string = "error"
+//   unresolved-import = "off"
+// key-naming 규칙의 세부 설정은 [key_naming] 테이블 (naming.rs)
+// ============================================
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::cache;
+use crate::catalog::{self, Catalog};
+use crate::config::{Config, RuleLevel};
+use crate::diagnostic::{self, Diagnostic, Severity};
+use crate::glob;
+use crate::json;
+use crate::naming;
+use crate::process::ProcessReport;
+use crate::transform;
+
+// check 명령의 종료 코드 (cli_examples::exit_codes_example 참고)
+// 발견 항목과 도구 에러를 구분해서 CI가 "코드 문제"와 "설정/환경 문제"를 나눠 처리할 수 있게 함
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_FINDINGS: i32 = 1;
+pub const EXIT_TOOL_ERROR: i32 = 2;
+
+pub struct Rule {
+    pub id: &'static str,
+    // 설정 파일 [rules]에서 쓰는 이름
+    pub name: &'static str,
+    pub level: RuleLevel,
+    pub description: &'static str,
+    pub help: &'static str,
+}
+
+// SARIF tool.driver.rules에도 그대로 들어감 (도구 에러 코드 포함)
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: diagnostic::HARDCODED_STRING,
+        name: "hardcoded-string",
+        level: RuleLevel::Error,
+        description: "사용자에게 보이는 문자열이 t()로 감싸져 있지 않습니다",
+        help: "i18n-wrapper process로 감싸거나, 의도한 문자열이면 i18n-ignore 주석을 붙이세요",
+    },
+    Rule {
+        id: diagnostic::UNRESOLVED_IMPORT,
+        name: "unresolved-import",
+        level: RuleLevel::Warn,
+        description: "상대 경로 import가 가리키는 파일을 찾을 수 없습니다",
+        help: "경로와 확장자(.ts, .tsx, .js, .jsx, index 파일)를 확인하세요",
+    },
+    Rule {
+        id: diagnostic::PLACEHOLDER_MISMATCH,
+        name: "placeholder-mismatch",
+        level: RuleLevel::Error,
+        description: "번역문의 {{자리표시자}}가 원문과 다릅니다",
+        help: "번역문에 원문과 같은 이름의 자리표시자를 사용하세요",
+    },
+    Rule {
+        id: diagnostic::KEY_NAMING,
+        name: "key-naming",
+        level: RuleLevel::Warn,
+        description: "카탈로그 키가 [key_naming] 이름 규칙을 따르지 않습니다",
+        help: "i18n-wrapper rename-key 로 소스 코드와 모든 로케일의 키를 함께 바꾸세요",
+    },
+    Rule {
+        id: diagnostic::PARSE_ERROR,
+        name: "parse-error",
+        level: RuleLevel::Error,
+        description: "소스 파일을 파싱할 수 없습니다",
+        help: "문법 오류가 있는 파일은 변환하지 않습니다",
+    },
+    Rule {
+        id: diagnostic::IO_ERROR,
+        name: "io-error",
+        level: RuleLevel::Error,
+        description: "파일을 읽을 수 없습니다",
+        help: "파일 권한과 경로를 확인하세요",
+    },
+    Rule {
+        id: diagnostic::TIMEOUT,
+        name: "timeout",
+        level: RuleLevel::Error,
+        description: "파일 처리 시간이 제한을 넘었습니다",
+        help: "--file-timeout 으로 제한 시간을 늘리거나 .i18nignore 에 추가하세요",
+    },
+    Rule {
+        id: diagnostic::CATALOG_ERROR,
+        name: "catalog-error",
+        level: RuleLevel::Error,
+        description: "로케일 카탈로그를 읽을 수 없습니다",
+        help: "카탈로그가 올바른 JSON 객체인지 확인하세요",
+    },
+    Rule {
+        id: diagnostic::WRITE_ERROR,
+        name: "write-error",
+        level: RuleLevel::Error,
+        description: "파일을 저장할 수 없습니다",
+        help: "파일 권한과 디스크 공간을 확인하세요",
+    },
+    Rule {
+        id: diagnostic::TRANSLATE_ERROR,
+        name: "translate-error",
+        level: RuleLevel::Error,
+        description: "기계 번역 제공자가 요청을 처리하지 못했습니다",
+        help: "--provider 설정(URL, 사전 파일, I18N_WRAPPER_API_KEY)을 확인하세요",
+    },
+];
+
+pub fn rule(id: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|rule| rule.id == id)
+}
+
+// 설정에서 off면 None
+fn severity(project: &Config, id: &str) -> Option<Severity> {
+    let rule = rule(id)?;
+    match project.rule_level(rule.name, rule.level) {
+        RuleLevel::Off => None,
+        RuleLevel::Warn => Some(Severity::Warning),
+        RuleLevel::Error => Some(Severity::Error),
+    }
+}
+
+// ============================================
+// 모든 규칙 실행. source_catalog는 --catalog 로 바뀔 수 있으므로 따로 받음
+// ============================================
+pub fn run(report: &ProcessReport, project: &Config, source_catalog: &Path) -> Vec<Diagnostic> {
+    let mut findings = Vec::new();
+    for analysis in &report.analyses {
+        let path = Path::new(&analysis.path);
+        let file = project_path(project, path);
+        // 스니펫/SARIF region 계산용 (읽을 수 없으면 위치 없이 보고)
+        let source: Option<Arc<str>> = fs::read_to_string(path).ok().map(Arc::from);
+        let with_source = |diagnostic: Diagnostic| match &source {
+            Some(source) => diagnostic.with_source(source.clone()),
+            None => diagnostic,
+        };
+
+        if let Some(severity) = severity(project, diagnostic::HARDCODED_STRING) {
+            // 제안하는 t() 호출은 process가 감쌀 수 있는 프레임워크일 때만 도움말로
+            let wraps = report
+                .changes
+                .iter()
+                .find(|c| c.path == path && project.framework.can_wrap())
+                .map(|c| c.wraps.as_slice())
+                .unwrap_or(&[]);
+            for s in analysis.strings.iter().filter(|s| !s.ignored) {
+                let message = format!("번역되지 않은 문자열 {:?}", s.text);
+                let mut finding = with_source(Diagnostic::new(severity, diagnostic::HARDCODED_STRING, message, path))
+                    .with_primary(s.start, s.end, "t()로 감싸지 않음")
+                    .with_fingerprint(&[&file, s.component.as_deref().unwrap_or(""), &s.text]);
+                if let Some(wrap) = wraps.iter().find(|w| w.start == s.start) {
+                    finding = finding.with_help(format!("{}로 바꾸세요", wrap.replacement));
+                }
+                let ignore = format!("무시하려면 윗줄에 `// {}` 주석을 추가하세요", transform::IGNORE_DIRECTIVE);
+                findings.push(finding.with_help(ignore));
+            }
+        }
+
+        if let Some(severity) = severity(project, diagnostic::UNRESOLVED_IMPORT) {
+            for (index, import) in analysis.imports.iter().enumerate() {
+                if !cache::is_relative(import) || cache::resolve_import(path, import).is_some() {
+                    continue;
+                }
+                let message = format!("import {:?}를 찾을 수 없습니다", import);
+                let mut finding = with_source(Diagnostic::new(severity, diagnostic::UNRESOLVED_IMPORT, message, path))
+                    .with_fingerprint(&[&file, import]);
+                if let Some(&(start, end)) = analysis.import_spans.get(index) {
+                    finding = finding.with_primary(start, end, "해석되지 않는 경로");
+                }
+                findings.push(finding);
+            }
+        }
+    }
+
+    if let Some(severity) = severity(project, diagnostic::PLACEHOLDER_MISMATCH) {
+        findings.extend(placeholder_mismatches(project, source_catalog, severity));
+    }
+    if let Some(severity) = severity(project, diagnostic::KEY_NAMING) {
+        findings.extend(key_naming_violations(project, source_catalog, severity));
+    }
+    findings
+}
+
+// 실행 위치와 무관하도록 설정 파일 디렉토리 기준 경로 ("/" 구분자)
+pub fn project_path(project: &Config, path: &Path) -> String {
+    let path = glob::normalize(path);
+    let root = glob::normalize(&project.root);
+    path.strip_prefix(&root).unwrap_or(&path).to_string_lossy().replace('\\', "/")
+}
+
+// 도구 에러가 있으면 결과를 믿을 수 없으므로 발견 항목보다 우선
+// 경고 수준 규칙은 출력만 하고 실패로 치지 않음
+pub fn exit_code(findings: &[Diagnostic], errors: &[Diagnostic]) -> i32 {
+    if !errors.is_empty() {
+        EXIT_TOOL_ERROR
+    } else if findings.iter().any(|f| f.severity == Severity::Error) {
+        EXIT_FINDINGS
+    } else {
+        EXIT_OK
+    }
+}
+
+// 원문 카탈로그와 각 로케일 카탈로그의 같은 키를 비교
+fn placeholder_mismatches(project: &Config, source_catalog: &Path, severity: Severity) -> Vec<Diagnostic> {
+    let source = match Catalog::load(source_catalog) {
+        Ok(source) => source,
+        // 카탈로그 에러는 분석 단계에서 이미 보고됨
+        Err(_) => return Vec::new(),
+    };
+    // 원문 항목의 줄 (help에 위치를 같이 보여줌)
+    let source_lines = fs::read_to_string(source_catalog)
+        .ok()
+        .and_then(|text| json::parse_with_key_lines(&text).ok())
+        .map(|(_, key_lines)| key_lines)
+        .unwrap_or_default();
+    let mut findings = Vec::new();
+    for locale in project.locales.iter().filter(|l| **l != project.source_locale) {
+        let path = project.catalog_path(locale);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(_) => continue,
+        };
+        let parsed = Catalog::parse(&path, &text).ok().zip(json::parse_with_key_lines(&text).ok());
+        let (catalog, key_lines) = match parsed {
+            Some((catalog, (_, key_lines))) => (catalog, key_lines),
+            None => continue,
+        };
+        let text: Arc<str> = Arc::from(text);
+        for (key, value) in catalog.entries() {
+            let expected = match source.get(&key) {
+                Some(original) => catalog::placeholders(original),
+                None => continue,
+            };
+            let actual = catalog::placeholders(&value);
+            if actual == expected {
+                continue;
+            }
+            let list = |names: &[String]| {
+                if names.is_empty() {
+                    "없음".to_string()
+                } else {
+                    names.iter().map(|n| format!("{{{{{}}}}}", n)).collect::<Vec<_>>().join(", ")
+                }
+            };
+            let message = format!(
+                "{} 번역 `{}`의 자리표시자가 원문과 다릅니다 (원문: {}, 번역: {})",
+                locale,
+                key,
+                list(&expected),
+                list(&actual)
+            );
+            let mut finding = Diagnostic::new(severity, diagnostic::PLACEHOLDER_MISMATCH, message, &path)
+                .with_source(text.clone())
+                .with_fingerprint(&[&project_path(project, &path), &key]);
+            if let Some((_, line)) = key_lines.iter().find(|(k, _)| *k == key) {
+                let (start, end) = line_span(&text, *line);
+                finding = finding.with_primary(start, end, format!("원문: {:?}", source.get(&key).unwrap_or("")));
+                // 원문에 없는 자리표시자 (오타 등)는 따로 표시
+                for (start, end, name) in placeholder_spans(&text, start, end) {
+                    if !expected.contains(&name) {
+                        finding = finding.with_label(start, end, "원문에 없음");
+                    }
+                }
+            }
+            if let Some((_, line)) = source_lines.iter().find(|(k, _)| *k == key) {
+                finding = finding.with_help(format!("원문 위치: {}:{}", project_path(project, source_catalog), line));
+            }
+            findings.push(finding);
+        }
+    }
+    findings
+}
+
+// 키 집합은 원문 카탈로그가 기준 (다른 로케일은 sync로 맞춤)
+fn key_naming_violations(project: &Config, source_catalog: &Path, severity: Severity) -> Vec<Diagnostic> {
+    let text = match fs::read_to_string(source_catalog) {
+        Ok(text) => text,
+        Err(_) => return Vec::new(),
+    };
+    let parsed = Catalog::parse(source_catalog, &text).ok().zip(json::parse_with_key_lines(&text).ok());
+    let (catalog, key_lines) = match parsed {
+        Some((catalog, (_, key_lines))) => (catalog, key_lines),
+        None => return Vec::new(),
+    };
+    let keys = catalog.keys();
+    let issues = naming::issues(&project.key_naming, keys.iter().map(String::as_str));
+    let text: Arc<str> = Arc::from(text);
+    let mut findings = Vec::new();
+    for issue in issues {
+        let message = format!("키 `{}`: {}", issue.key, issue.problems.join(", "));
+        let mut finding = Diagnostic::new(severity, diagnostic::KEY_NAMING, message, source_catalog)
+            .with_source(text.clone())
+            .with_fingerprint(&[&project_path(project, source_catalog), &issue.key]);
+        if let Some((_, line)) = key_lines.iter().find(|(k, _)| *k == issue.key) {
+            let (start, end) = line_span(&text, *line);
+            finding = finding.with_primary(start, end, "이름 규칙 위반");
+        }
+        if let Some(suggestion) = &issue.suggestion {
+            finding = finding.with_help(format!("i18n-wrapper rename-key {} {}", issue.key, suggestion));
+        }
+        findings.push(finding);
+    }
+    findings
+}
+
+// text[start..end] 안의 {{이름}} 위치와 이름 (catalog::placeholders와 같은 규칙)
+fn placeholder_spans(text: &str, start: usize, end: usize) -> Vec<(usize, usize, String)> {
+    let mut spans = Vec::new();
+    let mut offset = start;
+    while let Some(open) = text[offset..end].find("{{") {
+        let open = offset + open;
+        let close = match text[open + 2..end].find("}}") {
+            Some(close) => open + 2 + close,
+            None => break,
+        };
+        let name = text[open + 2..close].split(',').next().unwrap_or("").trim();
+        spans.push((open, close + 2, name.to_string()));
+        offset = close + 2;
+    }
+    spans
+}
+
+// n번째 줄(1부터)의 앞뒤 공백과 끝의 쉼표를 뺀 범위
+fn line_span(text: &str, line: usize) -> (usize, usize) {
+    let mut offset = 0;
+    for (index, content) in text.split_inclusive('\n').enumerate() {
+        if index + 1 == line {
+            let trimmed_start = content.len() - content.trim_start().len();
+            let trimmed = content.trim_end().trim_end_matches(',');
+            return (offset + trimmed_start, offset + trimmed.len().max(trimmed_start));
+        }
+        offset += content.len();
+    }
+    (text.len(), text.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn test_placeholder_mismatch_points_at_translation() {
+        let dir = TempDir::new("lint");
+        fs::write(dir.join("en.json"), "{\n  \"greet\": \"Hi {{name}}\",\n  \"bye\": \"Bye\"\n}\n").unwrap();
+        fs::write(dir.join("ko.json"), "{\n  \"bye\": \"잘 가\",\n  \"greet\": \"안녕 {{user}}\"\n}\n").unwrap();
+        let project = Config {
+            locales: vec!["en".to_string(), "ko".to_string()],
+            catalog: dir.join("{locale}.json").to_string_lossy().to_string(),
+            ..Default::default()
+        };
+
+        let findings = run(&ProcessReport::default(), &project, &dir.join("en.json"));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, diagnostic::PLACEHOLDER_MISMATCH);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert_eq!(findings[0].location(), Some((3, 3)));
+        assert!(findings[0].message.contains("{{name}}"));
+        // 원문에 없는 {{user}}는 보조 라벨, 원문 항목의 위치는 help로
+        let text = fs::read_to_string(dir.join("ko.json")).unwrap();
+        let user = text.find("{{user}}").unwrap();
+        assert_eq!(findings[0].secondary.len(), 1);
+        assert_eq!((findings[0].secondary[0].start, findings[0].secondary[0].end), (user, user + 8));
+        assert!(findings[0].help[0].ends_with("en.json:2"));
+        assert_eq!(exit_code(&findings, &[]), EXIT_FINDINGS);
+
+        let warn = Config {
+            rules: vec![("placeholder-mismatch".to_string(), RuleLevel::Warn)],
+            ..project
+        };
+        let findings = run(&ProcessReport::default(), &warn, &dir.join("en.json"));
+        assert_eq!(exit_code(&findings, &[]), EXIT_OK);
+    }
+}
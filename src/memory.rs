@@ -0,0 +1,432 @@
+// ============================================
+// i18n-wrapper: 번역 메모리 (translation memory)
+// 모든 카탈로그에서 "원문 → 로케일별 번역"을 모아 .i18n-wrapper/memory.json 에 보관
+// extract가 새 키를 추가할 때:
+//   원문이 똑같으면      -> 기존 번역을 대상 로케일 카탈로그에 그대로 재사용
+//   비슷하면 (편집 거리) -> 점수와 함께 제안만 출력
+// 실행할 때마다 현재 카탈로그의 번역으로 메모리를 갱신
+// translate가 needsReview로 표시한 기계 번역은 검토 전이라 배우지 않음
+//
+// {
+//   "version": 1,
+//   "sourceLocale": "en",
+//   "entries": [{ "source": "Save", "translations": { "ko": "저장", "ja": "保存" } }]
+// }
+// ============================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+use crate::extract;
+use crate::json::{self, Value};
+use crate::process::FileChange;
+
+pub const MEMORY_PATH: &str = ".i18n-wrapper/memory.json";
+pub const MEMORY_VERSION: usize = 1;
+pub const DEFAULT_THRESHOLD: f64 = 0.75;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryEntry {
+    pub source: String,
+    // (로케일, 번역)
+    pub translations: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub source: String,
+    pub translation: String,
+    // 0.0~1.0 (1.0이면 같은 문자열)
+    pub score: f64,
+}
+
+pub struct Memory {
+    path: PathBuf,
+    source_locale: String,
+    pub entries: Vec<MemoryEntry>,
+}
+
+impl Memory {
+    // 파일이 없으면 빈 상태로 시작
+    // 깨져 있거나 버전/원문 로케일이 다르면 Err (빈 메모리로 덮어쓰면 쌓아 둔 번역을 잃음)
+    pub fn load(path: &Path, source_locale: &str) -> Result<Self, String> {
+        let mut memory = Self {
+            path: path.to_path_buf(),
+            source_locale: source_locale.to_string(),
+            entries: Vec::new(),
+        };
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(memory),
+            Err(e) => return Err(e.to_string()),
+        };
+        let root = json::parse(&text).map_err(|e| e.to_string())?;
+        match root.get("version").and_then(Value::as_u64) {
+            Some(version) if version == MEMORY_VERSION as u64 => {}
+            Some(version) => return Err(format!("메모리 버전 {}은 지원하지 않습니다 (필요: {})", version, MEMORY_VERSION)),
+            None => return Err("메모리 파일에 version이 없습니다".to_string()),
+        }
+        match root.get("sourceLocale").and_then(Value::as_str) {
+            Some(locale) if locale == source_locale => {}
+            Some(locale) => {
+                return Err(format!("원문 로케일이 {}인 메모리입니다 (설정: {})", locale, source_locale));
+            }
+            None => return Err("메모리 파일에 sourceLocale이 없습니다".to_string()),
+        }
+        for item in root.get("entries").and_then(Value::as_array).into_iter().flatten() {
+            let source = match item.get("source").and_then(Value::as_str) {
+                Some(source) => source.to_string(),
+                None => continue,
+            };
+            let translations = item
+                .get("translations")
+                .and_then(Value::as_object)
+                .map(|pairs| {
+                    pairs
+                        .iter()
+                        .filter_map(|(locale, text)| text.as_str().map(|t| (locale.clone(), t.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default();
+            memory.entries.push(MemoryEntry { source, translations });
+        }
+        Ok(memory)
+    }
+
+    // 같은 원문/로케일은 최근 카탈로그 값으로 덮어씀
+    pub fn record(&mut self, source: &str, locale: &str, translation: &str) {
+        let index = match self.entries.iter().position(|e| e.source == source) {
+            Some(index) => index,
+            None => {
+                self.entries.push(MemoryEntry {
+                    source: source.to_string(),
+                    translations: Vec::new(),
+                });
+                self.entries.len() - 1
+            }
+        };
+        let translations = &mut self.entries[index].translations;
+        match translations.iter_mut().find(|(l, _)| l == locale) {
+            Some((_, existing)) => *existing = translation.to_string(),
+            None => translations.push((locale.to_string(), translation.to_string())),
+        }
+    }
+
+    // 원문 카탈로그와 같은 키를 가진 번역을 모두 기록
+    // 비어 있거나(sync --fill empty) 원문을 그대로 복사한(--fill source) 값은 번역이 아니므로 제외
+    // unreviewed: 검토 전 기계 번역 (로케일, 키), 재사용하면 검토 없이 퍼지므로 제외
+    pub fn learn(&mut self, source: &Catalog, targets: &[(String, Catalog)], unreviewed: &[(String, String)]) {
+        for (key, text) in source.entries() {
+            for (locale, catalog) in targets {
+                if unreviewed.iter().any(|(l, k)| l == locale && *k == key) {
+                    continue;
+                }
+                match catalog.get(&key) {
+                    Some(translation) if !translation.is_empty() && translation != text => {
+                        self.record(&text, locale, translation)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    pub fn exact(&self, source: &str, locale: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.source == source)
+            .and_then(|e| e.translations.iter().find(|(l, _)| l == locale))
+            .map(|(_, t)| t.as_str())
+    }
+
+    // threshold 이상인 비슷한 원문의 번역, 점수 높은 순
+    pub fn fuzzy(&self, source: &str, locale: &str, threshold: f64, limit: usize) -> Vec<Suggestion> {
+        let length = source.chars().count();
+        let mut found: Vec<Suggestion> = self
+            .entries
+            .iter()
+            .filter(|e| e.source != source)
+            .filter(|e| {
+                // 길이 차이만으로도 기준을 넘을 수 없으면 거리 계산 생략
+                let other = e.source.chars().count();
+                let longest = length.max(other).max(1);
+                1.0 - (length.abs_diff(other) as f64 / longest as f64) >= threshold
+            })
+            .filter_map(|e| {
+                let translation = e.translations.iter().find(|(l, _)| l == locale)?;
+                let score = similarity(source, &e.source);
+                (score >= threshold).then(|| Suggestion {
+                    source: e.source.clone(),
+                    translation: translation.1.clone(),
+                    score,
+                })
+            })
+            .collect();
+        found.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.source.cmp(&b.source)));
+        found.truncate(limit);
+        found
+    }
+
+    pub fn to_json(&self) -> Value {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let translations = entry
+                    .translations
+                    .iter()
+                    .map(|(locale, text)| (locale.clone(), text.as_str().into()))
+                    .collect();
+                Value::Object(vec![
+                    ("source".to_string(), entry.source.as_str().into()),
+                    ("translations".to_string(), Value::Object(translations)),
+                ])
+            })
+            .collect();
+        Value::Object(vec![
+            ("version".to_string(), MEMORY_VERSION.into()),
+            ("sourceLocale".to_string(), self.source_locale.as_str().into()),
+            ("entries".to_string(), Value::Array(entries)),
+        ])
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+        let mut text = self.to_json().to_pretty_string("  ");
+        text.push('\n');
+        crate::process::write_file(&self.path, &text)
+    }
+}
+
+// ============================================
+// extract 연동: 메모리 갱신 + 새 키에 재사용/제안
+// ============================================
+pub struct MemoryOptions {
+    pub path: PathBuf,
+    pub source_locale: String,
+    // 원문 로케일을 뺀 (로케일, 카탈로그 경로)
+    pub targets: Vec<(String, PathBuf)>,
+    pub threshold: f64,
+    // true면 메모리 파일을 쓰지 않음 (재사용/제안은 그대로 계산)
+    pub dry_run: bool,
+}
+
+pub struct Reuse {
+    // 대상 로케일 카탈로그 변경 (디스크에는 쓰지 않음)
+    pub changes: Vec<FileChange>,
+    // (로케일, 키)
+    pub reused: Vec<(String, String)>,
+    // (로케일, 키, 제안)
+    pub suggestions: Vec<(String, String, Suggestion)>,
+}
+
+// 제안은 키/로케일마다 점수 높은 순으로 이만큼만
+const SUGGESTIONS_PER_KEY: usize = 3;
+
+// new_keys: (키, 원문)
+pub fn update_and_reuse(
+    options: &MemoryOptions,
+    source_catalog: &Path,
+    new_keys: &[(String, String)],
+) -> Result<Reuse, Box<Diagnostic>> {
+    let source = Catalog::load_diagnostic(source_catalog)?;
+    let mut targets = Vec::new();
+    let mut unreviewed = Vec::new();
+    for (locale, path) in &options.targets {
+        targets.push((locale.clone(), Catalog::load_diagnostic(path)?));
+        unreviewed.extend(needs_review(path).into_iter().map(|key| (locale.clone(), key)));
+    }
+
+    let mut memory = Memory::load(&options.path, &options.source_locale).map_err(|message| {
+        Box::new(
+            Diagnostic::error(diagnostic::CATALOG_ERROR, message, &options.path)
+                .with_help("메모리 파일을 고치거나 다른 곳으로 옮긴 뒤 다시 실행하세요 (--no-memory로 건너뛸 수 있음)"),
+        )
+    })?;
+    memory.learn(&source, &targets, &unreviewed);
+    if !options.dry_run {
+        memory
+            .save()
+            .map_err(|e| Box::new(Diagnostic::error(diagnostic::WRITE_ERROR, e.to_string(), &options.path)))?;
+    }
+
+    let mut reuse = Reuse {
+        changes: Vec::new(),
+        reused: Vec::new(),
+        suggestions: Vec::new(),
+    };
+    for (locale, mut catalog) in targets {
+        let before = catalog.render();
+        for (key, text) in new_keys {
+            if catalog.get(key).map(|t| !t.is_empty()).unwrap_or(false) {
+                continue;
+            }
+            match memory.exact(text, &locale) {
+                Some(translation) => {
+                    catalog.insert(key, translation).map_err(|e| {
+                        Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, e.to_string(), &catalog.path))
+                    })?;
+                    reuse.reused.push((locale.clone(), key.clone()));
+                }
+                None => {
+                    for suggestion in memory.fuzzy(text, &locale, options.threshold, SUGGESTIONS_PER_KEY) {
+                        reuse.suggestions.push((locale.clone(), key.clone(), suggestion));
+                    }
+                }
+            }
+        }
+        let after = catalog.render();
+        if after != before {
+            reuse.changes.push(FileChange {
+                path: catalog.path.clone(),
+                original: before,
+                updated: after,
+                wraps: Vec::new(),
+            });
+        }
+    }
+    Ok(reuse)
+}
+
+// 카탈로그 sidecar에서 "needsReview": true 인 키 (sidecar가 없거나 깨졌으면 없음)
+fn needs_review(catalog: &Path) -> Vec<String> {
+    let meta = match fs::read_to_string(extract::meta_path(catalog)).ok().and_then(|text| json::parse(&text).ok()) {
+        Some(meta) => meta,
+        None => return Vec::new(),
+    };
+    meta.as_object()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter(|(_, entry)| entry.get("needsReview").and_then(Value::as_bool) == Some(true))
+                .map(|(key, _)| key.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// 대소문자/앞뒤 공백은 무시한 문자 단위 편집 거리 기반 유사도 (1 - 거리 / 긴 쪽 길이)
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.trim().to_lowercase().chars().collect();
+    let b: Vec<char> = b.trim().to_lowercase().chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(&a, &b) as f64 / longest as f64
+}
+
+// 두 줄만 유지하는 동적 계획법
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn test_memory_exact_and_fuzzy() {
+        let dir = TempDir::new("memory");
+        let source = Catalog::parse(
+            &dir.join("en.json"),
+            r#"{ "a": "Save changes", "b": "Delete file", "c": "Cancel" }"#,
+        )
+        .unwrap();
+        let ko = Catalog::parse(&dir.join("ko.json"), r#"{ "a": "변경 사항 저장", "b": "파일 삭제", "c": "Cancel" }"#).unwrap();
+
+        let path = dir.join("memory.json");
+        let mut memory = Memory::load(&path, "en").unwrap();
+        memory.learn(&source, &[("ko".to_string(), ko)], &[]);
+        // 원문을 그대로 복사한 값은 배우지 않음
+        assert_eq!(memory.exact("Cancel", "ko"), None);
+        assert_eq!(memory.exact("Save changes", "ko"), Some("변경 사항 저장"));
+        memory.save().unwrap();
+
+        let reloaded = Memory::load(&path, "en").unwrap();
+        assert_eq!(reloaded.entries, memory.entries);
+        let suggestions = reloaded.fuzzy("Save change", "ko", DEFAULT_THRESHOLD, 3);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].translation, "변경 사항 저장");
+        assert!((suggestions[0].score - 11.0 / 12.0).abs() < 1e-9);
+        assert!(reloaded.fuzzy("Delete folder", "ko", 0.9, 3).is_empty());
+        // 원문 로케일이 다르면 에러 (빈 메모리로 덮어쓰지 않음)
+        assert!(Memory::load(&path, "de").is_err());
+    }
+
+    #[test]
+    fn test_update_and_reuse_skips_unreviewed_and_dry_run() {
+        let dir = TempDir::new("memory-reuse");
+        let en = dir.write("en.json", "{\n  \"old\": \"Save\",\n  \"new\": \"Save\"\n}\n");
+        let ko = dir.write("ko.json", "{\n  \"old\": \"절약\"\n}\n");
+        dir.write("ko.meta.json", "{\n  \"old\": { \"needsReview\": true, \"translatedBy\": \"http\" }\n}\n");
+        let mut options = MemoryOptions {
+            path: dir.join("memory.json"),
+            source_locale: "en".to_string(),
+            targets: vec![("ko".to_string(), ko.clone())],
+            threshold: DEFAULT_THRESHOLD,
+            dry_run: true,
+        };
+        let new_keys = vec![("new".to_string(), "Save".to_string())];
+
+        // 검토 전 기계 번역은 배우지도 재사용하지도 않음, dry-run이면 메모리 파일도 없음
+        let reuse = update_and_reuse(&options, &en, &new_keys).unwrap();
+        assert!(reuse.reused.is_empty() && reuse.changes.is_empty());
+        assert!(!options.path.exists());
+
+        // 검토가 끝나면 재사용
+        fs::write(extract::meta_path(&ko), "{\n  \"old\": { \"needsReview\": false }\n}\n").unwrap();
+        options.dry_run = false;
+        let reuse = update_and_reuse(&options, &en, &new_keys).unwrap();
+        assert_eq!(reuse.reused, vec![("ko".to_string(), "new".to_string())]);
+        assert!(reuse.changes[0].updated.contains("\"new\": \"절약\""));
+        assert_eq!(Memory::load(&options.path, "en").unwrap().exact("Save", "ko"), Some("절약"));
+
+        // 깨진 대상 카탈로그
+        fs::write(&ko, "{").unwrap();
+        let err = update_and_reuse(&options, &en, &new_keys).err().unwrap();
+        assert_eq!(err.code, diagnostic::CATALOG_ERROR);
+    }
+
+    #[test]
+    fn test_incompatible_memory_is_reported_and_kept() {
+        let dir = TempDir::new("memory-incompatible");
+        let en = dir.write("en.json", "{\n  \"a\": \"Save\"\n}\n");
+        let ko = dir.write("ko.json", "{\n  \"a\": \"저장\"\n}\n");
+        let options = MemoryOptions {
+            path: dir.join("memory.json"),
+            source_locale: "en".to_string(),
+            targets: vec![("ko".to_string(), ko)],
+            threshold: DEFAULT_THRESHOLD,
+            dry_run: false,
+        };
+        for content in [
+            "{ \"version\": 1, ",
+            "{ \"version\": 2, \"sourceLocale\": \"en\", \"entries\": [] }",
+            "{ \"version\": 1, \"sourceLocale\": \"de\", \"entries\": [] }",
+        ] {
+            fs::write(&options.path, content).unwrap();
+            let err = update_and_reuse(&options, &en, &[]).err().unwrap();
+            assert_eq!(err.code, diagnostic::CATALOG_ERROR, "{}", content);
+            assert_eq!(fs::read_to_string(&options.path).unwrap(), content);
+        }
+    }
+}
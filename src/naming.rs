@@ -0,0 +1,312 @@
+// ============================================
+// i18n-wrapper: 키 이름 규칙 (key naming convention)
+// `Title`, `page_title`, `page.Title` 처럼 섞인 키를 잡아냄
+// 키를 "."으로 나눈 세그먼트마다 검사:
+//   case      -> snake | camel | kebab | pascal | constant
+//   segments  -> 세그먼트 정규식 (i번째 세그먼트에 i번째 패턴, 남는 세그먼트는 마지막 패턴)
+//   max_depth -> 세그먼트 개수 상한
+//   reserved  -> 세그먼트로 쓸 수 없는 단어 (i18next의 "defaultValue", 객체 속성 "constructor" 등)
+// regex_examples::compile_time_regex의 CONSTANT_NAME_RE처럼 패턴은 설정을 읽을 때 한 번만 컴파일
+// TypeScript:
+// key.split(".").every((s) => /^[a-z][a-z0-9_]*$/.test(s))
+//
+// i18n-wrapper.toml:
+//   [key_naming]
+//   case = "snake"
+//   segments = ['^(common|errors|[a-z]+_page)$', '^[a-z][a-z0-9_]*$']
+//   max_depth = 3
+//   reserved = ["default", "constructor"]
+// ============================================
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaseStyle {
+    // page_title
+    Snake,
+    // pageTitle
+    Camel,
+    // page-title
+    Kebab,
+    // PageTitle
+    Pascal,
+    // PAGE_TITLE (regex_examples의 상수 이름 규칙)
+    Constant,
+}
+
+impl CaseStyle {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "snake" => Some(CaseStyle::Snake),
+            "camel" => Some(CaseStyle::Camel),
+            "kebab" => Some(CaseStyle::Kebab),
+            "pascal" => Some(CaseStyle::Pascal),
+            "constant" => Some(CaseStyle::Constant),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CaseStyle::Snake => "snake",
+            CaseStyle::Camel => "camel",
+            CaseStyle::Kebab => "kebab",
+            CaseStyle::Pascal => "pascal",
+            CaseStyle::Constant => "constant",
+        }
+    }
+
+    // 단어 목록 -> 세그먼트
+    fn join(&self, words: &[String]) -> String {
+        let capitalize = |word: &String| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        };
+        match self {
+            CaseStyle::Snake => words.join("_"),
+            CaseStyle::Kebab => words.join("-"),
+            CaseStyle::Constant => words.join("_").to_uppercase(),
+            CaseStyle::Pascal => words.iter().map(capitalize).collect(),
+            CaseStyle::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| if i == 0 { word.clone() } else { capitalize(word) })
+                .collect(),
+        }
+    }
+
+    // 다시 변환해도 그대로면 이 형식을 따르는 것
+    fn matches(&self, segment: &str) -> bool {
+        self.join(&words(segment)) == segment
+    }
+}
+
+// "pageTitle", "Page_title", "page-title" -> ["page", "title"]
+// 소문자/숫자 뒤의 대문자에서 나누고, 연속 대문자(URLPath)는 마지막 대문자 앞에서 나눔
+fn words(segment: &str) -> Vec<String> {
+    let chars: Vec<char> = segment.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        let previous = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1);
+        let boundary = c.is_uppercase()
+            && match previous {
+                Some(p) if p.is_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_uppercase() => next.map(|n| n.is_lowercase()).unwrap_or(false),
+                _ => false,
+            };
+        if boundary && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyNaming {
+    // None이면 형식 검사 안 함
+    pub case: Option<CaseStyle>,
+    // 설정에 적힌 원래 패턴 (메시지용)
+    pub segments: Vec<String>,
+    #[cfg(feature = "regex")]
+    patterns: Vec<Regex>,
+    pub max_depth: Option<usize>,
+    pub reserved: Vec<String>,
+}
+
+// process가 만드는 키(transform::make_key)가 "component_name.slug_text" 형태라서 snake가 기본
+impl Default for KeyNaming {
+    fn default() -> Self {
+        Self {
+            case: Some(CaseStyle::Snake),
+            segments: Vec::new(),
+            #[cfg(feature = "regex")]
+            patterns: Vec::new(),
+            max_depth: None,
+            reserved: Vec::new(),
+        }
+    }
+}
+
+impl KeyNaming {
+    // 패턴은 세그먼트 전체와 맞아야 하므로 ^...$ 로 감싸서 컴파일
+    #[cfg(feature = "regex")]
+    pub fn set_segments(&mut self, segments: Vec<String>) -> Result<(), String> {
+        let mut patterns = Vec::new();
+        for segment in &segments {
+            let pattern = Regex::new(&format!("^(?:{})$", segment))
+                .map_err(|e| format!("잘못된 세그먼트 정규식 {:?}: {}", segment, e))?;
+            patterns.push(pattern);
+        }
+        self.segments = segments;
+        self.patterns = patterns;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "regex"))]
+    pub fn set_segments(&mut self, segments: Vec<String>) -> Result<(), String> {
+        if segments.is_empty() {
+            return Ok(());
+        }
+        Err("segments를 쓰려면 regex 기능을 켜고 빌드해야 합니다".to_string())
+    }
+
+    #[cfg(feature = "regex")]
+    fn segment_matches(&self, index: usize, segment: &str) -> Option<&str> {
+        let last = self.patterns.len().checked_sub(1)?;
+        let pattern = &self.patterns[index.min(last)];
+        (!pattern.is_match(segment)).then(|| self.segments[index.min(last)].as_str())
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn segment_matches(&self, _index: usize, _segment: &str) -> Option<&str> {
+        None
+    }
+
+    // 규칙 위반 설명 목록 (비어 있으면 통과)
+    pub fn check(&self, key: &str) -> Vec<String> {
+        let segments: Vec<&str> = key.split('.').collect();
+        let mut problems = Vec::new();
+        if let Some(max) = self.max_depth {
+            if segments.len() > max {
+                problems.push(format!("세그먼트가 {}개입니다 (최대 {}개)", segments.len(), max));
+            }
+        }
+        for (index, segment) in segments.iter().enumerate() {
+            if segment.is_empty() {
+                problems.push("빈 세그먼트가 있습니다".to_string());
+                continue;
+            }
+            if let Some(case) = self.case {
+                if !case.matches(segment) {
+                    problems.push(format!("`{}`가 {} 형식이 아닙니다", segment, case.name()));
+                }
+            }
+            if let Some(pattern) = self.segment_matches(index, segment) {
+                problems.push(format!("`{}`가 패턴 {}와 맞지 않습니다", segment, pattern));
+            }
+            if self.is_reserved(segment) {
+                problems.push(format!("`{}`는 예약어입니다", segment));
+            }
+        }
+        problems
+    }
+
+    fn is_reserved(&self, segment: &str) -> bool {
+        self.reserved.iter().any(|word| word.eq_ignore_ascii_case(segment))
+    }
+
+    // 자동 수정 이름: 형식 변환 -> 예약어에 "_key" 덧붙임 -> 깊이를 넘는 세그먼트는 마지막 세그먼트로 합침
+    // 고친 이름도 규칙(세그먼트 정규식 등)을 통과하지 못하면 None
+    pub fn suggest(&self, key: &str) -> Option<String> {
+        let case = self.case.unwrap_or(CaseStyle::Snake);
+        let mut segments: Vec<Vec<String>> = key.split('.').map(words).filter(|w| !w.is_empty()).collect();
+        if let Some(max) = self.max_depth.filter(|max| *max > 0) {
+            if segments.len() > max {
+                let merged: Vec<String> = segments.drain(max - 1..).flatten().collect();
+                segments.push(merged);
+            }
+        }
+        let mut renamed = Vec::new();
+        for mut segment_words in segments {
+            if self.is_reserved(&case.join(&segment_words)) {
+                segment_words.push("key".to_string());
+            }
+            renamed.push(case.join(&segment_words));
+        }
+        let renamed = renamed.join(".");
+        (renamed != key && !renamed.is_empty() && self.check(&renamed).is_empty()).then_some(renamed)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamingIssue {
+    pub key: String,
+    pub problems: Vec<String>,
+    pub suggestion: Option<String>,
+}
+
+pub fn issues<'a>(naming: &KeyNaming, keys: impl IntoIterator<Item = &'a str>) -> Vec<NamingIssue> {
+    keys.into_iter()
+        .filter_map(|key| {
+            let problems = naming.check(key);
+            (!problems.is_empty()).then(|| NamingIssue {
+                key: key.to_string(),
+                problems,
+                suggestion: naming.suggest(key),
+            })
+        })
+        .collect()
+}
+
+// extract/process 요약에 붙이는 출력 (규칙은 경고일 뿐이라 종료 코드는 그대로)
+pub fn print_issues(issues: &[NamingIssue]) {
+    if issues.is_empty() {
+        return;
+    }
+    println!("  키 이름 규칙 위반 {}개:", issues.len());
+    for issue in issues {
+        println!("    ! {}: {}", issue.key, issue.problems.join(", "));
+        if let Some(suggestion) = &issue.suggestion {
+            println!("      -> i18n-wrapper rename-key {} {}", issue.key, suggestion);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_naming_check_and_suggest() {
+        let mut naming = KeyNaming {
+            max_depth: Some(3),
+            reserved: vec!["default".to_string()],
+            ..Default::default()
+        };
+        assert!(naming.check("page.title").is_empty());
+        assert_eq!(naming.suggest("Title"), Some("title".to_string()));
+        assert_eq!(naming.suggest("page.Title"), Some("page.title".to_string()));
+        assert_eq!(naming.suggest("pageTitle.URLPath"), Some("page_title.url_path".to_string()));
+        assert_eq!(naming.suggest("a.b.c.dEf"), Some("a.b.c_d_ef".to_string()));
+        assert_eq!(naming.suggest("errors.default"), Some("errors.default_key".to_string()));
+        assert_eq!(naming.check("a..b"), vec!["빈 세그먼트가 있습니다"]);
+
+        naming.case = Some(CaseStyle::Camel);
+        assert!(naming.check("page.pageTitle").is_empty());
+        assert_eq!(naming.suggest("page.page_title"), Some("page.pageTitle".to_string()));
+
+        // 상수 이름 규칙 (regex_examples::is_valid_constant_name)과 같은 결과
+        naming.case = Some(CaseStyle::Constant);
+        assert!(naming.check("ERRORS.MY_CONSTANT").is_empty());
+        assert!(!naming.check("ERRORS.Constant").is_empty());
+
+        #[cfg(feature = "regex")]
+        {
+            naming.case = Some(CaseStyle::Snake);
+            naming
+                .set_segments(vec!["common|errors".to_string(), "[a-z][a-z0-9_]*".to_string()])
+                .unwrap();
+            assert!(naming.check("errors.not_found").is_empty());
+            assert_eq!(naming.check("page.title").len(), 1);
+            // 첫 세그먼트는 어떻게 고쳐도 패턴에 맞지 않음
+            assert_eq!(naming.suggest("Page.title"), None);
+            assert!(naming.set_segments(vec!["(".to_string()]).is_err());
+        }
+    }
+}
@@ -0,0 +1,554 @@
+// ============================================
+// i18n-wrapper: 병렬 파일 파이프라인 (--jobs N)
+// async_examples.rs의 process_files_example은 파일마다 태스크를 무제한으로 만들고
+// 에러를 버림 (Err(_) => None). 여기서는:
+// - Semaphore로 동시에 처리하는 파일 수를 제한
+// - 파싱/변환(CPU 작업)은 spawn_blocking으로 런타임 밖에서 실행
+// - 결과는 입력 순서대로, 에러는 모두 리포트에 수집
+// - 키 할당은 파일마다 원래 카탈로그 기준으로 병렬 계산한 뒤 입력 순서대로 합침 (process::plan_prepared)
+// - 파일별 제한 시간과 Ctrl-C 취소를 select_example처럼 tokio::select!로 처리
+// TypeScript:
+// const limit = pLimit(jobs);
+// await Promise.all(files.map((f) => limit(() => processFile(f))));
+// ============================================
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{watch, Semaphore};
+use tokio::task::JoinSet;
+
+use crate::cache::Cache;
+use crate::diagnostic::{self, Diagnostic};
+use crate::progress::{self, ProgressEvent, ProgressSender};
+use crate::catalog::Catalog;
+use crate::process::{self, Analyzed, FileChange, PreparedFile, ProcessOptions, ProcessReport};
+use crate::transform::KeyStrategy;
+
+const CANCELLED: &str = "취소됨";
+const ABORTED: &str = "작업이 중단되었습니다";
+
+// ============================================
+// 취소 토큰: watch 채널로 "취소됨" 상태를 모든 단계에 전달
+// ============================================
+pub struct CancelHandle(watch::Sender<bool>);
+
+#[derive(Clone)]
+pub struct CancelToken(watch::Receiver<bool>);
+
+pub fn cancel_pair() -> (CancelHandle, CancelToken) {
+    let (tx, rx) = watch::channel(false);
+    (CancelHandle(tx), CancelToken(rx))
+}
+
+impl CancelHandle {
+    pub fn cancel(&self) {
+        let _ = self.0.send(true);
+    }
+}
+
+impl CancelToken {
+    pub fn is_cancelled(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    // 취소될 때까지 대기 (핸들이 취소 없이 사라지면 영원히 대기)
+    pub async fn cancelled(&self) {
+        let mut rx = self.0.clone();
+        if rx.wait_for(|cancelled| *cancelled).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+// Ctrl-C가 들어오면 취소, 두 번째 Ctrl-C는 기본 동작처럼 바로 종료
+// (tokio는 한 번 등록한 SIGINT 핸들러를 되돌릴 수 없으므로 128 + SIGINT(2)로 직접 종료)
+pub const EXIT_INTERRUPTED: i32 = 130;
+
+pub fn cancel_on_ctrl_c(handle: CancelHandle) {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        handle.cancel();
+        if tokio::signal::ctrl_c().await.is_ok() {
+            eprintln!();
+            std::process::exit(EXIT_INTERRUPTED);
+        }
+    });
+}
+
+// 동기 코드(CLI)에서 호출: jobs개의 워커 스레드로 런타임을 만들어
+// 분석 → 키 할당 → (dry-run이 아니면) 저장까지 실행. 진행 상황은 stderr에 표시
+pub fn run_blocking(options: &ProcessOptions) -> ProcessReport {
+    let runtime = match runtime(options.jobs) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            let mut report = process::plan(options);
+            if !options.dry_run {
+                let failed = process::write_all(&report.changes);
+                report.errors.extend(failed);
+            }
+            report.errors.push(Diagnostic::error(diagnostic::IO_ERROR, e.to_string(), Path::new("<runtime>")));
+            return report;
+        }
+    };
+
+    let report = runtime.block_on(async {
+        let (handle, cancel) = cancel_pair();
+        // 대화형 모드는 q로 끝낼 수 있으므로 Ctrl-C는 기본 동작(즉시 종료)에 맡김
+        if !options.interactive {
+            cancel_on_ctrl_c(handle);
+        }
+
+        let (tx, rx) = progress::channel();
+        let renderer = tokio::spawn(progress::render(rx));
+        let analyzed = analyze_all(
+            &options.files,
+            options.jobs,
+            options.cache.clone(),
+            Some(tx),
+            options.file_timeout,
+            cancel.clone(),
+        )
+        .await;
+        // 대화형 검토 프롬프트와 진행 표시가 섞이지 않도록 먼저 끝냄
+        let _ = renderer.await;
+        if cancel.is_cancelled() {
+            return cancelled_report(options.files.clone());
+        }
+
+        let mut report = plan_all(options, analyzed, &cancel).await;
+        if report.cancelled || options.dry_run {
+            return report;
+        }
+        if cancel.is_cancelled() {
+            let skipped = report.changes.iter().map(|c| c.path.clone()).collect();
+            return ProcessReport { errors: report.errors, ..cancelled_report(skipped) };
+        }
+
+        let (tx, rx) = progress::channel();
+        let renderer = tokio::spawn(progress::render(rx));
+        let outcome = write_all(&report.changes, options.jobs, Some(tx), cancel).await;
+        let _ = renderer.await;
+        report.errors.extend(outcome.failed);
+        if !outcome.skipped.is_empty() {
+            report.cancelled = true;
+            report.skipped = outcome.skipped;
+        }
+        report
+    });
+    // 취소나 시간 초과로 버린 spawn_blocking 작업과 시그널 태스크를 기다리지 않고 종료
+    runtime.shutdown_background();
+    report
+}
+
+fn cancelled_report(skipped: Vec<PathBuf>) -> ProcessReport {
+    ProcessReport {
+        cancelled: true,
+        skipped,
+        ..Default::default()
+    }
+}
+
+fn runtime(jobs: usize) -> std::io::Result<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(jobs.max(1))
+        .enable_all()
+        .build()
+}
+
+// 대화형 검토는 stdin을 쓰므로 순차 처리, 아니면 파일별 키 할당/치환을 병렬로 계산한 뒤 합침
+// 취소되면 합치지 않고 취소 리포트 (카탈로그도 건드리지 않음)
+async fn plan_all(options: &ProcessOptions, analyzed: Vec<Analyzed>, cancel: &CancelToken) -> ProcessReport {
+    if options.interactive {
+        return tokio::task::block_in_place(|| process::plan_analyzed(options, analyzed));
+    }
+    let catalog = match process::load_catalog(options) {
+        Ok(catalog) => catalog,
+        Err(diagnostic) => {
+            return ProcessReport {
+                errors: vec![*diagnostic],
+                ..Default::default()
+            }
+        }
+    };
+    let catalog = Arc::new(catalog);
+    let prepared = prepare_all(analyzed, catalog.clone(), options.key_strategy, options.jobs, cancel.clone()).await;
+    if cancel.is_cancelled() {
+        return cancelled_report(options.files.clone());
+    }
+    let catalog = Arc::try_unwrap(catalog).unwrap_or_else(|shared| (*shared).clone());
+    // 합치기는 파일 순서에 따라 결과가 달라지므로 순차 처리 (충돌한 파일만 다시 계산)
+    tokio::task::block_in_place(|| process::plan_prepared(options, catalog, prepared))
+}
+
+// ============================================
+// 키 할당 + 치환: 최대 jobs개 동시 실행, 결과는 입력 순서
+// 파일마다 같은 원래 카탈로그의 사본을 기준으로 계산
+// 취소되면 아직 시작하지 않은 파일은 건너뜀
+// ============================================
+pub async fn prepare_all(
+    analyzed: Vec<Analyzed>,
+    catalog: Arc<Catalog>,
+    strategy: KeyStrategy,
+    jobs: usize,
+    cancel: CancelToken,
+) -> Vec<PreparedFile> {
+    let semaphore = Arc::new(Semaphore::new(jobs.max(1)));
+    let mut tasks = JoinSet::new();
+    let paths: Vec<PathBuf> = analyzed.iter().map(|(path, _)| path.clone()).collect();
+
+    for (index, (path, result)) in analyzed.into_iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let catalog = catalog.clone();
+        let cancel = cancel.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+            if cancel.is_cancelled() {
+                return (index, Err(Box::new(Diagnostic::error(diagnostic::IO_ERROR, CANCELLED, &path))));
+            }
+            let (code, analysis) = match result {
+                Ok(analyzed) => analyzed,
+                Err(diagnostic) => return (index, Err(diagnostic)),
+            };
+            let prepared =
+                tokio::task::spawn_blocking(move || process::prepare(code, analysis, strategy, &catalog)).await;
+            let prepared = prepared.map_err(|e| {
+                let message = format!("변환 작업 실패: {}", e);
+                Box::new(Diagnostic::error(diagnostic::IO_ERROR, message, &path))
+            });
+            (index, prepared)
+        });
+    }
+
+    let mut results: Vec<Option<Result<_, Box<Diagnostic>>>> = paths.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, result)) = joined {
+            results[index] = Some(result);
+        }
+    }
+    paths
+        .into_iter()
+        .zip(results)
+        .map(|(path, result)| {
+            let result = result.unwrap_or_else(|| Err(Box::new(Diagnostic::error(diagnostic::IO_ERROR, ABORTED, &path))));
+            (path, result)
+        })
+        .collect()
+}
+
+// ============================================
+// 읽기 + 파싱: 최대 jobs개 동시 실행, 결과는 입력 순서
+// 파일마다 timeout이 지나거나 취소되면 기다리지 않고 에러로 기록
+// (spawn_blocking 작업 자체는 멈출 수 없으므로 결과만 버림)
+// ============================================
+pub async fn analyze_all(
+    files: &[PathBuf],
+    jobs: usize,
+    cache: Option<Cache>,
+    progress: Option<ProgressSender>,
+    timeout: Duration,
+    cancel: CancelToken,
+) -> Vec<Analyzed> {
+    let semaphore = Arc::new(Semaphore::new(jobs.max(1)));
+    let mut tasks = JoinSet::new();
+    let total = files.len();
+
+    for (index, path) in files.iter().cloned().enumerate() {
+        let semaphore = semaphore.clone();
+        let cache = cache.clone();
+        let progress = progress.clone();
+        let cancel = cancel.clone();
+        tasks.spawn(async move {
+            // 세마포어는 닫지 않으므로 실패하지 않음
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+            let cancelled = || Box::new(Diagnostic::error(diagnostic::IO_ERROR, CANCELLED, &path));
+            if cancel.is_cancelled() {
+                return (index, Err(cancelled()));
+            }
+            progress::emit(&progress, ProgressEvent::FileStarted { path: path.clone(), index, total }).await;
+
+            let work = async {
+                let code = tokio::fs::read_to_string(&path)
+                    .await
+                    .map_err(|e| Box::new(Diagnostic::error(diagnostic::IO_ERROR, e.to_string(), &path)))?;
+                let blocking_path = path.clone();
+                tokio::task::spawn_blocking(move || process::analyze_file(&blocking_path, code, cache.as_ref()))
+                    .await
+                    .unwrap_or_else(|e| {
+                        let message = format!("분석 작업 실패: {}", e);
+                        Err(Box::new(Diagnostic::error(diagnostic::IO_ERROR, message, &path)))
+                    })
+            };
+            let result = tokio::select! {
+                result = work => result,
+                _ = tokio::time::sleep(timeout) => Err(Box::new(
+                    Diagnostic::error(diagnostic::TIMEOUT, format!("시간 초과 ({}초)", timeout.as_secs()), &path)
+                        .with_help("--file-timeout 으로 제한 시간을 늘리거나 .i18nignore 에 추가하세요"),
+                )),
+                _ = cancel.cancelled() => return (index, Err(cancelled())),
+            };
+
+            match &result {
+                Ok((_, analysis)) => {
+                    progress::emit(&progress, ProgressEvent::FileParsed { path: path.clone() }).await;
+                    let count = analysis.strings.iter().filter(|s| !s.ignored).count();
+                    progress::emit(&progress, ProgressEvent::StringsFound { path, count }).await;
+                }
+                Err(diagnostic) => {
+                    let message = diagnostic.message.clone();
+                    progress::emit(&progress, ProgressEvent::FileFailed { path, message }).await;
+                }
+            }
+            (index, result)
+        });
+    }
+
+    let mut results: Vec<Option<Result<_, Box<Diagnostic>>>> = files.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, result)) = joined {
+            results[index] = Some(result);
+        }
+    }
+    files
+        .iter()
+        .cloned()
+        .zip(results)
+        .map(|(path, result)| {
+            let result = result.unwrap_or_else(|| Err(Box::new(Diagnostic::error(diagnostic::IO_ERROR, ABORTED, &path))));
+            (path, result)
+        })
+        .collect()
+}
+
+// ============================================
+// 쓰기: 카탈로그 먼저, 그 다음 소스 파일을 최대 jobs개 동시에
+// 취소되면 새 쓰기는 시작하지 않고, 이미 시작한 쓰기는 끝까지 (임시 파일 + rename)
+// ============================================
+#[derive(Default)]
+pub struct WriteOutcome {
+    pub failed: Vec<Diagnostic>,
+    pub skipped: Vec<PathBuf>,
+}
+
+enum WriteResult {
+    Written,
+    Failed(String),
+    Skipped,
+}
+
+pub async fn write_all(
+    changes: &[FileChange],
+    jobs: usize,
+    progress: Option<ProgressSender>,
+    cancel: CancelToken,
+) -> WriteOutcome {
+    let (catalogs, sources): (Vec<usize>, Vec<usize>) =
+        process::write_order(changes).into_iter().partition(|&i| changes[i].wraps.is_empty());
+
+    let mut results = Vec::new();
+    for group in [catalogs, sources] {
+        results.extend(write_group(changes, &group, jobs, &progress, &cancel).await);
+    }
+    results.sort_by_key(|(index, _)| *index);
+
+    let mut outcome = WriteOutcome::default();
+    for (index, result) in results {
+        let path = changes[index].path.clone();
+        match result {
+            WriteResult::Written => {}
+            WriteResult::Failed(message) => {
+                outcome.failed.push(Diagnostic::error(diagnostic::WRITE_ERROR, message, &path))
+            }
+            WriteResult::Skipped => outcome.skipped.push(path),
+        }
+    }
+    outcome
+}
+
+async fn write_group(
+    changes: &[FileChange],
+    indices: &[usize],
+    jobs: usize,
+    progress: &Option<ProgressSender>,
+    cancel: &CancelToken,
+) -> Vec<(usize, WriteResult)> {
+    let semaphore = Arc::new(Semaphore::new(jobs.max(1)));
+    let mut tasks = JoinSet::new();
+    let total = changes.len();
+
+    for &index in indices {
+        let semaphore = semaphore.clone();
+        let path = changes[index].path.clone();
+        let content = changes[index].updated.clone();
+        let progress = progress.clone();
+        let cancel = cancel.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+            if cancel.is_cancelled() {
+                return (index, WriteResult::Skipped);
+            }
+            progress::emit(&progress, ProgressEvent::WriteStarted { path: path.clone(), index, total }).await;
+            let result = write_file(&path, content).await;
+            let event = match &result {
+                Ok(()) => ProgressEvent::FileWritten { path },
+                Err(message) => ProgressEvent::FileFailed { path, message: message.clone() },
+            };
+            progress::emit(&progress, event).await;
+            (index, result.map(|_| WriteResult::Written).unwrap_or_else(WriteResult::Failed))
+        });
+    }
+
+    let mut results = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok(result) = joined {
+            results.push(result);
+        }
+    }
+    for &index in indices {
+        if !results.iter().any(|(i, _)| *i == index) {
+            results.push((index, WriteResult::Failed(ABORTED.to_string())));
+        }
+    }
+    results
+}
+
+// process::write_file의 async 버전 (임시 파일 + rename)
+async fn write_file(path: &Path, content: String) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent).await.map_err(|e| e.to_string())?;
+    }
+    let temp = process::temp_path(path);
+    tokio::fs::write(&temp, content).await.map_err(|e| e.to_string())?;
+    if let Err(e) = tokio::fs::rename(&temp, path).await {
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(e.to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+    use crate::transform::{self, Wrap};
+
+    #[test]
+    fn test_analyze_keeps_order_and_errors() {
+        let dir = TempDir::new("pipeline");
+        let mut files = Vec::new();
+        for i in 0..8 {
+            let path = dir.join(format!("C{}.jsx", i));
+            std::fs::write(&path, format!("const C{} = () => <p>Text {}</p>;", i, i)).unwrap();
+            files.push(path);
+        }
+        files.insert(3, dir.join("missing.jsx"));
+
+        let runtime = runtime(2).unwrap();
+        let (_handle, cancel) = cancel_pair();
+        let analyzed = runtime.block_on(analyze_all(&files, 2, None, None, Duration::from_secs(30), cancel));
+        let paths: Vec<&PathBuf> = analyzed.iter().map(|(p, _)| p).collect();
+        assert_eq!(paths, files.iter().collect::<Vec<_>>());
+        assert!(analyzed[3].1.is_err());
+        assert_eq!(analyzed.iter().filter(|(_, r)| r.is_ok()).count(), 8);
+    }
+
+    #[test]
+    fn test_parallel_plan_matches_sequential_keys() {
+        let dir = TempDir::new("plan");
+        // 같은 컴포넌트 이름 -> 같은 네임스페이스, 비슷한 문구 -> 같은 slug 키를 두고 경쟁
+        let texts = ["Hello world", "Hello, world!", "Hello world", "Bye"];
+        let files: Vec<PathBuf> = texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| dir.write(&format!("{}/Card.jsx", i), &format!("const Card = () => <p>{}</p>;", text)))
+            .collect();
+        let options = ProcessOptions {
+            files: files.clone(),
+            catalog_path: dir.join("en.json"),
+            jobs: 4,
+            ..Default::default()
+        };
+
+        let runtime = runtime(4).unwrap();
+        let (_handle, cancel) = cancel_pair();
+        let analyze = || analyze_all(&files, 4, None, None, Duration::from_secs(30), cancel.clone());
+        let report = runtime.block_on(async { plan_all(&options, analyze().await, &cancel).await });
+
+        // 한 파일씩 같은 카탈로그에 할당한 결과와 같아야 함
+        let mut catalog = Catalog::load(&options.catalog_path).unwrap();
+        let mut expected = Vec::new();
+        for (_, result) in runtime.block_on(analyze()) {
+            let (_, analysis) = result.unwrap();
+            expected.extend(transform::plan_wraps(&analysis, options.key_strategy, &mut catalog));
+        }
+        let keys = |wraps: Vec<&Wrap>| wraps.into_iter().map(|w| w.key.clone()).collect::<Vec<_>>();
+        let actual: Vec<_> = report.changes.iter().flat_map(|change| &change.wraps).collect();
+        assert_eq!(keys(actual), keys(expected.iter().collect()));
+        assert_eq!(report.changes.last().unwrap().updated, catalog.render());
+        assert!(report.errors.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_before_planning_leaves_catalog_alone() {
+        let dir = TempDir::new("cancel-plan");
+        let files = vec![dir.write("App.jsx", "const App = () => <p>Hello</p>;")];
+        let options = ProcessOptions {
+            files: files.clone(),
+            catalog_path: dir.join("en.json"),
+            ..Default::default()
+        };
+
+        let runtime = runtime(2).unwrap();
+        let (handle, cancel) = cancel_pair();
+        let analyzed = runtime.block_on(analyze_all(&files, 2, None, None, Duration::from_secs(30), cancel.clone()));
+        handle.cancel();
+        let report = runtime.block_on(plan_all(&options, analyzed, &cancel));
+        assert!(report.cancelled);
+        assert!(report.changes.is_empty());
+        assert_eq!(report.skipped, files);
+    }
+
+    #[test]
+    fn test_cancelled_write_leaves_files_untouched() {
+        let dir = TempDir::new("cancel");
+        let path = dir.join("App.jsx");
+        std::fs::write(&path, "before").unwrap();
+        let changes = vec![FileChange {
+            path: path.clone(),
+            original: "before".to_string(),
+            updated: "after".to_string(),
+            wraps: Vec::new(),
+        }];
+
+        let (handle, cancel) = cancel_pair();
+        handle.cancel();
+        let outcome = runtime(1).unwrap().block_on(write_all(&changes, 1, None, cancel));
+        assert_eq!(outcome.skipped, vec![path.clone()]);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "before");
+    }
+
+    #[test]
+    fn test_write_failure_is_reported_per_file() {
+        let dir = TempDir::new("write-fail");
+        let ok = dir.write("App.jsx", "before");
+        // 부모가 디렉토리가 아니라 파일이라 쓸 수 없음
+        let blocked = dir.write("file.txt", "").join("Card.jsx");
+        let change = |path: &PathBuf| FileChange {
+            path: path.clone(),
+            original: "before".to_string(),
+            updated: "after".to_string(),
+            wraps: Vec::new(),
+        };
+        let changes = vec![change(&blocked), change(&ok)];
+
+        let (_handle, cancel) = cancel_pair();
+        let outcome = runtime(2).unwrap().block_on(write_all(&changes, 2, None, cancel));
+        assert_eq!(outcome.failed.len(), 1);
+        assert_eq!(outcome.failed[0].path, blocked);
+        assert_eq!(outcome.failed[0].code, diagnostic::WRITE_ERROR);
+        assert_eq!(std::fs::read_to_string(&ok).unwrap(), "after");
+    }
+}
@@ -0,0 +1,503 @@
+// ============================================
+// i18n-wrapper: process 명령 (분석 → 키 할당 → 치환 → 저장)
+// TypeScript:
+// public async processFiles(): Promise<{ processedFiles: string[] }> {
+//   for (const filePath of filePaths) {
+//     const code = fs.readFileSync(filePath, "utf-8");
+//     const output = transform(code);
+//     if (!dryRun) fs.writeFileSync(filePath, output.code, "utf-8");
+//   }
+// }
+// ============================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cache::Cache;
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+use crate::diff::{self, DiffFormat};
+use crate::framework::Framework;
+use crate::json::Value;
+use crate::naming::{self, KeyNaming, NamingIssue};
+use crate::report::{self, ReportFormat};
+use crate::review::{self, Decisions};
+use crate::transform::{self, FileAnalysis, KeyStrategy, Wrap};
+
+pub const DEFAULT_FILE_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct ProcessOptions {
+    pub files: Vec<PathBuf>,
+    pub dry_run: bool,
+    // dry-run diff의 앞뒤 문맥 줄 수
+    pub context: usize,
+    pub diff_format: DiffFormat,
+    // 결과 출력 형식 (json이면 요약/미리보기 대신 report::to_json)
+    pub format: ReportFormat,
+    pub catalog_path: PathBuf,
+    pub key_strategy: KeyStrategy,
+    // 참조를 찾을 호출/컴포넌트 (extract 등), process는 i18next 계열만 지원
+    pub framework: Framework,
+    // 변경마다 수락/건너뛰기/키 수정/무시를 묻기
+    pub interactive: bool,
+    // 대화형 검토 결정 파일 (CLI는 프로젝트 루트 기준 review::DECISIONS_PATH)
+    pub decisions_path: PathBuf,
+    // 동시에 처리할 파일 수 (1이면 순차 처리)
+    pub jobs: usize,
+    // None이면 매번 다시 파싱
+    pub cache: Option<Cache>,
+    // 파일 하나의 읽기+파싱 제한 시간 (거대한 번들 파일 등)
+    pub file_timeout: Duration,
+    pub verbose: bool,
+    // 새로 만든 키에 적용할 이름 규칙 (요약에 경고로만 표시)
+    pub key_naming: KeyNaming,
+}
+
+impl Default for ProcessOptions {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            dry_run: false,
+            context: 3,
+            diff_format: DiffFormat::Text,
+            format: ReportFormat::Text,
+            catalog_path: PathBuf::from("locales/en.json"),
+            key_strategy: KeyStrategy::Slug,
+            framework: Framework::default(),
+            interactive: false,
+            decisions_path: PathBuf::from(review::DECISIONS_PATH),
+            jobs: default_jobs(),
+            cache: None,
+            file_timeout: DEFAULT_FILE_TIMEOUT,
+            verbose: false,
+            key_naming: KeyNaming::default(),
+        }
+    }
+}
+
+pub struct FileChange {
+    pub path: PathBuf,
+    pub original: String,
+    pub updated: String,
+    // 카탈로그 파일은 비어 있음
+    pub wraps: Vec<Wrap>,
+}
+
+#[derive(Default)]
+pub struct ProcessReport {
+    pub changes: Vec<FileChange>,
+    pub errors: Vec<Diagnostic>,
+    // 분석에 성공한 파일 (입력 순서, 리포트용)
+    pub analyses: Vec<FileAnalysis>,
+    pub new_keys: usize,
+    // 새 키 중 이름 규칙에 맞지 않는 것
+    pub naming: Vec<NamingIssue>,
+    // Ctrl-C로 중단됨. skipped는 손대지 않은 파일
+    pub cancelled: bool,
+    pub skipped: Vec<PathBuf>,
+}
+
+pub fn default_jobs() -> usize {
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+// 분석 단계의 파일별 결과 (파일 순서 유지)
+// Diagnostic은 크기가 커서 Result 안에서는 Box로 감쌈 (clippy::result_large_err)
+pub type Analyzed = (PathBuf, Result<(String, FileAnalysis), Box<Diagnostic>>);
+
+impl ProcessReport {
+    pub fn wrapped_strings(&self) -> usize {
+        self.changes.iter().map(|c| c.wraps.len()).sum()
+    }
+}
+
+// ============================================
+// 파일 읽기 + 파싱 (순차). 병렬 버전은 pipeline::analyze_all
+// ============================================
+#[cfg(feature = "swc")]
+pub fn analyze_file(path: &Path, code: String, cache: Option<&Cache>) -> Result<(String, FileAnalysis), Box<Diagnostic>> {
+    if let Some(analysis) = cache.and_then(|cache| cache.lookup(path, &code)) {
+        return Ok((code, analysis));
+    }
+    let analysis = transform::analyze_source(&path.to_string_lossy(), &code).map_err(|e| Box::new(e.to_diagnostic(&code)))?;
+    if let Some(cache) = cache {
+        // 캐시 저장 실패는 치명적이지 않음 (다음 실행에서 다시 파싱)
+        let _ = cache.store(path, &code, &analysis);
+    }
+    Ok((code, analysis))
+}
+
+#[cfg(feature = "swc")]
+pub fn analyze_all(files: &[PathBuf], cache: Option<&Cache>) -> Vec<Analyzed> {
+    files
+        .iter()
+        .map(|path| {
+            let result = fs::read_to_string(path)
+                .map_err(|e| Box::new(Diagnostic::error(diagnostic::IO_ERROR, e.to_string(), path)))
+                .and_then(|code| analyze_file(path, code, cache));
+            (path.clone(), result)
+        })
+        .collect()
+}
+
+// ============================================
+// 전체 변환을 메모리에서 계산 (디스크에는 쓰지 않음)
+// ============================================
+#[cfg(feature = "swc")]
+pub fn plan(options: &ProcessOptions) -> ProcessReport {
+    plan_analyzed(options, analyze_all(&options.files, options.cache.as_ref()))
+}
+
+// 키 할당과 대화형 검토는 결과가 결정적이도록 파일 순서대로 진행
+// 대화형이 아니면 파일마다 prepare로 계산한 뒤 plan_prepared로 합침 (pipeline은 prepare를 병렬로)
+pub fn plan_analyzed(options: &ProcessOptions, analyzed: Vec<Analyzed>) -> ProcessReport {
+    let catalog = match load_catalog(options) {
+        Ok(catalog) => catalog,
+        Err(diagnostic) => {
+            return ProcessReport {
+                errors: vec![*diagnostic],
+                ..Default::default()
+            }
+        }
+    };
+    if !options.interactive {
+        let prepared = analyzed
+            .into_iter()
+            .map(|(path, result)| {
+                let prepared = result.map(|(code, analysis)| prepare(code, analysis, options.key_strategy, &catalog));
+                (path, prepared)
+            })
+            .collect();
+        return plan_prepared(options, catalog, prepared);
+    }
+
+    let mut report = ProcessReport::default();
+    let mut catalog = catalog;
+    let catalog_before = catalog.render();
+    let keys_before = catalog.keys();
+    let mut decisions = Decisions::load(&options.decisions_path);
+
+    for (path, result) in analyzed {
+        let (code, analysis) = match result {
+            Ok(analyzed) => analyzed,
+            Err(diagnostic) => {
+                report.errors.push(*diagnostic);
+                continue;
+            }
+        };
+
+        // 제안은 카탈로그 사본으로 계산하고, 수락된 키만 review_file이 파일용 사본에 추가
+        // (검토가 중간에 실패하면 그 파일의 키는 버림)
+        let proposed = transform::plan_wraps(&analysis, options.key_strategy, &mut catalog.clone());
+        let mut file_catalog = catalog.clone();
+        let outcome = match review::review_file(
+            &mut io::stdin().lock(),
+            &mut io::stdout(),
+            &path.to_string_lossy(),
+            &code,
+            proposed,
+            &mut file_catalog,
+            &mut decisions,
+        ) {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                report.errors.push(Diagnostic::error(diagnostic::IO_ERROR, e.to_string(), &path));
+                break;
+            }
+        };
+        catalog = file_catalog;
+        let ignored: Vec<&Wrap> = outcome.ignored.iter().collect();
+        let updated = transform::apply_edits(&code, &analysis, &outcome.accepted, &ignored);
+        push_change(&mut report, &path, code, updated, outcome.accepted);
+        report.analyses.push(analysis);
+        if outcome.quit {
+            break;
+        }
+    }
+
+    if let Err(e) = decisions.save() {
+        report.errors.push(Diagnostic::error(diagnostic::WRITE_ERROR, e.to_string(), &options.decisions_path));
+    }
+    push_catalog_change(&mut report, options, &catalog, catalog_before, &keys_before);
+    report
+}
+
+pub fn load_catalog(options: &ProcessOptions) -> Result<Catalog, Box<Diagnostic>> {
+    Catalog::load_diagnostic(&options.catalog_path)
+}
+
+// 한 파일의 키 할당 + 치환을 카탈로그 사본 기준으로 계산 (파일끼리 독립적이라 병렬 실행 가능)
+pub struct Prepared {
+    pub code: String,
+    pub analysis: FileAnalysis,
+    pub wraps: Vec<Wrap>,
+    pub updated: String,
+}
+
+pub type PreparedFile = (PathBuf, Result<Prepared, Box<Diagnostic>>);
+
+pub fn prepare(code: String, analysis: FileAnalysis, strategy: KeyStrategy, catalog: &Catalog) -> Prepared {
+    let wraps = transform::plan_wraps(&analysis, strategy, &mut catalog.clone());
+    let updated = transform::apply_wraps(&code, &analysis, &wraps);
+    Prepared {
+        code,
+        analysis,
+        wraps,
+        updated,
+    }
+}
+
+// 미리 계산한 결과를 파일 순서대로 카탈로그에 합침
+// 앞 파일이 같은 키를 다른 문구로 먼저 가져갔으면 그 파일만 합친 카탈로그로 다시 계산
+// -> 파일 순서대로 하나씩 처리한 것과 같은 키가 나옴
+pub fn plan_prepared(options: &ProcessOptions, mut catalog: Catalog, prepared: Vec<PreparedFile>) -> ProcessReport {
+    let mut report = ProcessReport::default();
+    let catalog_before = catalog.render();
+    let keys_before = catalog.keys();
+
+    for (path, result) in prepared {
+        let prepared = match result {
+            Ok(prepared) => prepared,
+            Err(diagnostic) => {
+                report.errors.push(*diagnostic);
+                continue;
+            }
+        };
+        let Prepared {
+            code,
+            analysis,
+            mut wraps,
+            mut updated,
+        } = prepared;
+        if !fits(&catalog, &wraps) {
+            wraps = transform::plan_wraps(&analysis, options.key_strategy, &mut catalog);
+            updated = transform::apply_wraps(&code, &analysis, &wraps);
+        } else {
+            for wrap in &wraps {
+                // fits에서 확인했으므로 실패하지 않음
+                let _ = catalog.insert_default(&wrap.key, &wrap.text);
+            }
+        }
+        push_change(&mut report, &path, code, updated, wraps);
+        report.analyses.push(analysis);
+    }
+
+    push_catalog_change(&mut report, options, &catalog, catalog_before, &keys_before);
+    report
+}
+
+// 모든 키가 비어 있는 자리이거나 같은 문구로 이미 있으면 그대로 합칠 수 있음
+fn fits(catalog: &Catalog, wraps: &[Wrap]) -> bool {
+    wraps.iter().all(|wrap| match catalog.get(&wrap.key) {
+        Some(existing) => existing == wrap.text,
+        None => catalog.conflict(&wrap.key).is_none(),
+    })
+}
+
+fn push_catalog_change(
+    report: &mut ProcessReport,
+    options: &ProcessOptions,
+    catalog: &Catalog,
+    catalog_before: String,
+    keys_before: &[String],
+) {
+    let catalog_after = catalog.render();
+    if catalog_after == catalog_before {
+        return;
+    }
+    report.new_keys = catalog.len() - keys_before.len();
+    let keys = catalog.keys();
+    let new_keys = keys.iter().filter(|key| !keys_before.contains(key)).map(String::as_str);
+    report.naming = naming::issues(&options.key_naming, new_keys);
+    report.changes.push(FileChange {
+        path: options.catalog_path.clone(),
+        original: catalog_before,
+        updated: catalog_after,
+        wraps: Vec::new(),
+    });
+}
+
+// 내용이 실제로 바뀐 파일만 기록
+fn push_change(report: &mut ProcessReport, path: &Path, original: String, updated: String, wraps: Vec<Wrap>) {
+    if updated != original {
+        report.changes.push(FileChange {
+            path: path.to_path_buf(),
+            original,
+            updated,
+            wraps,
+        });
+    }
+}
+
+// 분석 → 키 할당 → (dry-run이 아니면) 저장. 출력은 하지 않음
+#[cfg(feature = "swc")]
+pub fn execute(options: &ProcessOptions) -> ProcessReport {
+    // async 기능이 있으면 파싱/쓰기를 --jobs 개씩 병렬로 (Ctrl-C로 취소 가능)
+    #[cfg(feature = "async")]
+    {
+        crate::pipeline::run_blocking(options)
+    }
+    #[cfg(not(feature = "async"))]
+    {
+        let mut report = plan(options);
+        if !options.dry_run {
+            let failed = write_all(&report.changes);
+            report.errors.extend(failed);
+        }
+        report
+    }
+}
+
+#[cfg(feature = "swc")]
+pub fn run_process(options: &ProcessOptions) -> ProcessReport {
+    let report = execute(options);
+    if options.format == ReportFormat::Json {
+        println!("{}", report::to_json("process", &report).to_pretty_string("  "));
+    } else if options.dry_run {
+        print_preview(&report, options);
+    } else {
+        if options.verbose {
+            let saved = report.changes.iter().filter(|c| {
+                !report.skipped.contains(&c.path) && !report.errors.iter().any(|d| d.path == c.path)
+            });
+            for change in saved {
+                println!("  저장: {}", change.path.display());
+            }
+        }
+        print_summary(&report);
+    }
+    report
+}
+
+// 카탈로그를 먼저 써서, 중간에 멈춰도 소스의 t() 호출이 없는 키를 가리키지 않게 함
+pub fn write_order(changes: &[FileChange]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..changes.len()).collect();
+    order.sort_by_key(|&i| !changes[i].wraps.is_empty());
+    order
+}
+
+pub fn write_all(changes: &[FileChange]) -> Vec<Diagnostic> {
+    write_order(changes)
+        .into_iter()
+        .map(|i| &changes[i])
+        .filter_map(|change| {
+            let error = write_file(&change.path, &change.updated).err()?;
+            Some(Diagnostic::error(diagnostic::WRITE_ERROR, error.to_string(), &change.path))
+        })
+        .collect()
+}
+
+// 임시 파일에 쓴 뒤 rename: 중간에 멈춰도 파일은 이전 내용 또는 새 내용 중 하나
+pub fn write_file(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+    let temp = temp_path(path);
+    fs::write(&temp, content)?;
+    fs::rename(&temp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp);
+    })
+}
+
+pub fn temp_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    path.with_file_name(format!(".{}.i18n-wrapper.tmp", name))
+}
+
+// ============================================
+// 명령들의 마지막 단계: dry-run이면 diff만 출력, 아니면 저장
+// sync/prune/rename/export/extract/duplicates/pseudo/translate가 공유
+// ============================================
+pub fn apply_changes(options: &ProcessOptions, changes: &[FileChange]) -> Vec<Diagnostic> {
+    if !options.dry_run {
+        return write_all(changes);
+    }
+    print_diffs(changes, options.context);
+    Vec::new()
+}
+
+// 요약 뒤에 붙이는 안내 (dry-run일 때만)
+pub fn print_dry_run_notice(options: &ProcessOptions) {
+    if options.dry_run {
+        println!("(Dry run mode: 파일을 수정하지 않았습니다)");
+    }
+}
+
+fn print_diffs(changes: &[FileChange], context: usize) {
+    let color = diff::use_color();
+    for change in changes {
+        let hunks = diff::diff_lines(&change.original, &change.updated, context);
+        print!("{}", diff::render_unified(&change.path.to_string_lossy(), &hunks, color));
+    }
+}
+
+// ============================================
+// dry-run 미리보기: 파일별 diff + 요약
+// ============================================
+pub fn print_preview(report: &ProcessReport, options: &ProcessOptions) {
+    match options.diff_format {
+        DiffFormat::Text => {
+            print_diffs(&report.changes, options.context);
+            print_summary(report);
+            print_dry_run_notice(options);
+        }
+        DiffFormat::Json => {
+            println!("{}", preview_json(report, options.context).to_pretty_string("  "));
+        }
+    }
+}
+
+pub fn preview_json(report: &ProcessReport, context: usize) -> Value {
+    let files = report
+        .changes
+        .iter()
+        .map(|change| {
+            let hunks = diff::diff_lines(&change.original, &change.updated, context);
+            let mut file = diff::hunks_to_json(&change.path.to_string_lossy(), &hunks);
+            file.insert("strings", change.wraps.len().into());
+            file
+        })
+        .collect();
+    let errors = report.errors.iter().map(report::diagnostic_json).collect();
+    Value::Object(vec![
+        ("files".to_string(), Value::Array(files)),
+        ("errors".to_string(), Value::Array(errors)),
+        (
+            "summary".to_string(),
+            Value::Object(vec![
+                ("filesChanged".to_string(), report.changes.len().into()),
+                ("stringsWrapped".to_string(), report.wrapped_strings().into()),
+                ("newKeys".to_string(), report.new_keys.into()),
+            ]),
+        ),
+    ])
+}
+
+pub fn print_summary(report: &ProcessReport) {
+    println!();
+    println!("요약:");
+    for change in report.changes.iter().filter(|c| !c.wraps.is_empty()) {
+        println!("  {}: 문자열 {}개", change.path.display(), change.wraps.len());
+    }
+    println!(
+        "  파일 {}개 변경, 문자열 {}개 래핑, 새 키 {}개",
+        report.changes.len(),
+        report.wrapped_strings(),
+        report.new_keys
+    );
+    naming::print_issues(&report.naming);
+    let color = diagnostic::use_color();
+    for diagnostic in &report.errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+    if report.cancelled {
+        eprintln!("  취소됨: 파일 {}개를 건너뜀", report.skipped.len());
+        for path in &report.skipped {
+            eprintln!("    {}", path.display());
+        }
+    }
+}
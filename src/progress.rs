@@ -0,0 +1,165 @@
+// ============================================
+// i18n-wrapper: 진행 상황 이벤트
+// 파이프라인의 각 단계가 async_examples.rs의 channel_example처럼
+// tokio::sync::mpsc 채널로 이벤트를 보내고, 소비자가 화면에 표시
+// 라이브러리 사용자는 channel()로 직접 받아서 쓸 수 있음:
+//   let (tx, mut rx) = progress::channel();
+//   let (_handle, cancel) = pipeline::cancel_pair();
+//   let analyzed = tokio::spawn(async move {
+//       pipeline::analyze_all(&files, jobs, None, Some(tx), timeout, cancel).await
+//   });
+//   while let Some(event) = rx.recv().await { ... }
+//   let analyzed = analyzed.await?;
+// ============================================
+
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+const CHANNEL_SIZE: usize = 256;
+// TTY가 아닐 때(CI 로그 등) 요약을 찍는 간격
+const LOG_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgressEvent {
+    // index는 0부터
+    FileStarted { path: PathBuf, index: usize, total: usize },
+    // 저장 단계: total은 저장할 파일 수 (카탈로그 포함)
+    WriteStarted { path: PathBuf, index: usize, total: usize },
+    FileParsed { path: PathBuf },
+    StringsFound { path: PathBuf, count: usize },
+    FileWritten { path: PathBuf },
+    FileFailed { path: PathBuf, message: String },
+}
+
+pub type ProgressSender = mpsc::Sender<ProgressEvent>;
+
+pub fn channel() -> (ProgressSender, mpsc::Receiver<ProgressEvent>) {
+    mpsc::channel(CHANNEL_SIZE)
+}
+
+// 받는 쪽이 없어져도 파이프라인은 계속 진행
+pub async fn emit(progress: &Option<ProgressSender>, event: ProgressEvent) {
+    if let Some(tx) = progress {
+        let _ = tx.send(event).await;
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ProgressState {
+    pub total: usize,
+    pub parsed: usize,
+    pub strings: usize,
+    pub written: usize,
+    pub failed: usize,
+    pub current: Option<PathBuf>,
+    // WriteStarted를 받으면 저장 단계: [저장+실패/전체]로 표시
+    pub writing: bool,
+}
+
+impl ProgressState {
+    pub fn apply(&mut self, event: &ProgressEvent) {
+        match event {
+            ProgressEvent::FileStarted { path, total, .. } => {
+                self.total = *total;
+                self.current = Some(path.clone());
+            }
+            ProgressEvent::WriteStarted { path, total, .. } => {
+                self.total = *total;
+                self.current = Some(path.clone());
+                self.writing = true;
+            }
+            ProgressEvent::FileParsed { .. } => self.parsed += 1,
+            ProgressEvent::StringsFound { count, .. } => self.strings += count,
+            ProgressEvent::FileWritten { .. } => self.written += 1,
+            ProgressEvent::FileFailed { .. } => self.failed += 1,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let done = if self.writing { self.written } else { self.parsed };
+        let mut line = format!(
+            "[{}/{}] 문자열 {}개, 저장 {}개",
+            done + self.failed,
+            self.total,
+            self.strings,
+            self.written
+        );
+        if self.failed > 0 {
+            line.push_str(&format!(", 실패 {}개", self.failed));
+        }
+        line
+    }
+}
+
+// ============================================
+// 기본 소비자: TTY면 한 줄을 계속 덮어쓰고, 아니면 주기적으로 로그
+// stdout은 결과(JSON 등) 용이므로 stderr에 출력
+// ============================================
+pub async fn render(mut rx: mpsc::Receiver<ProgressEvent>) -> ProgressState {
+    let tty = io::stderr().is_terminal();
+    let mut state = ProgressState::default();
+    let mut ticker = tokio::time::interval(LOG_INTERVAL);
+    // 첫 tick은 바로 완료되므로 건너뜀
+    ticker.tick().await;
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Some(event) => {
+                    state.apply(&event);
+                    if tty {
+                        let current = state.current.as_ref().map(|p| p.display().to_string()).unwrap_or_default();
+                        eprint!("\r\x1b[2K{} {}", state.summary(), current);
+                        let _ = io::stderr().flush();
+                    }
+                }
+                None => break,
+            },
+            _ = ticker.tick(), if !tty => eprintln!("진행: {}", state.summary()),
+        }
+    }
+    if tty {
+        eprint!("\r\x1b[2K");
+        let _ = io::stderr().flush();
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_render_counts_events() {
+        let (tx, rx) = channel();
+        let consumer = tokio::spawn(render(rx));
+        let path = PathBuf::from("src/App.tsx");
+        for event in [
+            ProgressEvent::FileStarted { path: path.clone(), index: 0, total: 2 },
+            ProgressEvent::FileParsed { path: path.clone() },
+            ProgressEvent::StringsFound { path: path.clone(), count: 3 },
+            ProgressEvent::FileFailed { path: PathBuf::from("src/Bad.tsx"), message: "parse".to_string() },
+            ProgressEvent::FileWritten { path },
+        ] {
+            tx.send(event).await.unwrap();
+        }
+        drop(tx);
+        let state = consumer.await.unwrap();
+        assert_eq!((state.parsed, state.strings, state.written, state.failed), (1, 3, 1, 1));
+        assert_eq!(state.summary(), "[2/2] 문자열 3개, 저장 1개, 실패 1개");
+    }
+
+    #[test]
+    fn test_write_phase_counts_written_files() {
+        let mut state = ProgressState::default();
+        let path = PathBuf::from("src/App.tsx");
+        state.apply(&ProgressEvent::WriteStarted { path: path.clone(), index: 0, total: 3 });
+        assert_eq!(state.summary(), "[0/3] 문자열 0개, 저장 0개");
+        state.apply(&ProgressEvent::FileWritten { path: path.clone() });
+        state.apply(&ProgressEvent::FileFailed { path, message: "denied".to_string() });
+        assert_eq!(state.summary(), "[2/3] 문자열 0개, 저장 1개, 실패 1개");
+    }
+}
@@ -0,0 +1,276 @@
+// ============================================
+// i18n-wrapper: prune 명령 (코드에서 더 이상 쓰지 않는 키 삭제)
+// extract와 같은 참조 스캔(extract::scan_references)으로 사용 중인 키를 모으고,
+// 어느 로케일 카탈로그에든 남아 있는 나머지 키를 모든 로케일에서 지움
+//
+// t(`status.${code}`) 처럼 동적으로 만드는 키는 스캔에 잡히지 않으므로
+// 설정의 keep = ["status.*"] 또는 --keep 패턴으로 보호
+// 지운 번역은 --archive 파일에 로케일별로 모아두어 나중에 되살릴 수 있음:
+// {
+//   "en": { "old.key": "Old text" },
+//   "ko": { "old.key": "옛 문구" }
+// }
+// ============================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+use crate::extract::{self, KeyReference};
+use crate::glob::Pattern;
+use crate::json::{self, Value};
+use crate::process::FileChange;
+// run_prune 전용
+#[cfg(feature = "swc")]
+use crate::process::{self, ProcessOptions};
+
+// 키 패턴은 점을 경로 구분자처럼 취급해서 glob 규칙을 그대로 사용
+//   "errors.*"  -> errors.network (한 단계)
+//   "errors.**" -> errors.network.timeout (모든 하위 키)
+pub fn keep_matches(patterns: &[String], key: &str) -> bool {
+    let path = key.replace('.', "/");
+    patterns.iter().any(|pattern| Pattern::new(&pattern.replace('.', "/")).matches(&path))
+}
+
+pub struct PrunePlan {
+    pub changes: Vec<FileChange>,
+    // 지운 키 (카탈로그 순서)
+    pub removed: Vec<String>,
+    // 쓰이지 않지만 keep 패턴 때문에 남긴 키
+    pub kept: Vec<String>,
+}
+
+// catalogs: (로케일, 카탈로그 경로), 원문 로케일 포함
+pub fn plan_prune(
+    references: &[KeyReference],
+    catalogs: &[(String, PathBuf)],
+    keep: &[String],
+    archive: Option<&Path>,
+) -> Result<PrunePlan, Box<Diagnostic>> {
+    let mut loaded = Vec::new();
+    for (locale, path) in catalogs {
+        let catalog = Catalog::load_diagnostic(path)?;
+        loaded.push((locale, catalog));
+    }
+
+    let mut removed: Vec<String> = Vec::new();
+    let mut kept: Vec<String> = Vec::new();
+    for key in loaded.iter().flat_map(|(_, catalog)| catalog.keys()) {
+        if references.iter().any(|r| r.key == key) || removed.contains(&key) || kept.contains(&key) {
+            continue;
+        }
+        if keep_matches(keep, &key) {
+            kept.push(key);
+        } else {
+            removed.push(key);
+        }
+    }
+
+    let mut changes = Vec::new();
+    let mut archived: Vec<(String, Vec<(String, String)>)> = Vec::new();
+    for (locale, mut catalog) in loaded {
+        let before = catalog.render();
+        let mut entries = Vec::new();
+        for key in &removed {
+            if let Some(value) = catalog.remove(key) {
+                entries.push((key.clone(), value));
+            }
+        }
+        if entries.is_empty() {
+            continue;
+        }
+        changes.push(FileChange {
+            path: catalog.path.clone(),
+            original: before,
+            updated: catalog.render(),
+            wraps: Vec::new(),
+        });
+        archived.push((locale.clone(), entries));
+
+        // extract가 만든 sidecar 항목도 함께 정리
+        let meta_file = extract::meta_path(&catalog.path);
+        let meta_before = match fs::read_to_string(&meta_file) {
+            Ok(text) if !text.trim().is_empty() => text,
+            _ => continue,
+        };
+        let mut meta = json::parse(&meta_before).map_err(|e| {
+            Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, format!("{}:{}", meta_file.display(), e), &meta_file))
+        })?;
+        let mut changed = false;
+        for key in &removed {
+            changed |= meta.remove(key).is_some();
+        }
+        if changed {
+            let mut meta_after = meta.to_pretty_string(&json::detect_indent(&meta_before));
+            meta_after.push('\n');
+            changes.push(FileChange {
+                path: meta_file,
+                original: meta_before,
+                updated: meta_after,
+                wraps: Vec::new(),
+            });
+        }
+    }
+
+    if let Some(path) = archive.filter(|_| !archived.is_empty()) {
+        changes.push(archive_change(path, &archived)?);
+    }
+
+    Ok(PrunePlan { changes, removed, kept })
+}
+
+// 기존 아카이브에 이어서 기록 (같은 키는 이번 값으로 덮어씀)
+fn archive_change(path: &Path, archived: &[(String, Vec<(String, String)>)]) -> Result<FileChange, Box<Diagnostic>> {
+    let original = fs::read_to_string(path).unwrap_or_default();
+    let mut root = if original.trim().is_empty() {
+        Value::object()
+    } else {
+        json::parse(&original).map_err(|e| {
+            Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, format!("{}:{}", path.display(), e), path))
+        })?
+    };
+    for (locale, entries) in archived {
+        if !matches!(root.get(locale), Some(Value::Object(_))) {
+            root.insert(locale, Value::object());
+        }
+        if let Some(section) = root.get_mut(locale) {
+            for (key, value) in entries {
+                section.insert(key, value.as_str().into());
+            }
+        }
+    }
+    let mut updated = root.to_pretty_string(&json::detect_indent(&original));
+    updated.push('\n');
+    Ok(FileChange {
+        path: path.to_path_buf(),
+        original,
+        updated,
+        wraps: Vec::new(),
+    })
+}
+
+// ============================================
+// CLI 진입점: 스캔 → 계획 → process::apply_changes → 요약
+// 파싱하지 못한 파일이 있으면 그 안의 키를 모르므로 아무것도 지우지 않음
+// ============================================
+#[cfg(feature = "swc")]
+pub fn run_prune(
+    options: &ProcessOptions,
+    catalogs: &[(String, PathBuf)],
+    keep: &[String],
+    archive: Option<&Path>,
+) -> Vec<Diagnostic> {
+    let (references, errors) = extract::scan_files(&options.files, options.framework);
+    if !errors.is_empty() {
+        let color = diagnostic::use_color();
+        for diagnostic in &errors {
+            eprintln!("{}", diagnostic.render(color));
+        }
+        eprintln!("prune: 스캔하지 못한 파일이 있어 키를 지우지 않았습니다");
+        return errors;
+    }
+    let plan = match plan_prune(&references, catalogs, keep, archive) {
+        Ok(plan) => plan,
+        Err(diagnostic) => return vec![*diagnostic],
+    };
+
+    let errors = process::apply_changes(options, &plan.changes);
+    println!();
+    println!("요약:");
+    for key in &plan.removed {
+        println!("  - {}", key);
+    }
+    for key in &plan.kept {
+        println!("  = {} (keep 패턴)", key);
+    }
+    let mut used: Vec<&str> = references.iter().map(|r| r.key.as_str()).collect();
+    used.sort_unstable();
+    used.dedup();
+    println!(
+        "  사용 중인 키 {}개, 삭제 {}개, 보호 {}개, 변경 파일 {}개",
+        used.len(),
+        plan.removed.len(),
+        plan.kept.len(),
+        plan.changes.len()
+    );
+    if let Some(path) = archive.filter(|_| !plan.removed.is_empty()) {
+        println!("  삭제한 번역은 {}에 보관", path.display());
+    }
+    process::print_dry_run_notice(options);
+    let color = diagnostic::use_color();
+    for diagnostic in &errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::{self, TempDir};
+
+    #[test]
+    fn test_prune_keeps_patterns_and_archives() {
+        let dir = TempDir::new("prune");
+        fs::write(dir.join("en.json"), "{\n  \"used\": \"Used\",\n  \"old\": \"Old\",\n  \"status.404\": \"Not found\"\n}\n")
+            .unwrap();
+        fs::write(dir.join("ko.json"), "{\n  \"old\": \"옛\",\n  \"gone\": \"없음\"\n}\n").unwrap();
+        let catalogs = vec![("en".to_string(), dir.join("en.json")), ("ko".to_string(), dir.join("ko.json"))];
+        let references = vec![testutil::reference("used")];
+
+        let archive = dir.join("archive.json");
+        let plan = plan_prune(&references, &catalogs, &["status.*".to_string()], Some(&archive)).unwrap();
+        assert_eq!(plan.removed, vec!["old", "gone"]);
+        assert_eq!(plan.kept, vec!["status.404"]);
+        let updated = |path: &Path| plan.changes.iter().find(|c| c.path == path).map(|c| c.updated.as_str());
+        assert_eq!(
+            updated(&dir.join("en.json")),
+            Some("{\n  \"used\": \"Used\",\n  \"status.404\": \"Not found\"\n}\n")
+        );
+        assert_eq!(updated(&dir.join("ko.json")), Some("{}\n"));
+        let archived = json::parse(updated(&archive).unwrap()).unwrap();
+        assert_eq!(archived.get("ko").and_then(|ko| ko.get("gone")).and_then(Value::as_str), Some("없음"));
+        assert_eq!(archived.get("en").and_then(|en| en.get("old")).and_then(Value::as_str), Some("Old"));
+
+        assert!(keep_matches(&["errors.**".to_string()], "errors.network.timeout"));
+        assert!(!keep_matches(&["errors.*".to_string()], "errors.network.timeout"));
+    }
+
+    #[test]
+    fn test_prune_nested_catalog_sidecar_and_existing_archive() {
+        let dir = TempDir::new("prune-nested");
+        let en = dir.write(
+            "en.json",
+            "{\n  \"cart\": {\n    \"title\": \"Cart\",\n    \"old\": \"Old\"\n  },\n  \"legacy\": {\n    \"banner\": \"Sale\"\n  },\n  \"errors\": {\n    \"network\": {\n      \"timeout\": \"Timeout\"\n    }\n  }\n}\n",
+        );
+        dir.write(
+            "en.meta.json",
+            "{\n  \"cart.title\": { \"description\": \"heading\" },\n  \"cart.old\": { \"description\": \"gone\" }\n}\n",
+        );
+        // 이전 실행에서 보관한 다른 키는 그대로, 같은 키는 이번 값으로
+        let archive = dir.write("archive.json", "{\n  \"en\": {\n    \"cart.old\": \"Older\",\n    \"intro\": \"Hi\"\n  }\n}\n");
+
+        let references = vec![testutil::reference("cart.title")];
+        let catalogs = vec![("en".to_string(), en.clone())];
+        let plan = plan_prune(&references, &catalogs, &["errors.**".to_string()], Some(&archive)).unwrap();
+        assert_eq!(plan.removed, vec!["cart.old", "legacy.banner"]);
+        assert_eq!(plan.kept, vec!["errors.network.timeout"]);
+
+        let updated = |path: &Path| plan.changes.iter().find(|c| c.path == path).map(|c| c.updated.as_str());
+        // 비게 된 상위 객체(legacy)도 함께 정리
+        assert_eq!(
+            updated(&en),
+            Some("{\n  \"cart\": {\n    \"title\": \"Cart\"\n  },\n  \"errors\": {\n    \"network\": {\n      \"timeout\": \"Timeout\"\n    }\n  }\n}\n")
+        );
+        assert_eq!(
+            updated(&dir.join("en.meta.json")),
+            Some("{\n  \"cart.title\": {\n    \"description\": \"heading\"\n  }\n}\n")
+        );
+        let archived = json::parse(updated(&archive).unwrap()).unwrap();
+        let en_archive = archived.get("en").unwrap();
+        assert_eq!(en_archive.get("cart.old").and_then(Value::as_str), Some("Old"));
+        assert_eq!(en_archive.get("intro").and_then(Value::as_str), Some("Hi"));
+        assert_eq!(en_archive.get("legacy.banner").and_then(Value::as_str), Some("Sale"));
+    }
+}
@@ -0,0 +1,351 @@
+// ============================================
+// i18n-wrapper: 의사 번역 (pseudo-localization)
+// 실제 번역이 오기 전에 UI 버그를 찾기 위한 가짜 로케일
+//   악센트:  "Save" -> "Śàṽé"           (번역 안 된 하드코딩 문자열이 눈에 띔)
+//   늘리기:  "[Śàṽé~~]"                  (독일어 등 긴 번역에서 잘리는 곳 확인)
+//   괄호:    [ ... ]                      (문자열 이어 붙이기/잘림 확인)
+//   미러:    U+202E ... U+202C            (RTL 레이아웃 흉내)
+// 런타임에 치환되는 부분은 그대로 통과:
+//   {{name}}, ICU {count, plural, one {# item} other {# items}}, <0>..</0>, <br/>
+// TypeScript (pseudo-localization 패키지):
+// localize("Save", { strategy: "accented" }) // "Śàṽé"
+// ============================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+use crate::process::FileChange;
+
+pub const DEFAULT_LOCALE: &str = "en-XA";
+// 미러 모드의 관례적인 이름 (Android와 같음)
+pub const DEFAULT_MIRROR_LOCALE: &str = "ar-XB";
+
+const RIGHT_TO_LEFT_OVERRIDE: char = '\u{202E}';
+const POP_DIRECTIONAL_FORMATTING: char = '\u{202C}';
+const PADDING: char = '~';
+
+#[derive(Debug, Clone)]
+pub struct PseudoOptions {
+    // 번역할 글자 수 대비 늘릴 비율 (30이면 30%)
+    pub expansion: usize,
+    pub brackets: bool,
+    pub mirror: bool,
+}
+
+impl Default for PseudoOptions {
+    fn default() -> Self {
+        Self {
+            expansion: 30,
+            brackets: true,
+            mirror: false,
+        }
+    }
+}
+
+// 원래 글자와 모양이 비슷해서 읽을 수는 있는 문자
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'à',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Î',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Ś',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        _ => c,
+    }
+}
+
+pub fn pseudolocalize(text: &str, options: &PseudoOptions) -> String {
+    let mut writer = Writer {
+        options,
+        out: String::new(),
+        run: String::new(),
+        letters: 0,
+    };
+    writer.message(text, false);
+    writer.flush();
+
+    let padding: String = std::iter::repeat_n(PADDING, (writer.letters * options.expansion).div_ceil(100)).collect();
+    if options.brackets {
+        format!("[{}{}]", writer.out, padding)
+    } else {
+        format!("{}{}", writer.out, padding)
+    }
+}
+
+// 번역할 글자는 run에 모았다가 보호 구간을 만나면 한꺼번에 변환 (미러는 구간 단위로 감쌈)
+struct Writer<'a> {
+    options: &'a PseudoOptions,
+    out: String,
+    run: String,
+    letters: usize,
+}
+
+impl Writer<'_> {
+    fn flush(&mut self) {
+        if self.run.is_empty() {
+            return;
+        }
+        let run: String = self.run.drain(..).map(accent).collect();
+        if self.options.mirror {
+            self.out.push(RIGHT_TO_LEFT_OVERRIDE);
+            self.out.push_str(&run);
+            self.out.push(POP_DIRECTIONAL_FORMATTING);
+        } else {
+            self.out.push_str(&run);
+        }
+    }
+
+    fn keep(&mut self, text: &str) {
+        self.flush();
+        self.out.push_str(text);
+    }
+
+    // plural: ICU plural 분기 안이면 #(숫자 자리)도 보호
+    fn message(&mut self, text: &str, plural: bool) {
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let protected = if rest.starts_with("{{") {
+                Some(rest.find("}}").map(|close| close + 2).unwrap_or(rest.len()))
+            } else if c == '{' {
+                Some(matching_brace(rest).map(|close| close + 1).unwrap_or(rest.len()))
+            } else if c == '<' {
+                tag_len(rest)
+            } else if c == '#' && plural {
+                Some(1)
+            } else {
+                None
+            };
+            match protected {
+                Some(len) if c == '{' && !rest.starts_with("{{") => {
+                    self.argument(&rest[..len]);
+                    rest = &rest[len..];
+                }
+                Some(len) => {
+                    self.keep(&rest[..len]);
+                    rest = &rest[len..];
+                }
+                None => {
+                    if c.is_alphanumeric() {
+                        self.letters += 1;
+                    }
+                    self.run.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+    }
+
+    // ICU 인자: {name}, {n, number}은 그대로,
+    // {n, plural|select|selectordinal, 선택자 {문장} ...}은 문장 부분만 변환
+    fn argument(&mut self, block: &str) {
+        let inner = match block.strip_prefix('{').and_then(|b| b.strip_suffix('}')) {
+            Some(inner) => inner,
+            None => return self.keep(block),
+        };
+        let mut parts = inner.splitn(3, ',');
+        let (name, kind, cases) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(name), Some(kind), Some(cases)) => (name, kind, cases),
+            _ => return self.keep(block),
+        };
+        let plural = match kind.trim() {
+            "plural" | "selectordinal" => true,
+            "select" => false,
+            _ => return self.keep(block),
+        };
+        self.keep(&format!("{{{},{},", name, kind));
+        let mut rest = cases;
+        while !rest.is_empty() {
+            // 선택자 (one, other, =0, offset:1) 와 공백은 그대로
+            let open = match rest.find('{') {
+                Some(open) => open,
+                None => break,
+            };
+            self.keep(&rest[..open]);
+            let close = match matching_brace(&rest[open..]) {
+                Some(close) => open + close,
+                None => break,
+            };
+            self.keep("{");
+            self.message(&rest[open + 1..close], plural);
+            self.keep("}");
+            rest = &rest[close + 1..];
+        }
+        self.keep(&format!("{}}}", rest));
+    }
+}
+
+// text[0] == '{' 일 때 짝이 맞는 '}'의 위치
+fn matching_brace(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// react-i18next Trans 태그: <0>, </0>, <1/>, <br/>, <strong> (속성은 없음)
+fn tag_len(text: &str) -> Option<usize> {
+    let inner = text.strip_prefix('<')?;
+    let inner = inner.strip_prefix('/').unwrap_or(inner);
+    let name_len = inner.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(inner.len());
+    if name_len == 0 {
+        return None;
+    }
+    let after = inner[name_len..].trim_start();
+    let after = after.strip_prefix('/').unwrap_or(after);
+    after.starts_with('>').then(|| text.len() - after.len() + 1)
+}
+
+// ============================================
+// 원문 카탈로그 → 의사 번역 로케일 카탈로그 (매번 원문에서 다시 생성)
+// ============================================
+pub fn plan_pseudo(source: &Path, target: &Path, options: &PseudoOptions) -> Result<FileChange, Box<Diagnostic>> {
+    let source = Catalog::load_diagnostic(source)?;
+    let original = fs::read_to_string(target).unwrap_or_default();
+
+    // 들여쓰기 등 파일 형식은 원문을 따름
+    let mut catalog = source.clone();
+    catalog.path = PathBuf::from(target);
+    for (key, text) in source.entries() {
+        catalog
+            .insert(&key, &pseudolocalize(&text, options))
+            .map_err(|e| Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, e.to_string(), target)))?;
+    }
+    Ok(FileChange {
+        path: target.to_path_buf(),
+        original,
+        updated: catalog.render(),
+        wraps: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn test_pseudolocalize_keeps_placeholders_icu_and_tags() {
+        let plain = PseudoOptions {
+            expansion: 0,
+            brackets: false,
+            mirror: false,
+        };
+        assert_eq!(pseudolocalize("Save", &plain), "Śàṽé");
+        assert_eq!(pseudolocalize("Hi {{name}}, <0>go</0><br/>", &plain), "Ĥî {{name}}, <0>ĝö</0><br/>");
+        assert_eq!(
+            pseudolocalize("{count, plural, one {# item} other {# items}} in {place}", &plain),
+            "{count, plural, one {# îţéɱ} other {# îţéɱš}} îñ {place}"
+        );
+        assert_eq!(pseudolocalize("a < b", &plain), "à < ƀ");
+
+        // 글자 10개의 30% -> 3개
+        let expanded = pseudolocalize("Save files", &PseudoOptions::default());
+        assert_eq!(expanded, "[Śàṽé ƒîļéš~~~]");
+
+        let mirrored = pseudolocalize("Go {{x}}", &PseudoOptions { mirror: true, ..plain });
+        assert_eq!(mirrored, "\u{202E}Ĝö \u{202C}{{x}}");
+    }
+
+    #[test]
+    fn test_nested_icu_and_unbalanced_braces() {
+        let plain = PseudoOptions {
+            expansion: 0,
+            brackets: false,
+            mirror: false,
+        };
+        // plural 분기 안의 select도 인자 이름/선택자는 그대로, 문장만 변환
+        assert_eq!(
+            pseudolocalize(
+                "{count, plural, =0 {No items} other {{gender, select, female {# for her} other {# items}}}}",
+                &plain
+            ),
+            "{count, plural, =0 {Ñö îţéɱš} other {{gender, select, female {# ƒöŕ ĥéŕ} other {# îţéɱš}}}}"
+        );
+        // 닫히지 않은 인자는 끝까지 보호 (번역자가 고칠 때까지 깨뜨리지 않음)
+        assert_eq!(pseudolocalize("Oops {count, plural, one {item", &plain), "Ööþš {count, plural, one {item");
+        // 패딩은 보호 구간을 빼고 센 글자 수 기준: "No"의 2글자 * 50% -> 1개
+        let padded = PseudoOptions { expansion: 50, ..plain };
+        assert_eq!(pseudolocalize("No {count}", &padded), "Ñö {count}~");
+    }
+
+    #[test]
+    fn test_plan_pseudo_regenerates_nested_target_from_source() {
+        let dir = TempDir::new("pseudo");
+        let target = dir.join("en-XA.json");
+        dir.write("en.json", "{\n  \"cart\": {\n    \"title\": \"Cart\"\n  },\n  \"save\": \"Save\"\n}\n");
+        // 손으로 고친 값이나 원문에서 사라진 키는 남기지 않음
+        let stale = "{\n  \"cart\": {\n    \"title\": \"edited\"\n  },\n  \"removed\": \"x\"\n}\n";
+        dir.write("en-XA.json", stale);
+
+        let options = PseudoOptions {
+            expansion: 0,
+            ..PseudoOptions::default()
+        };
+        let change = plan_pseudo(&dir.join("en.json"), &target, &options).unwrap();
+        assert_eq!(change.path, target);
+        assert_eq!(change.original, stale);
+        assert_eq!(
+            change.updated,
+            "{\n  \"cart\": {\n    \"title\": \"[Çàŕţ]\"\n  },\n  \"save\": \"[Śàṽé]\"\n}\n"
+        );
+    }
+}
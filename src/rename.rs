@@ -0,0 +1,368 @@
+// ============================================
+// i18n-wrapper: rename-key (코드와 모든 로케일 카탈로그의 키 이름 변경)
+//   i18n-wrapper rename-key checkout.title checkout.heading
+//   i18n-wrapper rename-key --prefix checkout. payment.
+//
+// swc_examples.rs의 ConstantRenamer는 VisitMut로 Ident를 바꾼 뒤 코드를 다시 생성하지만
+// 그러면 포맷과 주석이 바뀌므로, 여기서는 extract::scan_references가 찾은
+// 키 리터럴 범위(따옴표 포함)만 새 문자열로 교체 (process의 Wrap 적용과 같은 방식)
+// TypeScript: code.slice(0, start) + `'${newKey}'` + code.slice(end)
+// ============================================
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+use crate::extract::{self, KeyReference};
+use crate::json;
+use crate::process::FileChange;
+// run_rename 전용
+#[cfg(feature = "swc")]
+use crate::process::{self, ProcessOptions};
+
+// ConstantRenamer { old_name, new_name }와 같은 역할, 접두사 모드만 추가
+pub struct KeyRenamer {
+    pub old: String,
+    pub new: String,
+    // true면 old로 시작하는 모든 키의 앞부분을 new로 교체
+    pub prefix: bool,
+}
+
+impl KeyRenamer {
+    pub fn rename(&self, key: &str) -> Option<String> {
+        if self.prefix {
+            key.strip_prefix(self.old.as_str()).map(|rest| format!("{}{}", self.new, rest))
+        } else if key == self.old {
+            Some(self.new.clone())
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RenameError {
+    SameKey(String),
+    // 코드에도 카탈로그에도 없는 키
+    NotFound(String),
+    // 새 키가 이미 있으면 덮어쓰는 순간 번역이 사라지므로 거부
+    Exists(Vec<String>),
+    Io(Box<Diagnostic>),
+    // 파싱하지 못한 파일 안의 참조는 바꿀 수 없으므로 아무것도 바꾸지 않음 (진단은 run_rename이 이미 출력)
+    Scan(Vec<Diagnostic>),
+    // 계획은 세웠지만 일부 파일을 쓰지 못함 (진단은 run_rename이 이미 출력)
+    Write(Vec<Diagnostic>),
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RenameError::SameKey(key) => write!(f, "이전 키와 새 키가 같습니다: {}", key),
+            RenameError::NotFound(key) => write!(f, "코드와 카탈로그 어디에도 `{}` 키가 없습니다", key),
+            RenameError::Exists(keys) => write!(f, "새 키가 이미 있습니다: {}", keys.join(", ")),
+            RenameError::Io(diagnostic) => write!(f, "{}", diagnostic),
+            RenameError::Scan(errors) => write!(f, "파일 {}개를 스캔하지 못해 키를 바꾸지 않았습니다", errors.len()),
+            RenameError::Write(errors) => write!(f, "파일 {}개를 쓰지 못했습니다", errors.len()),
+        }
+    }
+}
+
+impl std::error::Error for RenameError {}
+
+pub struct RenamePlan {
+    pub changes: Vec<FileChange>,
+    // (이전 키, 새 키), 처음 나온 순서
+    pub renamed: Vec<(String, String)>,
+    // 바뀐 코드 참조 수
+    pub references: usize,
+}
+
+// catalogs: 원문 카탈로그를 포함한 모든 로케일 카탈로그 경로
+pub fn plan_rename(
+    renamer: &KeyRenamer,
+    references: &[KeyReference],
+    catalogs: &[PathBuf],
+) -> Result<RenamePlan, RenameError> {
+    if renamer.old == renamer.new {
+        return Err(RenameError::SameKey(renamer.old.clone()));
+    }
+    let io_error = |path: &Path, message: String| {
+        RenameError::Io(Box::new(Diagnostic::error(diagnostic::IO_ERROR, message, path)))
+    };
+    let mut loaded = Vec::new();
+    for path in catalogs {
+        let catalog = Catalog::load_diagnostic(path).map_err(RenameError::Io)?;
+        loaded.push(catalog);
+    }
+
+    // 코드와 카탈로그에 있는 모든 키
+    let mut existing: Vec<String> = Vec::new();
+    for key in references.iter().map(|r| r.key.clone()).chain(loaded.iter().flat_map(Catalog::keys)) {
+        if !existing.contains(&key) {
+            existing.push(key);
+        }
+    }
+    let renamed: Vec<(String, String)> = existing
+        .iter()
+        .filter_map(|key| renamer.rename(key).map(|new| (key.clone(), new)))
+        .collect();
+    if renamed.is_empty() {
+        return Err(RenameError::NotFound(renamer.old.clone()));
+    }
+    // 함께 이름이 바뀌는 키와 겹치는 것은 괜찮음 (a.x -> a.b.x 와 a.b.x -> a.b.b.x)
+    let conflicts: Vec<String> = renamed
+        .iter()
+        .map(|(_, new)| new)
+        .filter(|new| existing.contains(new) && !renamed.iter().any(|(old, _)| old == *new))
+        .cloned()
+        .collect();
+    if !conflicts.is_empty() {
+        return Err(RenameError::Exists(conflicts));
+    }
+
+    let (mut changes, count) = apply_literal_edits(references, |key| renamer.rename(key)).map_err(RenameError::Io)?;
+
+    for mut catalog in loaded {
+        let before = catalog.render();
+        for (old, new) in &renamed {
+            catalog.rename(old, new).map_err(|e| {
+                RenameError::Io(Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, e.to_string(), &catalog.path)))
+            })?;
+        }
+        let after = catalog.render();
+        if after != before {
+            changes.push(FileChange {
+                path: catalog.path.clone(),
+                original: before,
+                updated: after,
+                wraps: Vec::new(),
+            });
+        }
+
+        // extract가 만든 sidecar(comment, references)도 함께 옮김
+        let meta_file = extract::meta_path(&catalog.path);
+        let meta_before = match fs::read_to_string(&meta_file) {
+            Ok(text) if !text.trim().is_empty() => text,
+            _ => continue,
+        };
+        let mut meta = json::parse(&meta_before).map_err(|e| io_error(&meta_file, e.to_string()))?;
+        let mut moved = false;
+        for (old, new) in &renamed {
+            moved |= meta.rename_key(old, new);
+        }
+        if moved {
+            let mut meta_after = meta.to_pretty_string(&json::detect_indent(&meta_before));
+            meta_after.push('\n');
+            changes.push(FileChange {
+                path: meta_file,
+                original: meta_before,
+                updated: meta_after,
+                wraps: Vec::new(),
+            });
+        }
+    }
+
+    Ok(RenamePlan {
+        changes,
+        renamed,
+        references: count,
+    })
+}
+
+// 코드의 키 리터럴을 파일마다 교체 (new_key가 None인 참조는 그대로)
+// rename-key와 duplicates --consolidate가 같이 씀. (바뀐 파일, 바꾼 참조 수)
+pub fn apply_literal_edits(
+    references: &[KeyReference],
+    new_key: impl Fn(&str) -> Option<String>,
+) -> Result<(Vec<FileChange>, usize), Box<Diagnostic>> {
+    let mut paths: Vec<&str> = Vec::new();
+    for reference in references {
+        if !paths.contains(&reference.path.as_str()) {
+            paths.push(&reference.path);
+        }
+    }
+    let mut changes = Vec::new();
+    let mut count = 0;
+    for path in paths {
+        let mut edits: Vec<(usize, usize, String)> = references
+            .iter()
+            .filter(|r| r.path == path)
+            .filter_map(|r| new_key(&r.key).map(|new| (r.start, r.end, new)))
+            .collect();
+        if edits.is_empty() {
+            continue;
+        }
+        let original = fs::read_to_string(path)
+            .map_err(|e| Box::new(Diagnostic::error(diagnostic::IO_ERROR, e.to_string(), Path::new(path))))?;
+        // 뒤에서부터 바꿔야 앞쪽 오프셋이 유지됨
+        edits.sort_by_key(|(start, _, _)| std::cmp::Reverse(*start));
+        let mut updated = original.clone();
+        for (start, end, new) in &edits {
+            updated.replace_range(*start..*end, &quote(&original[*start..*end], new));
+        }
+        count += edits.len();
+        changes.push(FileChange {
+            path: PathBuf::from(path),
+            original,
+            updated,
+            wraps: Vec::new(),
+        });
+    }
+    Ok((changes, count))
+}
+
+// 원래 리터럴의 따옴표 종류를 유지: 'a.b' -> 'c.d', "a.b" -> "c.d"
+pub fn quote(literal: &str, key: &str) -> String {
+    let quote = literal.chars().next().filter(|c| *c == '\'' || *c == '"').unwrap_or('\'');
+    let mut out = String::new();
+    out.push(quote);
+    for c in key.chars() {
+        if c == quote || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push(quote);
+    out
+}
+
+// ============================================
+// CLI 진입점: 스캔 → 계획 → process::apply_changes → 요약
+// ============================================
+#[cfg(feature = "swc")]
+pub fn run_rename(options: &ProcessOptions, catalogs: &[PathBuf], renamer: &KeyRenamer) -> Result<(), RenameError> {
+    let (references, errors) = extract::scan_files(&options.files, options.framework);
+    let color = diagnostic::use_color();
+    if !errors.is_empty() {
+        // 카탈로그만 바뀌고 파싱하지 못한 파일의 참조는 이전 키로 남게 되므로 중단
+        for diagnostic in &errors {
+            eprintln!("{}", diagnostic.render(color));
+        }
+        return Err(RenameError::Scan(errors));
+    }
+    let plan = plan_rename(renamer, &references, catalogs)?;
+
+    let errors = process::apply_changes(options, &plan.changes);
+    println!();
+    println!("요약:");
+    for (old, new) in &plan.renamed {
+        println!("  {} -> {}", old, new);
+    }
+    println!(
+        "  키 {}개, 코드 참조 {}개, 변경 파일 {}개",
+        plan.renamed.len(),
+        plan.references,
+        plan.changes.len()
+    );
+    process::print_dry_run_notice(options);
+    for diagnostic in &errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+    if !errors.is_empty() {
+        return Err(RenameError::Write(errors));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    fn renamer(old: &str, new: &str) -> KeyRenamer {
+        KeyRenamer {
+            old: old.to_string(),
+            new: new.to_string(),
+            prefix: false,
+        }
+    }
+
+    #[test]
+    fn test_plan_rename_errors() {
+        let dir = TempDir::new("rename-errors");
+        let en = dir.write("en.json", "{\n  \"cart\": \"Cart\",\n  \"checkout\": {\n    \"title\": \"Checkout\"\n  }\n}\n");
+        let catalogs = vec![en.clone()];
+
+        assert!(matches!(plan_rename(&renamer("a", "a"), &[], &catalogs), Err(RenameError::SameKey(_))));
+        assert!(matches!(plan_rename(&renamer("missing", "b"), &[], &catalogs), Err(RenameError::NotFound(_))));
+        // 빈 카탈로그에서는 바꿀 키가 없음
+        let empty = vec![dir.write("empty.json", "")];
+        assert!(matches!(plan_rename(&renamer("cart", "basket"), &[], &empty), Err(RenameError::NotFound(_))));
+
+        // 문자열인 "cart" 아래로 옮기려 함: 덮어쓰지 않고 카탈로그 에러
+        match plan_rename(&renamer("checkout.title", "cart.title"), &[], &catalogs) {
+            Err(RenameError::Io(diagnostic)) => {
+                assert_eq!(diagnostic.code, diagnostic::CATALOG_ERROR);
+                assert!(diagnostic.message.contains("`cart`"));
+            }
+            _ => panic!("겹치는 경로를 거부해야 합니다"),
+        }
+        assert_eq!(fs::read_to_string(&en).unwrap().matches("Checkout").count(), 1);
+
+        // 깨진 카탈로그
+        let broken = vec![dir.write("broken.json", "{ \"cart\": ")];
+        assert!(matches!(plan_rename(&renamer("cart", "basket"), &[], &broken), Err(RenameError::Io(_))));
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_rename_updates_code_and_every_catalog() {
+        let dir = TempDir::new("rename");
+        let source = dir.join("App.jsx");
+        let code = "const App = () => (\n  <p title={t('cart.title')}>\n    <Trans i18nKey=\"cart.body\">Hi</Trans>\n  </p>\n);\n";
+        fs::write(&source, code).unwrap();
+        fs::write(dir.join("en.json"), "{\n  \"cart.title\": \"Cart\",\n  \"cart.body\": \"Hi\",\n  \"x\": \"X\"\n}\n").unwrap();
+        fs::write(dir.join("ko.json"), "{\n  \"cart\": {\n    \"title\": \"장바구니\"\n  }\n}\n").unwrap();
+        let catalogs = vec![dir.join("en.json"), dir.join("ko.json")];
+        let references = extract::scan_references(&source.to_string_lossy(), code, crate::framework::Framework::default()).unwrap();
+
+        let renamer = KeyRenamer {
+            old: "cart.".to_string(),
+            new: "basket.".to_string(),
+            prefix: true,
+        };
+        let plan = plan_rename(&renamer, &references, &catalogs).unwrap();
+        assert_eq!(plan.references, 2);
+        let updated = |path: &Path| plan.changes.iter().find(|c| c.path == path).map(|c| c.updated.as_str());
+        let new_code = updated(&source).unwrap();
+        assert!(new_code.contains("t('basket.title')"));
+        assert!(new_code.contains("i18nKey=\"basket.body\""));
+        // 평평한 카탈로그는 같은 자리에서, 중첩 카탈로그는 새 경로로
+        assert_eq!(
+            updated(&dir.join("en.json")),
+            Some("{\n  \"basket.title\": \"Cart\",\n  \"basket.body\": \"Hi\",\n  \"x\": \"X\"\n}\n")
+        );
+        assert_eq!(
+            updated(&dir.join("ko.json")),
+            Some("{\n  \"basket\": {\n    \"title\": \"장바구니\"\n  }\n}\n")
+        );
+
+        let renamer = KeyRenamer {
+            old: "cart.title".to_string(),
+            new: "x".to_string(),
+            prefix: false,
+        };
+        assert!(matches!(plan_rename(&renamer, &references, &catalogs), Err(RenameError::Exists(_))));
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_rename_refuses_when_a_file_fails_to_parse() {
+        let dir = TempDir::new("rename-scan");
+        let good = dir.write("App.jsx", "const App = () => <p>{t('cart')}</p>;\n");
+        let broken = dir.write("Broken.jsx", "const Broken = () => <p>{t('cart')</p>;\n");
+        let catalog = dir.write("en.json", "{\n  \"cart\": \"Cart\"\n}\n");
+        let options = ProcessOptions {
+            files: vec![good.clone(), broken],
+            catalog_path: catalog.clone(),
+            ..Default::default()
+        };
+
+        let result = run_rename(&options, std::slice::from_ref(&catalog), &renamer("cart", "basket"));
+        assert!(matches!(result, Err(RenameError::Scan(errors)) if errors.len() == 1));
+        assert_eq!(fs::read_to_string(&good).unwrap(), "const App = () => <p>{t('cart')}</p>;\n");
+        assert_eq!(fs::read_to_string(&catalog).unwrap(), "{\n  \"cart\": \"Cart\"\n}\n");
+    }
+}
@@ -0,0 +1,253 @@
+// ============================================
+// i18n-wrapper: 기계가 읽는 리포트 (analyze / process --format json)
+// 대시보드가 필드 구조에 의존할 수 있도록 schemaVersion을 함께 출력
+// 필드를 추가만 할 때는 그대로 두고, 없애거나 의미를 바꾸면 SCHEMA_VERSION을 올림
+//
+// {
+//   "schemaVersion": 1,
+//   "tool": { "name": "i18n-wrapper", "version": "0.1.0" },
+//   "command": "analyze",
+//   "files": [{
+//     "path": "src/App.tsx",
+//     "imports": ["react", "./Button"],
+//     "usesTranslation": true,
+//     "strings": [{ "text", "span": { "start", "end", "line", "column" }, "context": {...}, "ignored" }],
+//     "wraps": [{ "key", "text", "replacement", "span": {...} }]
+//   }],
+//   "errors": [{ "path", "code", "severity", "message", "line", "column" }],
+//   "summary": { ... }
+// }
+// ============================================
+
+use crate::cache::TOOL_VERSION;
+use crate::diagnostic::Diagnostic;
+use crate::json::Value;
+use crate::process::ProcessReport;
+use crate::transform::{FileAnalysis, HardcodedString, Wrap};
+
+pub const SCHEMA_VERSION: usize = 1;
+pub const TOOL_NAME: &str = "i18n-wrapper";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    // SARIF 2.1.0 (sarif.rs), analyze 전용
+    Sarif,
+}
+
+impl ReportFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(ReportFormat::Text),
+            "json" => Some(ReportFormat::Json),
+            "sarif" => Some(ReportFormat::Sarif),
+            _ => None,
+        }
+    }
+}
+
+pub fn to_json(command: &str, report: &ProcessReport) -> Value {
+    let files = report
+        .analyses
+        .iter()
+        .map(|analysis| {
+            let wraps = report
+                .changes
+                .iter()
+                .find(|c| c.path.to_string_lossy() == analysis.path)
+                .map(|c| c.wraps.as_slice())
+                .unwrap_or(&[]);
+            file_json(analysis, wraps)
+        })
+        .collect();
+    let strings: usize = report.analyses.iter().map(|a| a.strings.len()).sum();
+    let ignored: usize = report.analyses.iter().map(|a| a.strings.iter().filter(|s| s.ignored).count()).sum();
+
+    Value::Object(vec![
+        ("schemaVersion".to_string(), SCHEMA_VERSION.into()),
+        (
+            "tool".to_string(),
+            Value::Object(vec![
+                ("name".to_string(), TOOL_NAME.into()),
+                ("version".to_string(), TOOL_VERSION.into()),
+            ]),
+        ),
+        ("command".to_string(), command.into()),
+        ("files".to_string(), Value::Array(files)),
+        (
+            "errors".to_string(),
+            Value::Array(report.errors.iter().map(diagnostic_json).collect()),
+        ),
+        (
+            "summary".to_string(),
+            Value::Object(vec![
+                ("filesAnalyzed".to_string(), report.analyses.len().into()),
+                ("filesChanged".to_string(), report.changes.len().into()),
+                ("strings".to_string(), strings.into()),
+                ("ignoredStrings".to_string(), ignored.into()),
+                ("stringsWrapped".to_string(), report.wrapped_strings().into()),
+                ("newKeys".to_string(), report.new_keys.into()),
+                ("errors".to_string(), report.errors.len().into()),
+                ("cancelled".to_string(), report.cancelled.into()),
+            ]),
+        ),
+    ])
+}
+
+fn file_json(analysis: &FileAnalysis, wraps: &[Wrap]) -> Value {
+    Value::Object(vec![
+        ("path".to_string(), analysis.path.as_str().into()),
+        (
+            "imports".to_string(),
+            Value::Array(analysis.imports.iter().map(|i| i.as_str().into()).collect()),
+        ),
+        ("usesTranslation".to_string(), analysis.uses_translation.into()),
+        (
+            "strings".to_string(),
+            Value::Array(analysis.strings.iter().map(string_json).collect()),
+        ),
+        ("wraps".to_string(), Value::Array(wraps.iter().map(wrap_json).collect())),
+    ])
+}
+
+fn string_json(s: &HardcodedString) -> Value {
+    Value::Object(vec![
+        ("text".to_string(), s.text.as_str().into()),
+        ("span".to_string(), span_json(s.start, s.end, s.line, s.column)),
+        (
+            "context".to_string(),
+            Value::Object(vec![
+                ("kind".to_string(), s.kind.name().into()),
+                ("attribute".to_string(), s.kind.attribute().map(Value::from).unwrap_or(Value::Null)),
+                (
+                    "component".to_string(),
+                    s.component.as_deref().map(Value::from).unwrap_or(Value::Null),
+                ),
+            ]),
+        ),
+        ("ignored".to_string(), s.ignored.into()),
+    ])
+}
+
+fn wrap_json(wrap: &Wrap) -> Value {
+    Value::Object(vec![
+        ("key".to_string(), wrap.key.as_str().into()),
+        ("text".to_string(), wrap.text.as_str().into()),
+        ("replacement".to_string(), wrap.replacement.as_str().into()),
+        ("span".to_string(), span_json(wrap.start, wrap.end, wrap.line, wrap.column)),
+    ])
+}
+
+// 바이트 오프셋 + 1부터 시작하는 줄/열
+fn span_json(start: usize, end: usize, line: usize, column: usize) -> Value {
+    Value::Object(vec![
+        ("start".to_string(), start.into()),
+        ("end".to_string(), end.into()),
+        ("line".to_string(), line.into()),
+        ("column".to_string(), column.into()),
+    ])
+}
+
+pub fn diagnostic_json(diagnostic: &Diagnostic) -> Value {
+    let (line, column) = diagnostic.location().unwrap_or((0, 0));
+    Value::Object(vec![
+        ("path".to_string(), diagnostic.path.to_string_lossy().as_ref().into()),
+        ("code".to_string(), diagnostic.code.as_str().into()),
+        ("severity".to_string(), diagnostic.severity.as_str().into()),
+        ("message".to_string(), diagnostic.message.as_str().into()),
+        ("line".to_string(), line.into()),
+        ("column".to_string(), column.into()),
+    ])
+}
+
+// ============================================
+// 사람이 읽는 analyze 출력
+// ============================================
+pub fn print_text(report: &ProcessReport) {
+    for analysis in &report.analyses {
+        let active = analysis.strings.iter().filter(|s| !s.ignored).count();
+        println!(
+            "{}: 문자열 {}개, import {}개{}",
+            analysis.path,
+            active,
+            analysis.imports.len(),
+            if analysis.uses_translation { ", useTranslation 사용" } else { "" }
+        );
+        for s in &analysis.strings {
+            let component = s.component.as_deref().map(|c| format!(" ({})", c)).unwrap_or_default();
+            let ignored = if s.ignored { " [ignored]" } else { "" };
+            println!("  {}:{} {} {:?}{}{}", s.line, s.column, s.kind.name(), s.text, component, ignored);
+        }
+    }
+    let color = crate::diagnostic::use_color();
+    for diagnostic in &report.errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::FileChange;
+    use crate::transform::StringKind;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_report_schema() {
+        let analysis = FileAnalysis {
+            path: "src/App.jsx".to_string(),
+            imports: vec!["react".to_string()],
+            strings: vec![HardcodedString {
+                text: "Hello".to_string(),
+                start: 30,
+                end: 35,
+                line: 2,
+                column: 12,
+                kind: StringKind::Attribute("title".to_string()),
+                component: Some("App".to_string()),
+                ignored: false,
+                ignore_at: 25,
+            }],
+            ..Default::default()
+        };
+        let wrap = Wrap {
+            start: 30,
+            end: 35,
+            line: 2,
+            column: 12,
+            key: "app.hello".to_string(),
+            text: "Hello".to_string(),
+            replacement: "{t('app.hello')}".to_string(),
+            kind: StringKind::Attribute("title".to_string()),
+            component: Some("App".to_string()),
+            ignore_at: 25,
+        };
+        let report = ProcessReport {
+            changes: vec![FileChange {
+                path: PathBuf::from("src/App.jsx"),
+                original: String::new(),
+                updated: String::new(),
+                wraps: vec![wrap],
+            }],
+            analyses: vec![analysis],
+            new_keys: 1,
+            ..Default::default()
+        };
+
+        let json = to_json("analyze", &report);
+        assert_eq!(json.get("schemaVersion").and_then(Value::as_u64), Some(SCHEMA_VERSION as u64));
+        let file = &json.get("files").and_then(Value::as_array).unwrap()[0];
+        let string = &file.get("strings").and_then(Value::as_array).unwrap()[0];
+        let context = string.get("context").unwrap();
+        assert_eq!(context.get("attribute").and_then(Value::as_str), Some("title"));
+        assert_eq!(context.get("component").and_then(Value::as_str), Some("App"));
+        assert_eq!(string.get("span").and_then(|s| s.get("column")).and_then(Value::as_u64), Some(12));
+        let wrap = &file.get("wraps").and_then(Value::as_array).unwrap()[0];
+        assert_eq!(wrap.get("key").and_then(Value::as_str), Some("app.hello"));
+        assert_eq!(
+            json.get("summary").and_then(|s| s.get("stringsWrapped")).and_then(Value::as_u64),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,358 @@
+// ============================================
+// i18n-wrapper: process --interactive (변경 하나씩 검토)
+// cli_examples.rs의 input_reading_example처럼 stdin에서 한 줄씩 읽음
+// 결정은 .i18n-wrapper/decisions.json 에 저장해서 다시 묻지 않음
+// 수락한 키는 바로 카탈로그에 넣어서 같은 파일의 다음 변경과도 겹치지 않게 함
+// ============================================
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::catalog::Catalog;
+use crate::json::{self, Value};
+use crate::transform::{self, Wrap};
+
+pub const DECISIONS_PATH: &str = ".i18n-wrapper/decisions.json";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Decision {
+    // 수락 (수정된 키 포함)
+    Accept(String),
+    Skip,
+    // 래핑 대신 ignore 주석 삽입
+    Ignore,
+}
+
+pub struct Decisions {
+    path: PathBuf,
+    entries: Vec<(String, Decision)>,
+}
+
+impl Decisions {
+    // 파일이 없거나 깨져 있으면 빈 상태로 시작
+    pub fn load(path: &Path) -> Self {
+        let mut entries = Vec::new();
+        let parsed = fs::read_to_string(path).ok().and_then(|text| json::parse(&text).ok());
+        if let Some(Value::Object(items)) = parsed {
+            for (fingerprint, value) in items {
+                let decision = match value.get("action").and_then(Value::as_str) {
+                    Some("accept") => match value.get("key").and_then(Value::as_str) {
+                        Some(key) => Decision::Accept(key.to_string()),
+                        None => continue,
+                    },
+                    Some("skip") => Decision::Skip,
+                    Some("ignore") => Decision::Ignore,
+                    _ => continue,
+                };
+                entries.push((fingerprint, decision));
+            }
+        }
+        Self {
+            path: path.to_path_buf(),
+            entries,
+        }
+    }
+
+    pub fn get(&self, fingerprint: &str) -> Option<&Decision> {
+        self.entries.iter().find(|(fp, _)| fp == fingerprint).map(|(_, d)| d)
+    }
+
+    pub fn record(&mut self, fingerprint: String, decision: Decision) {
+        match self.entries.iter_mut().find(|(fp, _)| *fp == fingerprint) {
+            Some((_, existing)) => *existing = decision,
+            None => self.entries.push((fingerprint, decision)),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let items = self
+            .entries
+            .iter()
+            .map(|(fingerprint, decision)| {
+                let value = match decision {
+                    Decision::Accept(key) => Value::Object(vec![
+                        ("action".to_string(), "accept".into()),
+                        ("key".to_string(), key.as_str().into()),
+                    ]),
+                    Decision::Skip => Value::Object(vec![("action".to_string(), "skip".into())]),
+                    Decision::Ignore => Value::Object(vec![("action".to_string(), "ignore".into())]),
+                };
+                (fingerprint.clone(), value)
+            })
+            .collect();
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, Value::Object(items).to_pretty_string("  ") + "\n")
+    }
+}
+
+// 줄 번호가 바뀌어도 같은 결정을 찾을 수 있도록 파일/컴포넌트/문구로만 구성
+pub fn fingerprint(path: &str, wrap: &Wrap) -> String {
+    let component = wrap.component.as_deref().unwrap_or("");
+    format!("{:016x}", transform::text_hash(&format!("{}\0{}\0{}", path, component, wrap.text)))
+}
+
+#[derive(Default)]
+pub struct ReviewOutcome {
+    pub accepted: Vec<Wrap>,
+    pub ignored: Vec<Wrap>,
+    // 사용자가 q로 종료함 (남은 파일은 건드리지 않음)
+    pub quit: bool,
+}
+
+enum Answer {
+    Accept,
+    Skip,
+    Edit,
+    Ignore,
+    AcceptAll,
+    Quit,
+}
+
+// ============================================
+// 한 파일의 제안된 래핑을 순서대로 검토
+// 수락한 래핑의 키는 catalog에 추가 (넣을 수 없는 키면 알리고 다시 물어봄)
+// ============================================
+pub fn review_file<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    path: &str,
+    code: &str,
+    wraps: Vec<Wrap>,
+    catalog: &mut Catalog,
+    decisions: &mut Decisions,
+) -> io::Result<ReviewOutcome> {
+    let mut outcome = ReviewOutcome::default();
+    let mut accept_all = false;
+
+    for mut wrap in wraps {
+        let fp = fingerprint(path, &wrap);
+
+        // 이전 실행에서 내린 결정이 있으면 그대로 적용
+        // 기억한 키를 이제 다른 문구가 쓰고 있으면 새로 물어봄
+        match decisions.get(&fp).cloned() {
+            Some(Decision::Accept(key)) => {
+                let mut remembered = wrap.clone();
+                set_key(&mut remembered, key);
+                match insert_key(catalog, &remembered) {
+                    Ok(()) => {
+                        outcome.accepted.push(remembered);
+                        continue;
+                    }
+                    Err(message) => writeln!(output, "\n  기억한 키를 쓸 수 없습니다: {}", message)?,
+                }
+            }
+            Some(Decision::Skip) => continue,
+            Some(Decision::Ignore) => {
+                outcome.ignored.push(wrap);
+                continue;
+            }
+            None => {}
+        }
+
+        if outcome.quit {
+            continue;
+        }
+        if accept_all && insert_key(catalog, &wrap).is_ok() {
+            decisions.record(fp, Decision::Accept(wrap.key.clone()));
+            outcome.accepted.push(wrap);
+            continue;
+        }
+
+        show_change(output, path, code, &wrap)?;
+        loop {
+            let answer = ask(input, output)?;
+            match answer {
+                Answer::Accept | Answer::Edit | Answer::AcceptAll => {
+                    if let Answer::Edit = answer {
+                        let key = ask_key(input, output)?;
+                        set_key(&mut wrap, key);
+                    }
+                    if let Err(message) = insert_key(catalog, &wrap) {
+                        writeln!(output, "  {}", message)?;
+                        continue;
+                    }
+                    accept_all |= matches!(answer, Answer::AcceptAll);
+                    decisions.record(fp, Decision::Accept(wrap.key.clone()));
+                    outcome.accepted.push(wrap);
+                }
+                Answer::Skip => decisions.record(fp, Decision::Skip),
+                Answer::Ignore => {
+                    decisions.record(fp, Decision::Ignore);
+                    outcome.ignored.push(wrap);
+                }
+                Answer::Quit => outcome.quit = true,
+            }
+            break;
+        }
+    }
+    Ok(outcome)
+}
+
+// 수락한 키를 카탈로그에 추가. 같은 키에 같은 문구가 이미 있으면 그대로 사용
+fn insert_key(catalog: &mut Catalog, wrap: &Wrap) -> Result<(), String> {
+    match catalog.insert_default(&wrap.key, &wrap.text) {
+        Ok(true) => Ok(()),
+        Ok(false) => match catalog.get(&wrap.key) {
+            Some(existing) if existing != wrap.text => {
+                Err(format!("이미 다른 문구에 사용 중인 키입니다: {} = \"{}\"", wrap.key, existing))
+            }
+            _ => Ok(()),
+        },
+        Err(conflict) => Err(conflict.to_string()),
+    }
+}
+
+fn set_key(wrap: &mut Wrap, key: String) {
+    wrap.replacement = transform::replacement_for(&wrap.kind, &key);
+    wrap.key = key;
+}
+
+// 소스 줄 + 캐럿 + 제안 키 표시
+fn show_change<W: Write>(output: &mut W, path: &str, code: &str, wrap: &Wrap) -> io::Result<()> {
+    let line_text = code.lines().nth(wrap.line - 1).unwrap_or("");
+    let width = code[wrap.start..wrap.end]
+        .lines()
+        .next()
+        .map(|first| first.chars().count())
+        .unwrap_or(1)
+        .max(1);
+    let gutter = wrap.line.to_string().len();
+
+    writeln!(output)?;
+    match &wrap.component {
+        Some(component) => writeln!(output, "{}:{}:{} ({})", path, wrap.line, wrap.column, component)?,
+        None => writeln!(output, "{}:{}:{}", path, wrap.line, wrap.column)?,
+    }
+    writeln!(output, "{} | {}", wrap.line, line_text)?;
+    writeln!(
+        output,
+        "{} | {}{}",
+        " ".repeat(gutter),
+        " ".repeat(wrap.column - 1),
+        "^".repeat(width)
+    )?;
+    writeln!(output, "  제안 키: {}", wrap.key)?;
+    Ok(())
+}
+
+fn read_answer<R: BufRead, W: Write>(input: &mut R, output: &mut W, prompt: &str) -> io::Result<Option<String>> {
+    write!(output, "{}", prompt)?;
+    output.flush()?;
+    let mut line = String::new();
+    // EOF (Ctrl-D)
+    if input.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim().to_string()))
+}
+
+fn ask<R: BufRead, W: Write>(input: &mut R, output: &mut W) -> io::Result<Answer> {
+    loop {
+        let prompt = "  [a] 수락  [s] 건너뛰기  [e] 키 수정  [i] 무시 주석  [A] 이 파일 모두 수락  [q] 종료 > ";
+        let answer = match read_answer(input, output, prompt)? {
+            Some(answer) => answer,
+            None => return Ok(Answer::Quit),
+        };
+        match answer.as_str() {
+            "a" | "" => return Ok(Answer::Accept),
+            "s" => return Ok(Answer::Skip),
+            "e" => return Ok(Answer::Edit),
+            "i" => return Ok(Answer::Ignore),
+            "A" => return Ok(Answer::AcceptAll),
+            "q" => return Ok(Answer::Quit),
+            _ => writeln!(output, "  알 수 없는 입력: {}", answer)?,
+        }
+    }
+}
+
+// 형식만 확인 (카탈로그와 겹치는지는 insert_key가 확인)
+fn ask_key<R: BufRead, W: Write>(input: &mut R, output: &mut W) -> io::Result<String> {
+    loop {
+        let key = match read_answer(input, output, "  새 키: ")? {
+            Some(key) => key,
+            None => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "입력이 끝났습니다")),
+        };
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')) {
+            writeln!(output, "  키는 영문/숫자/._- 만 사용할 수 있습니다")?;
+            continue;
+        }
+        return Ok(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transform::StringKind;
+
+    fn wrap(text: &str, key: &str) -> Wrap {
+        Wrap {
+            start: 4,
+            end: 4 + text.len(),
+            line: 1,
+            column: 5,
+            key: key.to_string(),
+            text: text.to_string(),
+            replacement: transform::replacement_for(&StringKind::Text, key),
+            kind: StringKind::Text,
+            component: Some("App".to_string()),
+            ignore_at: 4,
+        }
+    }
+
+    #[test]
+    fn test_review_answers_are_remembered() {
+        let code = "<p>Hello</p><p>Bye</p>";
+        let mut catalog = Catalog::empty(Path::new("en.json"));
+        let mut decisions = Decisions::load(Path::new("/nonexistent/decisions.json"));
+        let wraps = vec![wrap("Hello", "app.hello"), wrap("Bye", "app.bye")];
+
+        let mut input = io::Cursor::new("e\napp.greeting\ni\n");
+        let mut output = Vec::new();
+        let outcome =
+            review_file(&mut input, &mut output, "App.jsx", code, wraps.clone(), &mut catalog, &mut decisions).unwrap();
+        assert_eq!(outcome.accepted.len(), 1);
+        assert_eq!(outcome.accepted[0].replacement, "{t('app.greeting')}");
+        assert_eq!(outcome.ignored.len(), 1);
+        assert_eq!(catalog.get("app.greeting"), Some("Hello"));
+
+        // 두 번째 실행은 입력 없이 같은 결과
+        let mut empty = io::Cursor::new("");
+        let mut catalog = Catalog::empty(Path::new("en.json"));
+        let outcome = review_file(&mut empty, &mut output, "App.jsx", code, wraps, &mut catalog, &mut decisions).unwrap();
+        assert_eq!(outcome.accepted[0].key, "app.greeting");
+        assert_eq!(outcome.ignored.len(), 1);
+        assert!(!outcome.quit);
+    }
+
+    #[test]
+    fn test_taken_keys_are_asked_again() {
+        let code = "<p>Hello</p><p>Bye</p>";
+        let mut catalog = Catalog::empty(Path::new("en.json"));
+        let mut decisions = Decisions::load(Path::new("/nonexistent/decisions.json"));
+        decisions.record(fingerprint("App.jsx", &wrap("Bye", "app.bye")), Decision::Accept("app.hello".to_string()));
+        let wraps = vec![wrap("Hello", "app.hello"), wrap("Bye", "app.bye")];
+
+        // Hello는 app.greeting으로 수정, 기억한 Bye의 키(app.hello)는 비어 있으므로 그대로 적용
+        let mut input = io::Cursor::new("e\napp.greeting\n");
+        let mut output = Vec::new();
+        let outcome =
+            review_file(&mut input, &mut output, "App.jsx", code, wraps.clone(), &mut catalog, &mut decisions).unwrap();
+        assert_eq!(outcome.accepted.iter().map(|w| w.key.as_str()).collect::<Vec<_>>(), ["app.greeting", "app.hello"]);
+
+        // 다음 실행에서 app.hello가 다른 문구에 쓰이고 있으면 기억한 결정 대신 다시 물어봄
+        let mut catalog = Catalog::empty(Path::new("en.json"));
+        catalog.insert("app.hello", "Hi there").unwrap();
+        let mut input = io::Cursor::new("e\napp.greeting\ne\napp.bye\n");
+        let mut output = Vec::new();
+        let outcome = review_file(&mut input, &mut output, "App.jsx", code, wraps, &mut catalog, &mut decisions).unwrap();
+        let printed = String::from_utf8(output).unwrap();
+        assert!(printed.contains("기억한 키를 쓸 수 없습니다"), "{}", printed);
+        assert!(printed.contains("이미 다른 문구에 사용 중인 키입니다: app.greeting"), "{}", printed);
+        assert_eq!(outcome.accepted.iter().map(|w| w.key.as_str()).collect::<Vec<_>>(), ["app.greeting", "app.bye"]);
+        assert_eq!(decisions.get(&fingerprint("App.jsx", &wrap("Bye", "x"))), Some(&Decision::Accept("app.bye".to_string())));
+    }
+}
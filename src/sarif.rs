@@ -0,0 +1,160 @@
+// ============================================
+// i18n-wrapper: SARIF 2.1.0 출력 (analyze --format sarif)
+// GitHub code scanning 등 CI가 읽는 정적 분석 결과 형식
+// 스키마를 내려받지 않고 json::Value로 직접 만들어서 오프라인에서도 생성 가능
+//
+// { "version": "2.1.0", "runs": [{
+//     "tool": { "driver": { "name", "version", "rules": [...] } },
+//     "results": [{ "ruleId", "level", "message", "locations": [{ "physicalLocation": {...} }] }]
+// }] }
+// ============================================
+
+use std::path::Path;
+
+use crate::cache::TOOL_VERSION;
+use crate::config::RuleLevel;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::json::Value;
+use crate::lint::RULES;
+use crate::report::TOOL_NAME;
+
+pub const SARIF_VERSION: &str = "2.1.0";
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const FINGERPRINT_KEY: &str = "i18nWrapper/v1";
+
+pub fn to_sarif(diagnostics: &[Diagnostic]) -> Value {
+    let rules = RULES
+        .iter()
+        .map(|rule| {
+            let level = match rule.level {
+                RuleLevel::Off => "none",
+                RuleLevel::Warn => "warning",
+                RuleLevel::Error => "error",
+            };
+            Value::Object(vec![
+                ("id".to_string(), rule.id.into()),
+                ("name".to_string(), rule.name.into()),
+                ("shortDescription".to_string(), text(rule.description)),
+                ("help".to_string(), text(rule.help)),
+                (
+                    "defaultConfiguration".to_string(),
+                    Value::Object(vec![("level".to_string(), level.into())]),
+                ),
+            ])
+        })
+        .collect();
+    let driver = Value::Object(vec![
+        ("name".to_string(), TOOL_NAME.into()),
+        ("version".to_string(), TOOL_VERSION.into()),
+        ("rules".to_string(), Value::Array(rules)),
+    ]);
+
+    let run = Value::Object(vec![
+        ("tool".to_string(), Value::Object(vec![("driver".to_string(), driver)])),
+        // region의 열은 문자 단위 (transform::line_col과 같음)
+        ("columnKind".to_string(), "unicodeCodePoints".into()),
+        (
+            "results".to_string(),
+            Value::Array(diagnostics.iter().map(result).collect()),
+        ),
+    ]);
+    Value::Object(vec![
+        ("$schema".to_string(), SARIF_SCHEMA.into()),
+        ("version".to_string(), SARIF_VERSION.into()),
+        ("runs".to_string(), Value::Array(vec![run])),
+    ])
+}
+
+fn result(diagnostic: &Diagnostic) -> Value {
+    let level = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    let mut physical = vec![(
+        "artifactLocation".to_string(),
+        Value::Object(vec![("uri".to_string(), uri(&diagnostic.path).into())]),
+    )];
+    if let (Some((start_line, start_column)), Some((end_line, end_column))) =
+        (diagnostic.location(), diagnostic.end_location())
+    {
+        physical.push((
+            "region".to_string(),
+            Value::Object(vec![
+                ("startLine".to_string(), start_line.into()),
+                ("startColumn".to_string(), start_column.into()),
+                ("endLine".to_string(), end_line.into()),
+                ("endColumn".to_string(), end_column.into()),
+            ]),
+        ));
+    }
+
+    let mut message = diagnostic.message.clone();
+    for help in &diagnostic.help {
+        message.push_str(&format!("\nhelp: {}", help));
+    }
+    let mut fields = vec![("ruleId".to_string(), diagnostic.code.as_str().into())];
+    if let Some(index) = RULES.iter().position(|rule| rule.id == diagnostic.code) {
+        fields.push(("ruleIndex".to_string(), index.into()));
+    }
+    fields.extend([
+        ("level".to_string(), level.into()),
+        ("message".to_string(), text(&message)),
+        (
+            "locations".to_string(),
+            Value::Array(vec![Value::Object(vec![(
+                "physicalLocation".to_string(),
+                Value::Object(physical),
+            )])]),
+        ),
+    ]);
+    // 줄이 밀려도 같은 결과로 추적되도록 (baseline과 같은 값)
+    if let Some(fingerprint) = &diagnostic.fingerprint {
+        fields.push((
+            "partialFingerprints".to_string(),
+            Value::Object(vec![(FINGERPRINT_KEY.to_string(), fingerprint.as_str().into())]),
+        ));
+    }
+    Value::Object(fields)
+}
+
+fn text(message: &str) -> Value {
+    Value::Object(vec![("text".to_string(), message.into())])
+}
+
+// SARIF uri는 "/" 구분자, 상대 경로는 저장소 루트 기준으로 해석됨
+fn uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    path.strip_prefix("./").map(String::from).unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_sarif_result_has_rule_and_region() {
+        let source: Arc<str> = Arc::from("const A = () => (\n  <p>Hello</p>\n);\n");
+        let start = source.find("Hello").unwrap();
+        let finding = Diagnostic::error(diagnostic::HARDCODED_STRING, "번역되지 않은 문자열", Path::new("./src/A.jsx"))
+            .with_source(source)
+            .with_primary(start, start + 5, "");
+
+        let sarif = to_sarif(&[finding]);
+        assert_eq!(sarif.get("version").and_then(Value::as_str), Some("2.1.0"));
+        let run = &sarif.get("runs").and_then(Value::as_array).unwrap()[0];
+        let rules = run.get("tool").and_then(|t| t.get("driver")).and_then(|d| d.get("rules")).unwrap();
+        assert_eq!(rules.as_array().unwrap().len(), RULES.len());
+
+        let result = &run.get("results").and_then(Value::as_array).unwrap()[0];
+        assert_eq!(result.get("ruleId").and_then(Value::as_str), Some("I18N100"));
+        let location = &result.get("locations").and_then(Value::as_array).unwrap()[0];
+        let physical = location.get("physicalLocation").unwrap();
+        let uri = physical.get("artifactLocation").and_then(|a| a.get("uri")).and_then(Value::as_str);
+        assert_eq!(uri, Some("src/A.jsx"));
+        let region = physical.get("region").unwrap();
+        let field = |key: &str| region.get(key).and_then(Value::as_u64);
+        assert_eq!((field("startLine"), field("startColumn"), field("endColumn")), (Some(2), Some(6), Some(11)));
+    }
+}
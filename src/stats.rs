@@ -0,0 +1,343 @@
+// ============================================
+// i18n-wrapper: 번역 커버리지 통계 (stats)
+// "checkout 화면은 얼마나 번역됐나?"에 답하기 위한 집계
+//   번역됨:     t('key') / <Trans i18nKey> 참조 (extract::scan_references)
+//   하드코딩:   아직 감싸지 않은 문자열 (i18n-ignore 된 문자열은 따로 셈)
+// 디렉토리 / 파일 / React 컴포넌트 단위로 모아서 트리, JSON, CSV로 출력
+//
+// 집계는 collections::entry_api_example의 단어 세기와 같은 패턴:
+//   *map.entry(key).or_default() += ...
+// TypeScript로 치면 map.set(key, (map.get(key) ?? 0) + 1)
+// ============================================
+
+use std::collections::BTreeMap;
+use std::ops::AddAssign;
+
+use crate::extract::KeyReference;
+use crate::json::Value;
+use crate::report::SCHEMA_VERSION;
+use crate::transform::FileAnalysis;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatsFormat {
+    Text,
+    Json,
+    // 스프레드시트용 (한 줄에 한 집계 단위)
+    Csv,
+}
+
+impl StatsFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(StatsFormat::Text),
+            "json" => Some(StatsFormat::Json),
+            "csv" => Some(StatsFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Counts {
+    pub translated: usize,
+    pub hardcoded: usize,
+    // 의도적으로 번역하지 않는 문자열, 비율 계산에서 제외
+    pub ignored: usize,
+}
+
+impl Counts {
+    // 번역 대상 전체 (translated + hardcoded)
+    pub fn total(&self) -> usize {
+        self.translated + self.hardcoded
+    }
+
+    // 0~100, 번역 대상이 없으면 None
+    pub fn coverage(&self) -> Option<f64> {
+        match self.total() {
+            0 => None,
+            total => Some(self.translated as f64 * 100.0 / total as f64),
+        }
+    }
+}
+
+impl AddAssign for Counts {
+    fn add_assign(&mut self, other: Counts) {
+        self.translated += other.translated;
+        self.hardcoded += other.hardcoded;
+        self.ignored += other.ignored;
+    }
+}
+
+// 경로는 모두 프로젝트 루트 기준 "/" 구분자 (lint::project_path)
+#[derive(Debug, Default)]
+pub struct Stats {
+    pub total: Counts,
+    // "src", "src/checkout" 처럼 조상 디렉토리마다 누적
+    pub directories: BTreeMap<String, Counts>,
+    pub files: BTreeMap<String, Counts>,
+    // (파일, 컴포넌트) — 다른 파일의 같은 이름 컴포넌트는 따로 셈
+    pub components: BTreeMap<(String, String), Counts>,
+}
+
+impl Stats {
+    fn add(&mut self, file: &str, component: Option<&str>, counts: Counts) {
+        self.total += counts;
+        *self.files.entry(file.to_string()).or_default() += counts;
+        if let Some(component) = component {
+            *self.components.entry((file.to_string(), component.to_string())).or_default() += counts;
+        }
+        let mut dir = parent(file);
+        while !dir.is_empty() {
+            *self.directories.entry(dir.to_string()).or_default() += counts;
+            dir = parent(dir);
+        }
+    }
+}
+
+// "src/checkout/Form.tsx" -> "src/checkout", 최상위는 ""
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map(|(parent, _)| parent).unwrap_or("")
+}
+
+fn name(path: &str) -> &str {
+    path.rsplit_once('/').map(|(_, name)| name).unwrap_or(path)
+}
+
+// path: 분석 결과의 경로를 표시용 경로로 바꾸는 함수 (보통 lint::project_path)
+pub fn collect(analyses: &[FileAnalysis], references: &[KeyReference], path: impl Fn(&str) -> String) -> Stats {
+    let mut stats = Stats::default();
+    for analysis in analyses {
+        let file = path(&analysis.path);
+        // 문자열이 하나도 없는 파일도 목록에는 나오도록
+        stats.files.entry(file.clone()).or_default();
+        for s in &analysis.strings {
+            let counts = if s.ignored {
+                Counts { ignored: 1, ..Default::default() }
+            } else {
+                Counts { hardcoded: 1, ..Default::default() }
+            };
+            stats.add(&file, s.component.as_deref(), counts);
+        }
+    }
+    for reference in references {
+        let counts = Counts { translated: 1, ..Default::default() };
+        stats.add(&path(&reference.path), reference.component.as_deref(), counts);
+    }
+    stats
+}
+
+fn percent(counts: &Counts) -> String {
+    counts.coverage().map(|c| format!("{:.1}%", c)).unwrap_or_else(|| "-".to_string())
+}
+
+// ============================================
+// 트리 출력
+//   ./                       번역  50 / 70   71.4%
+//     src/                   번역  45 / 60   75.0%
+//       checkout/            번역  10 / 20   50.0%
+//         Form.tsx           번역  10 / 20   50.0%
+//           CheckoutForm     번역   8 / 15   53.3%
+// ============================================
+const NAME_WIDTH: usize = 40;
+
+pub fn print_tree(stats: &Stats) {
+    // 프로젝트 루트 (전체 합계)
+    print_row(0, "./", &stats.total);
+    print_dir(stats, "", 1);
+}
+
+fn print_dir(stats: &Stats, dir: &str, depth: usize) {
+    for (path, counts) in stats.directories.iter().filter(|(path, _)| parent(path) == dir) {
+        print_row(depth, &format!("{}/", name(path)), counts);
+        print_dir(stats, path, depth + 1);
+    }
+    for (file, counts) in stats.files.iter().filter(|(file, _)| parent(file) == dir) {
+        print_row(depth, name(file), counts);
+        for ((_, component), counts) in stats.components.iter().filter(|((f, _), _)| f == file) {
+            print_row(depth + 1, component, counts);
+        }
+    }
+}
+
+fn print_row(depth: usize, label: &str, counts: &Counts) {
+    let indent = "  ".repeat(depth);
+    let width = NAME_WIDTH.saturating_sub(indent.len());
+    let ignored = if counts.ignored > 0 {
+        format!("  (무시 {})", counts.ignored)
+    } else {
+        String::new()
+    };
+    println!(
+        "{}{:<width$} 번역 {:>5} / {:<5} {:>7}{}",
+        indent,
+        label,
+        counts.translated,
+        counts.total(),
+        percent(counts),
+        ignored,
+        width = width
+    );
+}
+
+// ============================================
+// JSON
+// { "schemaVersion", "command": "stats", "total": {...},
+//   "directories": [{ "path", ...counts }], "files": [...], "components": [{ "path", "component", ... }] }
+// ============================================
+pub fn to_json(stats: &Stats) -> Value {
+    let row = |mut fields: Vec<(String, Value)>, counts: &Counts| {
+        fields.extend(counts_fields(counts));
+        Value::Object(fields)
+    };
+    let directories = stats
+        .directories
+        .iter()
+        .map(|(path, counts)| row(vec![("path".to_string(), path.as_str().into())], counts))
+        .collect();
+    let files = stats
+        .files
+        .iter()
+        .map(|(path, counts)| row(vec![("path".to_string(), path.as_str().into())], counts))
+        .collect();
+    let components = stats
+        .components
+        .iter()
+        .map(|((path, component), counts)| {
+            row(
+                vec![
+                    ("path".to_string(), path.as_str().into()),
+                    ("component".to_string(), component.as_str().into()),
+                ],
+                counts,
+            )
+        })
+        .collect();
+    Value::Object(vec![
+        ("schemaVersion".to_string(), SCHEMA_VERSION.into()),
+        ("command".to_string(), "stats".into()),
+        ("total".to_string(), Value::Object(counts_fields(&stats.total))),
+        ("directories".to_string(), Value::Array(directories)),
+        ("files".to_string(), Value::Array(files)),
+        ("components".to_string(), Value::Array(components)),
+    ])
+}
+
+fn counts_fields(counts: &Counts) -> Vec<(String, Value)> {
+    // 번역 대상이 없으면 coverage는 null (0%와 구분)
+    let coverage = counts
+        .coverage()
+        .map(|c| Value::Number(format!("{:.1}", c)))
+        .unwrap_or(Value::Null);
+    vec![
+        ("translated".to_string(), counts.translated.into()),
+        ("hardcoded".to_string(), counts.hardcoded.into()),
+        ("ignored".to_string(), counts.ignored.into()),
+        ("coverage".to_string(), coverage),
+    ]
+}
+
+// ============================================
+// CSV (RFC 4180: 쉼표/따옴표/줄바꿈이 있으면 따옴표로 감싸고 "는 ""로)
+// level,path,component,translated,hardcoded,ignored,coverage
+// ============================================
+pub fn to_csv(stats: &Stats) -> String {
+    let mut out = String::from("level,path,component,translated,hardcoded,ignored,coverage\n");
+    let mut row = |level: &str, path: &str, component: &str, counts: &Counts| {
+        let coverage = counts.coverage().map(|c| format!("{:.1}", c)).unwrap_or_default();
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            level,
+            csv_field(path),
+            csv_field(component),
+            counts.translated,
+            counts.hardcoded,
+            counts.ignored,
+            coverage
+        ));
+    };
+    row("total", "", "", &stats.total);
+    for (path, counts) in &stats.directories {
+        row("directory", path, "", counts);
+    }
+    for (path, counts) in &stats.files {
+        row("file", path, "", counts);
+    }
+    for ((path, component), counts) in &stats.components {
+        row("component", path, component, counts);
+    }
+    out
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil;
+    use crate::transform::{HardcodedString, StringKind};
+
+    fn string(text: &str, component: &str, ignored: bool) -> HardcodedString {
+        HardcodedString {
+            text: text.to_string(),
+            start: 0,
+            end: 0,
+            line: 1,
+            column: 1,
+            kind: StringKind::Text,
+            component: Some(component.to_string()),
+            ignored,
+            ignore_at: 0,
+        }
+    }
+
+    fn reference(path: &str, component: &str) -> KeyReference {
+        KeyReference {
+            path: path.to_string(),
+            component: Some(component.to_string()),
+            ..testutil::reference("k")
+        }
+    }
+
+    #[test]
+    fn test_collect_aggregates_directories_files_and_components() {
+        let analyses = vec![
+            FileAnalysis {
+                path: "src/checkout/Form.tsx".to_string(),
+                strings: vec![string("Pay", "CheckoutForm", false), string("SKU", "CheckoutForm", true)],
+                ..Default::default()
+            },
+            FileAnalysis {
+                path: "src/App.tsx".to_string(),
+                strings: vec![string("Hi, \"you\"", "App", false)],
+                ..Default::default()
+            },
+        ];
+        let references = vec![
+            reference("src/checkout/Form.tsx", "CheckoutForm"),
+            reference("src/checkout/Form.tsx", "CheckoutForm"),
+            reference("src/checkout/Form.tsx", "Summary"),
+        ];
+        let stats = collect(&analyses, &references, |path| path.to_string());
+
+        assert_eq!(stats.total, Counts { translated: 3, hardcoded: 2, ignored: 1 });
+        let checkout = stats.directories["src/checkout"];
+        assert_eq!((checkout.translated, checkout.total()), (3, 4));
+        assert_eq!(checkout.coverage(), Some(75.0));
+        assert_eq!(stats.directories["src"].total(), 5);
+        let form = &stats.components[&("src/checkout/Form.tsx".to_string(), "CheckoutForm".to_string())];
+        assert_eq!((form.translated, form.hardcoded, form.ignored), (2, 1, 1));
+        assert_eq!(stats.files["src/App.tsx"].coverage(), Some(0.0));
+
+        let csv = to_csv(&stats);
+        assert!(csv.contains("directory,src/checkout,,3,1,1,75.0\n"));
+        let json = to_json(&stats);
+        let total = json.get("total").unwrap();
+        assert_eq!(total.get("coverage").map(|v| v.to_compact_string()), Some("60.0".to_string()));
+    }
+}
@@ -0,0 +1,260 @@
+// ============================================
+// i18n-wrapper: sync 명령 (모든 로케일의 키 집합을 원문 로케일에 맞춤)
+//   원문에만 있는 키  -> 대상 로케일에 자리 채움 값으로 추가 (--fill)
+//   대상에만 있는 키  -> 고아 키, 지우거나(--orphans remove) 목록만 보고(flag)
+// 기존 항목의 순서와 들여쓰기는 그대로 두고,
+// 새 키는 (평평한 카탈로그라면) 원문에서 바로 앞에 있던 키 뒤에 끼워 넣음
+// TypeScript:
+// for (const key of Object.keys(en)) if (!(key in ko)) ko[key] = "";
+// ============================================
+
+use std::path::{Path, PathBuf};
+
+use crate::catalog::{Catalog, KeyConflict};
+use crate::diagnostic::{self, Diagnostic};
+use crate::process::{self, FileChange, ProcessOptions};
+use crate::pseudo;
+
+// 빠진 번역의 자리 채움 값
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fill {
+    // "" (i18next는 빈 문자열도 번역으로 보므로 returnEmptyString: false와 함께 사용)
+    Empty,
+    // 원문 그대로
+    Source,
+    // 원문을 의사 번역 (화면에서 번역 안 된 곳이 눈에 띔)
+    Pseudo,
+}
+
+impl Fill {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "empty" => Some(Fill::Empty),
+            "source" => Some(Fill::Source),
+            "pseudo" => Some(Fill::Pseudo),
+            _ => None,
+        }
+    }
+
+    fn value(&self, source: &str) -> String {
+        match self {
+            Fill::Empty => String::new(),
+            Fill::Source => source.to_string(),
+            Fill::Pseudo => pseudo::pseudolocalize(source, &pseudo::PseudoOptions::default()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orphans {
+    Remove,
+    // 지우지 않고 요약에만 표시
+    Flag,
+}
+
+impl Orphans {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "remove" => Some(Orphans::Remove),
+            "flag" => Some(Orphans::Flag),
+            _ => None,
+        }
+    }
+}
+
+pub struct LocaleSync {
+    pub locale: String,
+    pub path: PathBuf,
+    pub added: Vec<String>,
+    pub orphans: Vec<String>,
+    // 중첩 카탈로그에서 기존 값과 경로가 겹쳐 추가하지 못한 키 ("cart"가 문자열인데 "cart.title")
+    pub conflicts: Vec<KeyConflict>,
+}
+
+pub struct SyncPlan {
+    pub changes: Vec<FileChange>,
+    pub locales: Vec<LocaleSync>,
+}
+
+// targets: 원문 로케일을 뺀 (로케일, 카탈로그 경로)
+pub fn plan_sync(
+    source: &Path,
+    targets: &[(String, PathBuf)],
+    fill: Fill,
+    orphans: Orphans,
+) -> Result<SyncPlan, Box<Diagnostic>> {
+    let source_entries = Catalog::load_diagnostic(source)?.entries();
+
+    let mut changes = Vec::new();
+    let mut locales = Vec::new();
+    for (locale, path) in targets {
+        let mut catalog = Catalog::load_diagnostic(path)?;
+        let before = catalog.render();
+
+        // 고아 키를 먼저 지워야 원문 구조가 바뀐 경우("cart" -> "cart.title")에 새 키가 들어갈 자리가 생김
+        let found: Vec<String> = catalog
+            .keys()
+            .into_iter()
+            .filter(|key| !source_entries.iter().any(|(k, _)| k == key))
+            .collect();
+        if orphans == Orphans::Remove {
+            for key in &found {
+                catalog.remove(key);
+            }
+        }
+
+        let mut added = Vec::new();
+        let mut conflicts = Vec::new();
+        let mut previous: Option<&str> = None;
+        for (key, text) in &source_entries {
+            if !catalog.contains(key) {
+                match catalog.insert_after(key, &fill.value(text), previous) {
+                    Ok(()) => added.push(key.clone()),
+                    Err(conflict) => conflicts.push(conflict),
+                }
+            }
+            previous = Some(key);
+        }
+
+        let after = catalog.render();
+        if after != before {
+            changes.push(FileChange {
+                path: path.clone(),
+                original: before,
+                updated: after,
+                wraps: Vec::new(),
+            });
+        }
+        locales.push(LocaleSync {
+            locale: locale.clone(),
+            path: path.clone(),
+            added,
+            orphans: found,
+            conflicts,
+        });
+    }
+    Ok(SyncPlan { changes, locales })
+}
+
+// ============================================
+// CLI 진입점: 계획 → process::apply_changes → 로케일별 요약
+// ============================================
+pub fn run_sync(options: &ProcessOptions, targets: &[(String, PathBuf)], fill: Fill, orphans: Orphans) -> Vec<Diagnostic> {
+    let plan = match plan_sync(&options.catalog_path, targets, fill, orphans) {
+        Ok(plan) => plan,
+        Err(diagnostic) => {
+            eprintln!("{}", diagnostic.render(diagnostic::use_color()));
+            return vec![*diagnostic];
+        }
+    };
+
+    let mut errors = process::apply_changes(options, &plan.changes);
+    println!();
+    println!("요약:");
+    for locale in &plan.locales {
+        let removed = if orphans == Orphans::Remove { "삭제" } else { "원문에 없음" };
+        println!("  {}: 추가 {}개, {} {}개", locale.locale, locale.added.len(), removed, locale.orphans.len());
+        for key in &locale.added {
+            println!("    + {}", key);
+        }
+        for key in &locale.orphans {
+            let mark = if orphans == Orphans::Remove { '-' } else { '!' };
+            println!("    {} {}", mark, key);
+        }
+        // 덮어쓰면 기존 번역이 사라지므로 추가하지 않고 에러로 보고
+        for conflict in &locale.conflicts {
+            println!("    x {} (겹치는 키: {})", conflict.key, conflict.existing);
+            errors.push(Diagnostic::error(diagnostic::CATALOG_ERROR, conflict.to_string(), &locale.path));
+        }
+    }
+    if orphans == Orphans::Flag && plan.locales.iter().any(|l| !l.orphans.is_empty()) {
+        println!("  (--orphans remove 로 원문에 없는 키를 지울 수 있습니다)");
+    }
+    process::print_dry_run_notice(options);
+    let color = diagnostic::use_color();
+    for diagnostic in &errors {
+        eprint!("\n{}", diagnostic.render(color));
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+    use std::fs;
+
+    #[test]
+    fn test_sync_fills_in_source_order_and_keeps_indent() {
+        let dir = TempDir::new("sync");
+        fs::write(dir.join("en.json"), "{\n  \"a\": \"Save\",\n  \"b\": \"Hi {{name}}\",\n  \"c\": \"Bye\"\n}\n").unwrap();
+        fs::write(dir.join("ko.json"), "{\n    \"c\": \"잘 가\",\n    \"a\": \"저장\",\n    \"old\": \"옛\"\n}").unwrap();
+        let targets = vec![("ko".to_string(), dir.join("ko.json"))];
+
+        let plan = plan_sync(&dir.join("en.json"), &targets, Fill::Pseudo, Orphans::Flag).unwrap();
+        assert_eq!(plan.locales[0].added, vec!["b"]);
+        assert_eq!(plan.locales[0].orphans, vec!["old"]);
+        // 기존 순서와 4칸 들여쓰기 유지, b는 원문에서 앞에 있던 a 뒤로
+        assert_eq!(
+            plan.changes[0].updated,
+            "{\n    \"c\": \"잘 가\",\n    \"a\": \"저장\",\n    \"b\": \"[Ĥî {{name}}~]\",\n    \"old\": \"옛\"\n}"
+        );
+
+        let plan = plan_sync(&dir.join("en.json"), &targets, Fill::Empty, Orphans::Remove).unwrap();
+        assert_eq!(
+            plan.changes[0].updated,
+            "{\n    \"c\": \"잘 가\",\n    \"a\": \"저장\",\n    \"b\": \"\"\n}"
+        );
+    }
+
+    #[test]
+    fn test_sync_reports_nested_conflicts_instead_of_overwriting() {
+        let dir = TempDir::new("sync-conflict");
+        let en = dir.write("en.json", "{\n  \"cart\": {\n    \"title\": \"Cart\"\n  },\n  \"pay\": \"Pay\"\n}\n");
+        // 원문에서 "cart"가 객체로 바뀌기 전의 번역이 남아 있음
+        let ko = dir.write("ko.json", "{\n  \"cart\": \"장바구니\",\n  \"nav\": {}\n}\n");
+        let targets = vec![("ko".to_string(), ko)];
+
+        let plan = plan_sync(&en, &targets, Fill::Empty, Orphans::Flag).unwrap();
+        let ko = &plan.locales[0];
+        assert_eq!(ko.added, vec!["pay"]);
+        assert_eq!(ko.conflicts.len(), 1);
+        assert_eq!((ko.conflicts[0].key.as_str(), ko.conflicts[0].existing.as_str()), ("cart.title", "cart"));
+        assert!(plan.changes[0].updated.contains("\"cart\": \"장바구니\""));
+
+        // 고아 키를 지우면 자리가 생겨서 겹치지 않음
+        let plan = plan_sync(&en, &targets, Fill::Empty, Orphans::Remove).unwrap();
+        assert!(plan.locales[0].conflicts.is_empty());
+        assert_eq!(plan.locales[0].added, vec!["cart.title", "pay"]);
+    }
+
+    #[test]
+    fn test_sync_orphan_parent_blocks_source_key_until_removed() {
+        let dir = TempDir::new("sync-orphan");
+        // 원문에서 "cart.title"이 "cart" 하나로 합쳐짐
+        let en = dir.write("en.json", "{\n  \"cart\": \"Cart\",\n  \"pay\": \"Pay\"\n}\n");
+        let ko = dir.write("ko.json", "{\n  \"cart\": {\n    \"title\": \"장바구니\"\n  },\n  \"pay\": \"결제\"\n}\n");
+        // 아직 없는 로케일은 원문 키를 모두 채운 새 파일
+        let ja = dir.join("ja.json");
+        let targets = vec![("ko".to_string(), ko.clone()), ("ja".to_string(), ja.clone())];
+
+        let plan = plan_sync(&en, &targets, Fill::Source, Orphans::Flag).unwrap();
+        let ko_sync = &plan.locales[0];
+        assert_eq!(ko_sync.orphans, vec!["cart.title"]);
+        assert!(ko_sync.added.is_empty());
+        assert_eq!(ko_sync.conflicts.len(), 1);
+        assert_eq!(ko_sync.conflicts[0].key, "cart");
+        assert_eq!(plan.locales[1].added, vec!["cart", "pay"]);
+        // 겹친 ko는 그대로 두고 ja만 저장
+        assert_eq!(plan.changes.len(), 1);
+        assert_eq!(plan.changes[0].path, ja);
+        assert_eq!(plan.changes[0].updated, "{\n  \"cart\": \"Cart\",\n  \"pay\": \"Pay\"\n}\n");
+
+        // 고아를 지우면 비게 된 "cart" 객체도 사라지고 원문 키가 그 자리에 들어감
+        let plan = plan_sync(&en, &targets, Fill::Source, Orphans::Remove).unwrap();
+        assert!(plan.locales[0].conflicts.is_empty());
+        assert_eq!(plan.locales[0].added, vec!["cart"]);
+        assert_eq!(plan.changes[0].path, ko);
+        assert_eq!(plan.changes[0].updated, "{\n  \"cart\": \"Cart\",\n  \"pay\": \"결제\"\n}\n");
+    }
+}
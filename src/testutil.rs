@@ -0,0 +1,77 @@
+// ============================================
+// i18n-wrapper: 테스트 공용 도우미 (cfg(test)에서만 빌드)
+// 임시 디렉토리: 이름 + 프로세스 id + 순번으로 겹치지 않게 만들고, 테스트가 끝나면(drop) 지움
+// 같은 모듈의 테스트가 병렬로 돌아도 서로의 파일을 건드리지 않음
+// TypeScript:
+// const dir = fs.mkdtempSync(path.join(os.tmpdir(), "i18n-wrapper-"));
+// afterEach(() => fs.rmSync(dir, { recursive: true, force: true }));
+// ============================================
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::extract::{KeyReference, ReferenceKind};
+
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let id = NEXT.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("i18n-wrapper-{}-{}-{}", name, std::process::id(), id));
+        // 이전 실행이 중간에 죽어서 남은 디렉토리
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Self { path }
+    }
+
+    // 상위 디렉토리까지 만들고 쓴 뒤 전체 경로를 돌려줌
+    pub fn write(&self, relative: &str, content: &str) -> PathBuf {
+        let path = self.path.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+// dir.join("en.json") 처럼 Path로 그대로 사용
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// src/App.jsx 첫 줄의 t('key') 참조, 필요한 필드만 바꿔서 사용:
+// KeyReference { default: Some("Cart".to_string()), ..reference("cart") }
+pub fn reference(key: &str) -> KeyReference {
+    KeyReference {
+        key: key.to_string(),
+        default: None,
+        path: "src/App.jsx".to_string(),
+        start: 0,
+        end: 0,
+        line: 1,
+        column: 1,
+        kind: ReferenceKind::Call,
+        component: None,
+        context: None,
+        element: None,
+        attribute: None,
+        max_length: None,
+    }
+}
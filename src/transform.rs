@@ -0,0 +1,688 @@
+// ============================================
+// i18n-wrapper: 하드코딩된 문자열 찾기 & t() 래핑
+// TypeScript (Babel):
+// traverse(ast, {
+//   JSXText(path) { path.replaceWith(t.jsxExpressionContainer(callT(key))); },
+//   JSXAttribute(path) { ... }
+// });
+// SWC로 위치(span)만 수집하고, 원본 텍스트를 직접 치환해서
+// 포맷팅/주석이 그대로 유지되도록 함
+// ============================================
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::catalog::Catalog;
+use crate::diagnostic::{self, Diagnostic};
+
+// 번역 대상으로 보는 JSX 속성
+pub const USER_FACING_ATTRS: &[&str] = &[
+    "title",
+    "alt",
+    "placeholder",
+    "label",
+    "aria-label",
+    "aria-description",
+];
+
+pub const IGNORE_DIRECTIVE: &str = "i18n-ignore";
+
+const TRANSLATION_IMPORT: &str = "import { t } from 'i18next';\n";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringKind {
+    // <p>Hello</p>
+    Text,
+    // <input placeholder="Hello" />
+    Attribute(String),
+    // <input placeholder={"Hello"} />
+    AttributeExpression(String),
+    // <p>{"Hello"}</p>
+    Expression,
+}
+
+impl StringKind {
+    // 캐시/JSON 리포트에 쓰는 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            StringKind::Text => "text",
+            StringKind::Attribute(_) => "attribute",
+            StringKind::AttributeExpression(_) => "attributeExpression",
+            StringKind::Expression => "expression",
+        }
+    }
+
+    pub fn attribute(&self) -> Option<&str> {
+        match self {
+            StringKind::Attribute(name) | StringKind::AttributeExpression(name) => Some(name),
+            StringKind::Text | StringKind::Expression => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HardcodedString {
+    pub text: String,
+    // 치환할 원본 범위 (바이트 오프셋)
+    pub start: usize,
+    pub end: usize,
+    // 1부터 시작
+    pub line: usize,
+    pub column: usize,
+    pub kind: StringKind,
+    pub component: Option<String>,
+    pub ignored: bool,
+    // ignore 주석을 넣을 위치 (속성이면 속성 시작, 아니면 문자열/컨테이너 시작)
+    pub ignore_at: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FileAnalysis {
+    pub path: String,
+    pub imports: Vec<String>,
+    // imports와 같은 순서, import 경로 문자열의 원본 범위 (바이트 오프셋)
+    pub import_spans: Vec<(usize, usize)>,
+    pub uses_translation: bool,
+    pub has_t_binding: bool,
+    // 새 import를 넣을 위치 (마지막 import 바로 뒤)
+    pub import_anchor: usize,
+    pub strings: Vec<HardcodedString>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Wrap {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    pub key: String,
+    pub text: String,
+    pub replacement: String,
+    pub kind: StringKind,
+    pub component: Option<String>,
+    pub ignore_at: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyStrategy {
+    // checkout_form.place_order
+    Slug,
+    // checkout_form.1a2b3c4d
+    Hash,
+}
+
+#[derive(Debug)]
+pub enum TransformError {
+    Parse {
+        path: String,
+        line: usize,
+        column: usize,
+        // 원본 소스 기준 바이트 오프셋
+        start: usize,
+        end: usize,
+        message: String,
+    },
+}
+
+impl TransformError {
+    pub fn to_diagnostic(&self, code: &str) -> Diagnostic {
+        match self {
+            TransformError::Parse {
+                path,
+                start,
+                end,
+                message,
+                ..
+            } => Diagnostic::error(diagnostic::PARSE_ERROR, format!("파싱 실패: {}", message), Path::new(path))
+                .with_source(Arc::from(code))
+                .with_primary(*start, *end, message.as_str())
+                .with_help("문법 오류가 있는 파일은 변환하지 않습니다"),
+        }
+    }
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransformError::Parse {
+                path,
+                line,
+                column,
+                message,
+                ..
+            } => write!(f, "{}:{}:{}: 파싱 실패: {}", path, line, column, message),
+        }
+    }
+}
+
+impl std::error::Error for TransformError {}
+
+// ============================================
+// 1단계: 분석 (SWC 파싱 + Visitor)
+// ============================================
+#[cfg(feature = "swc")]
+pub fn analyze_source(path: &str, code: &str) -> Result<FileAnalysis, TransformError> {
+    use swc_ecma_visit::VisitWith;
+
+    let parsed = parse_source(path, code)?;
+    let mut collector = StringCollector {
+        code,
+        base: parsed.base,
+        bom: parsed.bom,
+        components: Vec::new(),
+        analysis: FileAnalysis {
+            path: path.to_string(),
+            import_anchor: leading_directive_end(code),
+            ..Default::default()
+        },
+    };
+    parsed.module.visit_with(&mut collector);
+    Ok(collector.analysis)
+}
+
+// 파싱 결과 + span을 원본 바이트 오프셋으로 바꾸는 데 필요한 값
+// (extract.rs 등 다른 Visitor도 같은 방식으로 파싱)
+#[cfg(feature = "swc")]
+pub struct ParsedSource {
+    pub module: swc_ecma_ast::Module,
+    pub base: usize,
+    pub bom: usize,
+}
+
+#[cfg(feature = "swc")]
+impl ParsedSource {
+    pub fn offset(&self, pos: swc_common::BytePos) -> usize {
+        pos.0 as usize - self.base + self.bom
+    }
+}
+
+#[cfg(feature = "swc")]
+pub fn parse_source(path: &str, code: &str) -> Result<ParsedSource, TransformError> {
+    use swc_common::{sync::Lrc, FileName, SourceMap, Spanned};
+    use swc_ecma_parser::{EsSyntax, Parser, StringInput, Syntax, TsSyntax};
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(Lrc::new(FileName::Custom(path.to_string())), code.to_string());
+
+    let syntax = if path.ends_with(".ts") {
+        Syntax::Typescript(TsSyntax::default())
+    } else if path.ends_with(".tsx") {
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        })
+    } else {
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        })
+    };
+
+    // SourceMap은 BOM을 제거하므로 원본 오프셋으로 되돌릴 때 보정
+    let bom = if code.starts_with('\u{feff}') { 3 } else { 0 };
+
+    let mut parser = Parser::new(syntax, StringInput::from(&*fm), None);
+    let module = parser.parse_module().map_err(|e| {
+        let span = e.span();
+        let loc = cm.lookup_char_pos(span.lo);
+        TransformError::Parse {
+            path: path.to_string(),
+            line: loc.line,
+            column: loc.col.0 + 1,
+            start: (span.lo.0 - fm.start_pos.0) as usize + bom,
+            end: (span.hi.0 - fm.start_pos.0) as usize + bom,
+            message: e.kind().msg().to_string(),
+        }
+    })?;
+    Ok(ParsedSource {
+        module,
+        base: fm.start_pos.0 as usize,
+        bom,
+    })
+}
+
+#[cfg(feature = "swc")]
+struct StringCollector<'a> {
+    code: &'a str,
+    base: usize,
+    bom: usize,
+    // 현재 감싸고 있는 React 컴포넌트 이름 스택
+    components: Vec<String>,
+    analysis: FileAnalysis,
+}
+
+#[cfg(feature = "swc")]
+impl StringCollector<'_> {
+    fn offset(&self, pos: swc_common::BytePos) -> usize {
+        pos.0 as usize - self.base + self.bom
+    }
+
+    fn record(&mut self, text: String, start: usize, end: usize, kind: StringKind, ignore_at: usize) {
+        if !is_user_facing(&text) {
+            return;
+        }
+        let (line, column) = line_col(self.code, start);
+        let ignored = is_ignored(self.code, line);
+        self.analysis.strings.push(HardcodedString {
+            text,
+            start,
+            end,
+            line,
+            column,
+            kind,
+            component: self.components.last().cloned(),
+            ignored,
+            ignore_at,
+        });
+    }
+
+    fn record_str(&mut self, s: &swc_ecma_ast::Str, kind: StringKind, anchor: swc_common::BytePos) {
+        let start = self.offset(s.span.lo);
+        let end = self.offset(s.span.hi);
+        let ignore_at = self.offset(anchor);
+        self.record(s.value.to_string(), start, end, kind, ignore_at);
+    }
+
+    fn with_component<F: FnOnce(&mut Self)>(&mut self, name: Option<String>, f: F) {
+        let pushed = match name {
+            Some(name) if is_component_name(&name) => {
+                self.components.push(name);
+                true
+            }
+            _ => false,
+        };
+        f(self);
+        if pushed {
+            self.components.pop();
+        }
+    }
+}
+
+#[cfg(feature = "swc")]
+impl swc_ecma_visit::Visit for StringCollector<'_> {
+    fn visit_import_decl(&mut self, import: &swc_ecma_ast::ImportDecl) {
+        use swc_ecma_ast::ImportSpecifier;
+
+        self.analysis.imports.push(import.src.value.to_string());
+        let span = (self.offset(import.src.span.lo), self.offset(import.src.span.hi));
+        self.analysis.import_spans.push(span);
+        if import
+            .specifiers
+            .iter()
+            .any(|s| matches!(s, ImportSpecifier::Named(named) if named.local.sym == "t"))
+        {
+            self.analysis.has_t_binding = true;
+        }
+        let end = self.offset(import.span.hi);
+        // import 문 뒤 줄바꿈까지 포함
+        let end = self.code[end..].find('\n').map(|i| end + i + 1).unwrap_or(self.code.len());
+        self.analysis.import_anchor = self.analysis.import_anchor.max(end);
+    }
+
+    fn visit_call_expr(&mut self, call: &swc_ecma_ast::CallExpr) {
+        use swc_ecma_ast::{Callee, Expr};
+        use swc_ecma_visit::VisitWith;
+
+        if let Callee::Expr(expr) = &call.callee {
+            if let Expr::Ident(ident) = &**expr {
+                if ident.sym == "useTranslation" {
+                    self.analysis.uses_translation = true;
+                }
+            }
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, decl: &swc_ecma_ast::FnDecl) {
+        use swc_ecma_visit::VisitWith;
+        self.with_component(Some(decl.ident.sym.to_string()), |this| decl.visit_children_with(this));
+    }
+
+    fn visit_fn_expr(&mut self, expr: &swc_ecma_ast::FnExpr) {
+        use swc_ecma_visit::VisitWith;
+        let name = expr.ident.as_ref().map(|i| i.sym.to_string());
+        self.with_component(name, |this| expr.visit_children_with(this));
+    }
+
+    fn visit_class_decl(&mut self, decl: &swc_ecma_ast::ClassDecl) {
+        use swc_ecma_visit::VisitWith;
+        self.with_component(Some(decl.ident.sym.to_string()), |this| decl.visit_children_with(this));
+    }
+
+    // const CheckoutForm = () => { ... }
+    fn visit_var_declarator(&mut self, decl: &swc_ecma_ast::VarDeclarator) {
+        use swc_ecma_ast::{Expr, Pat};
+        use swc_ecma_visit::VisitWith;
+
+        let name = match (&decl.name, decl.init.as_deref()) {
+            (Pat::Ident(ident), Some(Expr::Arrow(_) | Expr::Fn(_) | Expr::Call(_))) => {
+                Some(ident.id.sym.to_string())
+            }
+            _ => None,
+        };
+        self.with_component(name, |this| decl.visit_children_with(this));
+    }
+
+    fn visit_jsx_element_child(&mut self, child: &swc_ecma_ast::JSXElementChild) {
+        use swc_ecma_ast::{Expr, JSXElementChild, JSXExpr, Lit};
+        use swc_ecma_visit::VisitWith;
+
+        match child {
+            JSXElementChild::JSXText(text) => {
+                let start = self.offset(text.span.lo);
+                let raw = &self.code[start..self.offset(text.span.hi)];
+                let trimmed = raw.trim();
+                if !trimmed.is_empty() {
+                    let lead = raw.len() - raw.trim_start().len();
+                    let normalized = trimmed.split_whitespace().collect::<Vec<_>>().join(" ");
+                    let start = start + lead;
+                    self.record(normalized, start, start + trimmed.len(), StringKind::Text, start);
+                }
+            }
+            JSXElementChild::JSXExprContainer(container) => {
+                if let JSXExpr::Expr(expr) = &container.expr {
+                    if let Expr::Lit(Lit::Str(s)) = &**expr {
+                        self.record_str(s, StringKind::Expression, container.span.lo);
+                    }
+                }
+            }
+            _ => {}
+        }
+        child.visit_children_with(self);
+    }
+
+    fn visit_jsx_attr(&mut self, attr: &swc_ecma_ast::JSXAttr) {
+        use swc_ecma_ast::{Expr, JSXAttrName, JSXAttrValue, JSXExpr, Lit};
+        use swc_ecma_visit::VisitWith;
+
+        let name = match &attr.name {
+            JSXAttrName::Ident(ident) => ident.sym.to_string(),
+            JSXAttrName::JSXNamespacedName(ns) => format!("{}:{}", ns.ns.sym, ns.name.sym),
+        };
+        if USER_FACING_ATTRS.contains(&name.as_str()) {
+            match &attr.value {
+                Some(JSXAttrValue::Lit(Lit::Str(s))) => {
+                    self.record_str(s, StringKind::Attribute(name), attr.span.lo)
+                }
+                Some(JSXAttrValue::JSXExprContainer(container)) => {
+                    if let JSXExpr::Expr(expr) = &container.expr {
+                        if let Expr::Lit(Lit::Str(s)) = &**expr {
+                            self.record_str(s, StringKind::AttributeExpression(name), attr.span.lo);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        attr.visit_children_with(self);
+    }
+}
+
+// ============================================
+// 2단계: 키 할당 (카탈로그 기준으로 충돌 회피)
+// ============================================
+pub fn plan_wraps(analysis: &FileAnalysis, strategy: KeyStrategy, catalog: &mut Catalog) -> Vec<Wrap> {
+    let file_namespace = namespace_for(&analysis.path, None);
+    // 같은 파일의 같은 컴포넌트 + 같은 문구는 같은 키 재사용
+    let mut assigned: HashMap<(String, String), String> = HashMap::new();
+    let mut wraps = Vec::new();
+
+    for found in analysis.strings.iter().filter(|s| !s.ignored) {
+        let namespace = match &found.component {
+            Some(component) => namespace_for(&analysis.path, Some(component)),
+            None => file_namespace.clone(),
+        };
+        let key = assigned
+            .entry((namespace.clone(), found.text.clone()))
+            .or_insert_with(|| allocate_key(catalog, &namespace, &found.text, strategy))
+            .clone();
+
+        wraps.push(Wrap {
+            start: found.start,
+            end: found.end,
+            line: found.line,
+            column: found.column,
+            replacement: replacement_for(&found.kind, &key),
+            key,
+            text: found.text.clone(),
+            kind: found.kind.clone(),
+            component: found.component.clone(),
+            ignore_at: found.ignore_at,
+        });
+    }
+    wraps
+}
+
+pub fn replacement_for(kind: &StringKind, key: &str) -> String {
+    let call = format!("t('{}')", key);
+    match kind {
+        StringKind::Text | StringKind::Attribute(_) => format!("{{{}}}", call),
+        StringKind::AttributeExpression(_) | StringKind::Expression => call,
+    }
+}
+
+// 같은 키에 다른 문구가 이미 있으면 _2, _3 ... 을 붙임
+// 중첩 카탈로그에서 네임스페이스 자리가 문자열이면 네임스페이스에 붙임 (checkout_form_2.place_order)
+fn allocate_key(catalog: &mut Catalog, namespace: &str, text: &str, strategy: KeyStrategy) -> String {
+    let mut base = make_key(namespace, text, strategy);
+    let mut n = 2;
+    while catalog.conflict(&base).map(|existing| existing != base).unwrap_or(false) {
+        base = make_key(&format!("{}_{}", namespace, n), text, strategy);
+        n += 1;
+    }
+    let mut key = base.clone();
+    let mut n = 2;
+    loop {
+        match catalog.get(&key) {
+            None => {
+                // 하위 키가 있는 자리(객체)면 다음 번호로
+                if catalog.insert(&key, text).is_ok() {
+                    return key;
+                }
+            }
+            Some(existing) if existing == text => return key,
+            Some(_) => {}
+        }
+        key = format!("{}_{}", base, n);
+        n += 1;
+    }
+}
+
+pub fn make_key(namespace: &str, text: &str, strategy: KeyStrategy) -> String {
+    let slug = slugify(text);
+    let leaf = match strategy {
+        KeyStrategy::Slug if !slug.is_empty() => slug,
+        // 한글처럼 ASCII slug가 안 나오는 문구는 해시로 대체
+        _ => format!("{:08x}", text_hash(text) as u32),
+    };
+    format!("{}.{}", namespace, leaf)
+}
+
+fn slugify(text: &str) -> String {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .take(5)
+        .map(|w| w.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+// 컴포넌트가 있으면 컴포넌트 이름, 없으면 파일 이름 (snake_case)
+fn namespace_for(path: &str, component: Option<&str>) -> String {
+    let name = match component {
+        Some(component) => component.to_string(),
+        None => std::path::Path::new(path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "common".to_string()),
+    };
+    to_snake_case(&name)
+}
+
+pub fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else if !out.is_empty() && !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    out.trim_end_matches('_').to_string()
+}
+
+// ============================================
+// 3단계: 원본 텍스트에 치환 적용
+// ============================================
+pub fn apply_wraps(code: &str, analysis: &FileAnalysis, wraps: &[Wrap]) -> String {
+    apply_edits(code, analysis, wraps, &[])
+}
+
+// 래핑 대신 `i18n-ignore` 주석을 넣을 위치와 주석 텍스트
+pub fn ignore_comment(wrap: &Wrap) -> (usize, &'static str) {
+    match wrap.kind {
+        StringKind::Text | StringKind::Expression => (wrap.ignore_at, "{/* i18n-ignore */}"),
+        // 여는 태그 안에서는 중괄호 없이 주석만 가능
+        StringKind::Attribute(_) | StringKind::AttributeExpression(_) => {
+            (wrap.ignore_at, "/* i18n-ignore */ ")
+        }
+    }
+}
+
+// wraps 치환 + ignore 주석 삽입을 한 번에 적용
+pub fn apply_edits(code: &str, analysis: &FileAnalysis, wraps: &[Wrap], ignored: &[&Wrap]) -> String {
+    let mut edits: Vec<(usize, usize, &str)> = wraps
+        .iter()
+        .map(|w| (w.start, w.end, w.replacement.as_str()))
+        .collect();
+    for wrap in ignored {
+        let (at, comment) = ignore_comment(wrap);
+        edits.push((at, at, comment));
+    }
+    if !wraps.is_empty() && !analysis.uses_translation && !analysis.has_t_binding {
+        edits.push((analysis.import_anchor, analysis.import_anchor, TRANSLATION_IMPORT));
+    }
+    // 뒤에서부터 치환해야 앞쪽 오프셋이 유지됨
+    edits.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+    let mut out = code.to_string();
+    for (start, end, replacement) in edits {
+        out.replace_range(start..end, replacement);
+    }
+    out
+}
+
+// ============================================
+// 유틸리티
+// ============================================
+
+// FNV-1a: 실행 환경과 무관하게 항상 같은 값 (키/캐시에 사용)
+pub fn text_hash(text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+pub fn line_col(code: &str, offset: usize) -> (usize, usize) {
+    let before = &code[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(feature = "swc")]
+fn is_user_facing(text: &str) -> bool {
+    text.chars().any(|c| c.is_alphabetic())
+}
+
+pub fn is_component_name(name: &str) -> bool {
+    name.chars().next().map(|c| c.is_ascii_uppercase()).unwrap_or(false)
+}
+
+// 같은 줄이나 바로 윗줄에 `i18n-ignore` 주석이 있으면 제외
+#[cfg(feature = "swc")]
+fn is_ignored(code: &str, line: usize) -> bool {
+    code.lines()
+        .skip(line.saturating_sub(2))
+        .take(if line >= 2 { 2 } else { 1 })
+        .any(|l| l.contains(IGNORE_DIRECTIVE))
+}
+
+// 'use client' 같은 디렉티브 뒤에 import를 넣기 위한 위치
+#[cfg(feature = "swc")]
+fn leading_directive_end(code: &str) -> usize {
+    let mut offset = 0;
+    for line in code.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with("'use ") || trimmed.starts_with("\"use ") {
+            offset += line.len();
+        } else {
+            break;
+        }
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_key() {
+        assert_eq!(make_key("checkout_form", "Place your order!", KeyStrategy::Slug), "checkout_form.place_your_order");
+        assert_eq!(to_snake_case("CheckoutForm"), "checkout_form");
+        // ASCII slug가 없는 문구는 해시 키
+        assert!(make_key("app", "주문하기", KeyStrategy::Slug).starts_with("app."));
+        assert_ne!(make_key("app", "주문하기", KeyStrategy::Slug), "app.");
+    }
+
+    #[test]
+    fn test_allocate_key_avoids_nested_conflicts() {
+        let text = "{\n  \"checkout_form\": \"Checkout\",\n  \"app\": {\n    \"save\": {\n      \"label\": \"Save\"\n    }\n  }\n}\n";
+        let mut catalog = Catalog::parse(std::path::Path::new("en.json"), text).unwrap();
+        // 네임스페이스 자리가 문자열
+        let key = allocate_key(&mut catalog, "checkout_form", "Place order", KeyStrategy::Slug);
+        assert_eq!(key, "checkout_form_2.place_order");
+        // 키 자리에 하위 키가 있음
+        assert_eq!(allocate_key(&mut catalog, "app", "Save", KeyStrategy::Slug), "app.save_2");
+        assert_eq!(catalog.get("checkout_form"), Some("Checkout"));
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_wrap_jsx_text_and_attribute() {
+        use std::path::Path;
+
+        let code = "export function Hello() {\n  return <img alt=\"Logo\" /> && <p>Hi there</p>;\n}\n";
+        let analysis = analyze_source("Hello.jsx", code).unwrap();
+        let mut catalog = Catalog::empty(Path::new("en.json"));
+        let wraps = plan_wraps(&analysis, KeyStrategy::Slug, &mut catalog);
+        let out = apply_wraps(code, &analysis, &wraps);
+        assert_eq!(
+            out,
+            "import { t } from 'i18next';\nexport function Hello() {\n  return <img alt={t('hello.logo')} /> && <p>{t('hello.hi_there')}</p>;\n}\n"
+        );
+        assert_eq!(catalog.get("hello.hi_there"), Some("Hi there"));
+    }
+
+    #[cfg(feature = "swc")]
+    #[test]
+    fn test_parse_failure_points_at_error() {
+        let code = "const A = () => <p>Hi</p>;\nconst B = () => <p>Bye</div>;\n";
+        let error = analyze_source("Broken.jsx", code).err().unwrap();
+        let diagnostic = error.to_diagnostic(code);
+        assert_eq!(diagnostic.code, diagnostic::PARSE_ERROR);
+        assert_eq!(diagnostic.location().map(|(line, _)| line), Some(2));
+        assert!(diagnostic.render(false).contains("const B = () => <p>Bye</div>;"));
+    }
+}
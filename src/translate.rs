@@ -0,0 +1,665 @@
+// ============================================
+// i18n-wrapper: 기계 번역으로 빠진 번역 미리 채우기 (translate)
+// 제공자(TranslationProvider)는 교체 가능:
+//   dictionary  오프라인 사전 파일 { "ko": { "Save": "저장" } }
+//   http        LibreTranslate 호환 API (POST /translate, 자체 호스팅 서버용 http://)
+// 공통 처리는 이 파일에서:
+//   - 같은 원문은 한 번만 요청하고 batch_size개씩 묶어서 전송
+//   - 요청 간격 제한 (--rate 초당 요청 수)
+//   - 429/5xx/연결 실패는 지수 백오프로 재시도 (Retry-After 헤더 우선)
+// 기계 번역한 항목은 카탈로그 sidecar(ko.meta.json)에 "needsReview": true 로 표시
+//
+// async_examples.rs의 fetch_data처럼 tokio::time::sleep으로 기다리고,
+// 트레이트 메서드는 `impl Future + Send`를 반환해서 tokio::spawn 안에서도 사용 가능
+// TypeScript:
+// interface TranslationProvider { translate(texts: string[], from: string, to: string): Promise<string[]> }
+// ============================================
+
+use std::fmt;
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::Instant;
+
+use crate::catalog::{self, Catalog};
+use crate::diagnostic::{self, Diagnostic};
+use crate::extract;
+use crate::json::{self, Value};
+use crate::process::FileChange;
+
+// HTTP 제공자의 API 키 (명령줄에 남지 않도록 환경 변수로만 받음)
+pub const API_KEY_ENV: &str = "I18N_WRAPPER_API_KEY";
+// 연결 + 전송 + 응답 읽기 전체 제한 (연결을 잡고 응답하지 않는 서버에서 멈추지 않도록)
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProviderError {
+    // 429: 잠시 후 다시 시도
+    RateLimited { retry_after: Option<Duration> },
+    // 5xx, 연결 끊김 등 다시 시도하면 될 수 있는 에러
+    Transient(String),
+    // 잘못된 키, 지원하지 않는 언어 등 재시도해도 소용없는 에러
+    Fatal(String),
+}
+
+impl fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProviderError::RateLimited { .. } => write!(f, "요청 한도 초과 (429)"),
+            ProviderError::Transient(message) => write!(f, "일시적 에러: {}", message),
+            ProviderError::Fatal(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+pub trait TranslationProvider: Sync {
+    // sidecar의 translatedBy 값
+    fn name(&self) -> &str;
+
+    // 요청 하나에 담을 최대 문자열 수
+    fn batch_size(&self) -> usize {
+        25
+    }
+
+    // texts와 같은 순서로 결과, 번역할 수 없는 항목은 None
+    fn translate(
+        &self,
+        texts: &[String],
+        source: &str,
+        target: &str,
+    ) -> impl Future<Output = Result<Vec<Option<String>>, ProviderError>> + Send;
+}
+
+// ============================================
+// 오프라인 사전 제공자 (네트워크 없이 테스트/CI용)
+// ============================================
+pub struct DictionaryProvider {
+    // (로케일, [(원문, 번역)])
+    entries: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl DictionaryProvider {
+    pub fn load(path: &Path) -> Result<Self, Box<Diagnostic>> {
+        let invalid = |message: String| Box::new(Diagnostic::error(diagnostic::TRANSLATE_ERROR, message, path));
+        let text = fs::read_to_string(path).map_err(|e| invalid(format!("사전 파일을 읽을 수 없습니다: {}", e)))?;
+        let root = json::parse(&text).map_err(|e| invalid(format!("{}:{}", path.display(), e)))?;
+        let locales = root.as_object().ok_or_else(|| invalid("사전 파일은 { 로케일: { 원문: 번역 } } 형태여야 합니다".to_string()))?;
+        let entries = locales
+            .iter()
+            .map(|(locale, pairs)| {
+                let pairs = pairs
+                    .as_object()
+                    .map(|pairs| {
+                        pairs
+                            .iter()
+                            .filter_map(|(source, target)| target.as_str().map(|t| (source.clone(), t.to_string())))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                (locale.clone(), pairs)
+            })
+            .collect();
+        Ok(Self { entries })
+    }
+}
+
+impl TranslationProvider for DictionaryProvider {
+    fn name(&self) -> &str {
+        "dictionary"
+    }
+
+    fn batch_size(&self) -> usize {
+        usize::MAX
+    }
+
+    fn translate(
+        &self,
+        texts: &[String],
+        _source: &str,
+        target: &str,
+    ) -> impl Future<Output = Result<Vec<Option<String>>, ProviderError>> + Send {
+        let pairs = self.entries.iter().find(|(locale, _)| locale == target).map(|(_, pairs)| pairs);
+        let result = texts
+            .iter()
+            .map(|text| pairs.and_then(|pairs| pairs.iter().find(|(s, _)| s == text)).map(|(_, t)| t.clone()))
+            .collect();
+        async move { Ok(result) }
+    }
+}
+
+// ============================================
+// 로컬 HTTP 제공자 (LibreTranslate 호환, --provider local-http)
+// 요청:  { "q": ["Save", ...], "source": "en", "target": "ko", "format": "text", "api_key": "..." }
+// 응답:  { "translatedText": ["저장", ...] }
+// 외부 크레이트 없이 HTTP/1.1을 직접 작성하는 stub 전용 전송:
+// TLS/프록시/리다이렉트/keep-alive 없음, 같은 머신의 번역 서버나 프록시(localhost)만 허용
+// API 키와 원문이 평문으로 나가므로 원격 서버는 거부
+// 원격 번역 API는 TLS를 처리하는 로컬 프록시(또는 로컬 LibreTranslate)를 띄워서 연결
+// ============================================
+pub struct LocalHttpProvider {
+    host: String,
+    port: u16,
+    path: String,
+    api_key: Option<String>,
+    batch_size: usize,
+    timeout: Duration,
+}
+
+impl LocalHttpProvider {
+    // "http://localhost:5000/translate"
+    pub fn new(url: &str, api_key: Option<String>) -> Result<Self, String> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("http:// URL만 지원합니다: {}", url))?;
+        let (authority, path) = match rest.find('/') {
+            Some(slash) => (&rest[..slash], &rest[slash..]),
+            None => (rest, "/translate"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| format!("잘못된 포트: {}", port))?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(format!("호스트가 없습니다: {}", url));
+        }
+        if !is_loopback(host) {
+            return Err(format!("local-http 제공자는 localhost 서버만 지원합니다 (원격 서버는 로컬 프록시를 거쳐야 함): {}", url));
+        }
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+            api_key,
+            batch_size: 25,
+            timeout: REQUEST_TIMEOUT,
+        })
+    }
+
+    // 시간 초과도 재시도 대상 (Transient)
+    async fn post(&self, body: &str) -> Result<(u16, Option<Duration>, String), ProviderError> {
+        match tokio::time::timeout(self.timeout, self.exchange(body)).await {
+            Ok(result) => result,
+            Err(_) => Err(ProviderError::Transient(format!("{}초 안에 응답이 없습니다", self.timeout.as_secs_f64()))),
+        }
+    }
+
+    async fn exchange(&self, body: &str) -> Result<(u16, Option<Duration>, String), ProviderError> {
+        let transient = |e: std::io::Error| ProviderError::Transient(e.to_string());
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port)).await.map_err(transient)?;
+        let request = format!(
+            "POST {} HTTP/1.1\r\nHost: {}:{}\r\nContent-Type: application/json\r\nAccept: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.path,
+            self.host,
+            self.port,
+            body.len(),
+            body
+        );
+        stream.write_all(request.as_bytes()).await.map_err(transient)?;
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).await.map_err(transient)?;
+        parse_response(&raw)
+    }
+}
+
+// "localhost", "127.x.x.x"
+fn is_loopback(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host.parse::<std::net::IpAddr>().map(|ip| ip.is_loopback()).unwrap_or(false)
+}
+
+// 상태 코드, Retry-After, 본문 (chunked 인코딩이면 풀어서)
+// chunk 경계가 UTF-8 글자 중간일 수 있으므로 본문은 바이트로 합친 뒤 한 번에 디코딩
+fn parse_response(raw: &[u8]) -> Result<(u16, Option<Duration>, String), ProviderError> {
+    let split = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| ProviderError::Transient("HTTP 응답이 잘렸습니다".to_string()))?;
+    let head = String::from_utf8_lossy(&raw[..split]);
+    let body = &raw[split + 4..];
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| ProviderError::Transient("잘못된 HTTP 상태 줄".to_string()))?;
+    let mut retry_after = None;
+    let mut chunked = false;
+    for line in lines {
+        let (name, value) = match line.split_once(':') {
+            Some((name, value)) => (name.trim().to_ascii_lowercase(), value.trim()),
+            None => continue,
+        };
+        match name.as_str() {
+            "retry-after" => retry_after = value.parse().ok().map(Duration::from_secs),
+            "transfer-encoding" => chunked = value.eq_ignore_ascii_case("chunked"),
+            _ => {}
+        }
+    }
+    let body = if chunked { dechunk(body) } else { body.to_vec() };
+    Ok((status, retry_after, String::from_utf8_lossy(&body).into_owned()))
+}
+
+fn dechunk(mut body: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    while let Some(line_end) = body.windows(2).position(|w| w == b"\r\n") {
+        let size_line = String::from_utf8_lossy(&body[..line_end]);
+        let size = usize::from_str_radix(size_line.split(';').next().unwrap_or("").trim(), 16).unwrap_or(0);
+        let rest = &body[line_end + 2..];
+        if size == 0 || rest.len() < size {
+            break;
+        }
+        out.extend_from_slice(&rest[..size]);
+        body = rest[size..].strip_prefix(b"\r\n").unwrap_or(&rest[size..]);
+    }
+    out
+}
+
+impl TranslationProvider for LocalHttpProvider {
+    fn name(&self) -> &str {
+        "local-http"
+    }
+
+    fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    fn translate(
+        &self,
+        texts: &[String],
+        source: &str,
+        target: &str,
+    ) -> impl Future<Output = Result<Vec<Option<String>>, ProviderError>> + Send {
+        let mut fields = vec![
+            ("q".to_string(), Value::Array(texts.iter().map(|t| t.as_str().into()).collect())),
+            ("source".to_string(), source.into()),
+            ("target".to_string(), target.into()),
+            ("format".to_string(), "text".into()),
+        ];
+        if let Some(key) = &self.api_key {
+            fields.push(("api_key".to_string(), key.as_str().into()));
+        }
+        let body = Value::Object(fields).to_compact_string();
+        let expected = texts.len();
+        async move {
+            let (status, retry_after, body) = self.post(&body).await?;
+            match status {
+                200..=299 => {}
+                429 => return Err(ProviderError::RateLimited { retry_after }),
+                500..=599 => return Err(ProviderError::Transient(format!("HTTP {}", status))),
+                _ => return Err(ProviderError::Fatal(format!("HTTP {}: {}", status, body.trim()))),
+            }
+            let parsed = json::parse(&body).map_err(|e| ProviderError::Fatal(format!("잘못된 응답: {}", e)))?;
+            let translated = parsed
+                .get("translatedText")
+                .and_then(Value::as_array)
+                .ok_or_else(|| ProviderError::Fatal("응답에 translatedText 배열이 없습니다".to_string()))?;
+            if translated.len() != expected {
+                return Err(ProviderError::Fatal(format!(
+                    "요청 {}개에 응답 {}개",
+                    expected,
+                    translated.len()
+                )));
+            }
+            Ok(translated.iter().map(|t| t.as_str().map(String::from)).collect())
+        }
+    }
+}
+
+// ============================================
+// 묶음 전송 + 요청 간격 제한 + 재시도
+// ============================================
+#[derive(Debug, Clone)]
+pub struct Limits {
+    // 초당 요청 수 (0이면 제한 없음)
+    pub requests_per_second: f64,
+    pub max_retries: u32,
+    // 첫 재시도 대기 시간, 이후 두 배씩 (MAX_BACKOFF까지)
+    pub backoff: Duration,
+}
+
+pub const MAX_RETRIES: u32 = 10;
+pub const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+// backoff * 2^attempt, 넘치거나 MAX_BACKOFF보다 길면 MAX_BACKOFF
+fn backoff_delay(backoff: Duration, attempt: u32) -> Duration {
+    2u32.checked_pow(attempt)
+        .and_then(|factor| backoff.checked_mul(factor))
+        .map(|delay| delay.min(MAX_BACKOFF))
+        .unwrap_or(MAX_BACKOFF)
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 2.0,
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+// 마지막 요청 시각 기준으로 다음 요청까지 대기
+struct RateLimiter {
+    interval: Duration,
+    next: Option<Instant>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: f64) -> Self {
+        let interval = if requests_per_second > 0.0 {
+            Duration::from_secs_f64(1.0 / requests_per_second)
+        } else {
+            Duration::ZERO
+        };
+        Self { interval, next: None }
+    }
+
+    async fn wait(&mut self) {
+        if let Some(next) = self.next {
+            tokio::time::sleep_until(next).await;
+        }
+        self.next = Some(Instant::now() + self.interval);
+    }
+}
+
+// texts와 같은 순서로 결과 (같은 원문은 한 번만 요청)
+pub async fn translate_texts<P: TranslationProvider>(
+    provider: &P,
+    texts: &[String],
+    source: &str,
+    target: &str,
+    limits: &Limits,
+) -> Result<Vec<Option<String>>, ProviderError> {
+    let mut unique: Vec<String> = Vec::new();
+    for text in texts {
+        if !unique.contains(text) {
+            unique.push(text.clone());
+        }
+    }
+    let mut limiter = RateLimiter::new(limits.requests_per_second);
+    let mut translated = Vec::with_capacity(unique.len());
+    for batch in unique.chunks(provider.batch_size().max(1)) {
+        let mut attempt = 0;
+        let result = loop {
+            limiter.wait().await;
+            let error = match provider.translate(batch, source, target).await {
+                Ok(result) => break result,
+                Err(ProviderError::Fatal(message)) => return Err(ProviderError::Fatal(message)),
+                Err(error) => error,
+            };
+            if attempt >= limits.max_retries {
+                return Err(error);
+            }
+            let delay = match &error {
+                ProviderError::RateLimited { retry_after: Some(delay) } => *delay,
+                _ => backoff_delay(limits.backoff, attempt),
+            };
+            attempt += 1;
+            tokio::time::sleep(delay).await;
+        };
+        translated.extend(result);
+    }
+    Ok(texts
+        .iter()
+        .map(|text| unique.iter().position(|u| u == text).and_then(|i| translated[i].clone()))
+        .collect())
+}
+
+// ============================================
+// 카탈로그 채우기 계획
+// 대상 로케일에 없거나 빈 문자열(sync --fill empty)인 키만 번역
+// ============================================
+pub struct TranslatePlan {
+    pub changes: Vec<FileChange>,
+    // (로케일, 키)
+    pub filled: Vec<(String, String)>,
+    // (로케일, 키, 이유)
+    pub skipped: Vec<(String, String, String)>,
+}
+
+pub async fn plan_translate<P: TranslationProvider>(
+    provider: &P,
+    source_locale: &str,
+    source_path: &Path,
+    targets: &[(String, PathBuf)],
+    limits: &Limits,
+) -> Result<TranslatePlan, Box<Diagnostic>> {
+    let source = Catalog::load_diagnostic(source_path)?;
+    let mut plan = TranslatePlan {
+        changes: Vec::new(),
+        filled: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for (locale, path) in targets {
+        let mut catalog = Catalog::load_diagnostic(path)?;
+        let missing: Vec<(String, String)> = source
+            .entries()
+            .into_iter()
+            .filter(|(key, _)| catalog.get(key).map(str::is_empty).unwrap_or(true))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+        let texts: Vec<String> = missing.iter().map(|(_, text)| text.clone()).collect();
+        let translated = translate_texts(provider, &texts, source_locale, locale, limits)
+            .await
+            .map_err(|e| {
+                Box::new(Diagnostic::error(
+                    diagnostic::TRANSLATE_ERROR,
+                    format!("{} 번역 실패 ({}): {}", locale, provider.name(), e),
+                    path,
+                ))
+            })?;
+
+        let before = catalog.render();
+        let mut filled = Vec::new();
+        for ((key, text), result) in missing.iter().zip(translated) {
+            let reason = match result {
+                None => Some("번역 결과 없음".to_string()),
+                // 자리표시자가 바뀌면 런타임에 값이 들어가지 않으므로 채우지 않음
+                Some(result) if catalog::placeholders(&result) != catalog::placeholders(text) => {
+                    Some(format!("자리표시자가 바뀜: {:?}", result))
+                }
+                Some(result) => match catalog.insert(key, &result) {
+                    Ok(()) => {
+                        filled.push(key.clone());
+                        None
+                    }
+                    Err(e) => Some(e.to_string()),
+                },
+            };
+            if let Some(reason) = reason {
+                plan.skipped.push((locale.clone(), key.clone(), reason));
+            }
+        }
+        if filled.is_empty() {
+            continue;
+        }
+        plan.changes.push(FileChange {
+            path: path.clone(),
+            original: before,
+            updated: catalog.render(),
+            wraps: Vec::new(),
+        });
+        plan.changes.push(mark_needs_review(path, &filled, provider.name())?);
+        plan.filled.extend(filled.into_iter().map(|key| (locale.clone(), key)));
+    }
+    Ok(plan)
+}
+
+// ko.json -> ko.meta.json 에 { "key": { "needsReview": true, "translatedBy": "local-http" } }
+// 사람이 검토한 뒤 needsReview를 지우거나 false로 바꿈
+fn mark_needs_review(catalog_path: &Path, keys: &[String], provider: &str) -> Result<FileChange, Box<Diagnostic>> {
+    let meta_file = extract::meta_path(catalog_path);
+    let original = fs::read_to_string(&meta_file).unwrap_or_default();
+    let mut meta = if original.trim().is_empty() {
+        Value::object()
+    } else {
+        json::parse(&original).map_err(|e| {
+            Box::new(Diagnostic::error(diagnostic::CATALOG_ERROR, format!("{}:{}", meta_file.display(), e), &meta_file))
+        })?
+    };
+    for key in keys {
+        if meta.get(key).is_none() {
+            meta.insert(key, Value::object());
+        }
+        if let Some(entry) = meta.get_mut(key) {
+            entry.insert("needsReview", true.into());
+            entry.insert("translatedBy", provider.into());
+        }
+    }
+    let mut updated = meta.to_pretty_string(&json::detect_indent(&original));
+    updated.push('\n');
+    Ok(FileChange {
+        path: meta_file,
+        original,
+        updated,
+        wraps: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+    use tokio::net::TcpListener;
+
+    // 첫 요청은 429, 두 번째부터는 "[target] 원문" 으로 번역해주는 로컬 스텁 서버
+    async fn stub_server() -> (u16, tokio::task::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = tokio::spawn(async move {
+            let mut bodies = Vec::new();
+            for attempt in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut raw = Vec::new();
+                let mut buf = [0u8; 1024];
+                // 헤더 + Content-Length 만큼 읽기
+                loop {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    raw.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&raw).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length: usize = head
+                            .lines()
+                            .find_map(|l| l.strip_prefix("Content-Length: "))
+                            .and_then(|v| v.parse().ok())
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            bodies.push(body.to_string());
+                            break;
+                        }
+                    }
+                }
+                let response = if attempt == 0 {
+                    "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\n\r\n".to_string()
+                } else {
+                    let request = json::parse(bodies.last().unwrap()).unwrap();
+                    let target = request.get("target").and_then(Value::as_str).unwrap_or("").to_string();
+                    let texts = request.get("q").and_then(Value::as_array).cloned().unwrap_or_default();
+                    let translated: Vec<Value> = texts
+                        .iter()
+                        .map(|t| format!("[{}] {}", target, t.as_str().unwrap_or("")).into())
+                        .collect();
+                    let body = Value::Object(vec![("translatedText".to_string(), Value::Array(translated))])
+                        .to_compact_string();
+                    format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body)
+                };
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            bodies
+        });
+        (port, handle)
+    }
+
+    #[tokio::test]
+    async fn test_local_http_provider_times_out_on_silent_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // 연결만 받고 아무것도 보내지 않음
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            drop(socket);
+        });
+        let mut provider = LocalHttpProvider::new(&format!("http://127.0.0.1:{}/translate", port), None).unwrap();
+        provider.timeout = Duration::from_millis(100);
+        let error = provider.post("{}").await.unwrap_err();
+        assert!(matches!(error, ProviderError::Transient(_)), "{:?}", error);
+        server.abort();
+    }
+
+    #[test]
+    fn test_backoff_delay_is_capped() {
+        let backoff = Duration::from_millis(500);
+        assert_eq!(backoff_delay(backoff, 0), backoff);
+        assert_eq!(backoff_delay(backoff, 3), Duration::from_secs(4));
+        assert_eq!(backoff_delay(backoff, 10), MAX_BACKOFF);
+        assert_eq!(backoff_delay(backoff, 40), MAX_BACKOFF);
+        assert_eq!(backoff_delay(Duration::MAX, 1), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_chunked_body_split_inside_utf8_char() {
+        // "저장" = EC A0 80 EC 9E A5, 첫 글자를 1 + 2 바이트로 나눔
+        let mut raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n1\r\n\xEC\r\n5\r\n\xA0\x80\xEC\x9E\xA5\r\n".to_vec();
+        raw.extend_from_slice(b"0\r\n\r\n");
+        let (status, _, body) = parse_response(&raw).unwrap();
+        assert_eq!((status, body.as_str()), (200, "저장"));
+    }
+
+    #[test]
+    fn test_local_http_provider_accepts_only_localhost() {
+        for url in ["http://localhost:5000/translate", "http://127.0.0.1/translate", "http://LOCALHOST:8080"] {
+            assert!(LocalHttpProvider::new(url, None).is_ok(), "{}", url);
+        }
+        for url in ["http://translate.example.com/translate", "http://10.0.0.5:5000", "https://localhost:5000", "http://:5000"] {
+            assert!(LocalHttpProvider::new(url, None).is_err(), "{}", url);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_local_http_provider_retries_and_marks_needs_review() {
+        let (port, server) = stub_server().await;
+        let provider = LocalHttpProvider::new(&format!("http://127.0.0.1:{}/translate", port), None).unwrap();
+
+        let dir = TempDir::new("translate");
+        fs::write(dir.join("en.json"), "{\n  \"a\": \"Save\",\n  \"b\": \"Hi {{name}}\",\n  \"c\": \"Save\"\n}\n").unwrap();
+        fs::write(dir.join("ko.json"), "{\n  \"a\": \"\"\n}\n").unwrap();
+        let limits = Limits {
+            requests_per_second: 0.0,
+            max_retries: 2,
+            backoff: Duration::from_millis(1),
+        };
+        let targets = vec![("ko".to_string(), dir.join("ko.json"))];
+
+        let plan = plan_translate(&provider, "en", &dir.join("en.json"), &targets, &limits).await.unwrap();
+        let bodies = server.await.unwrap();
+        // 같은 원문 "Save"는 한 번만 전송
+        let sent = json::parse(&bodies[1]).unwrap();
+        assert_eq!(sent.get("q").and_then(Value::as_array).map(Vec::len), Some(2));
+
+        assert_eq!(plan.filled.len(), 3);
+        let catalog = &plan.changes[0].updated;
+        assert!(catalog.contains("\"a\": \"[ko] Save\""));
+        assert!(catalog.contains("\"b\": \"[ko] Hi {{name}}\""));
+        let meta = json::parse(&plan.changes[1].updated).unwrap();
+        let entry = meta.get("b").unwrap();
+        assert_eq!(entry.get("needsReview").and_then(Value::as_bool), Some(true));
+        assert_eq!(entry.get("translatedBy").and_then(Value::as_str), Some("local-http"));
+
+        // 오프라인 사전: 없는 항목은 건너뜀
+        fs::write(dir.join("dict.json"), "{ \"ko\": { \"Save\": \"저장\" } }").unwrap();
+        let dictionary = DictionaryProvider::load(&dir.join("dict.json")).unwrap();
+        let plan = plan_translate(&dictionary, "en", &dir.join("en.json"), &targets, &limits).await.unwrap();
+        assert_eq!(plan.filled.len(), 2);
+        assert_eq!(plan.skipped.len(), 1);
+    }
+}
@@ -0,0 +1,266 @@
+// ============================================
+// i18n-wrapper: watch 모드
+// 일정 간격으로 파일 목록과 수정 시각을 다시 읽는 폴링 방식
+// (inotify 같은 별도 서비스 없이 어디서나 동작)
+// 변경이 몰려 들어오면 async_examples.rs의 select_example처럼
+// tokio::select!로 debounce 한 뒤, 바뀐 파일과 그 파일을 import하는 파일만 다시 처리
+// TypeScript:
+// chokidar.watch(pattern).on("all", debounce(() => processFiles(changed), 200));
+// ============================================
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use tokio::time::{self, Instant};
+
+use crate::cache::{self, Cache};
+use crate::config::Config;
+use crate::glob;
+use crate::pipeline::{self, CancelToken};
+use crate::process::{self, ProcessOptions};
+
+pub struct WatchOptions {
+    pub poll_interval: Duration,
+    // 마지막 변경 후 이만큼 조용하면 처리 시작
+    pub debounce: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(500),
+            debounce: Duration::from_millis(200),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileEvent {
+    Created(PathBuf),
+    Modified(PathBuf),
+    Deleted(PathBuf),
+}
+
+impl FileEvent {
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            FileEvent::Created(path) | FileEvent::Modified(path) | FileEvent::Deleted(path) => path,
+        }
+    }
+}
+
+// 수정 시각 + 크기 (같은 초 안에 바뀌어도 크기로 한 번 더 확인)
+type Snapshot = HashMap<PathBuf, (SystemTime, u64)>;
+
+fn scan(project: &Config, patterns: &[String]) -> Snapshot {
+    project
+        .source_files(patterns)
+        .map(|(files, _)| files)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|path| {
+            let metadata = std::fs::metadata(&path).ok()?;
+            Some((glob::normalize(&path), (metadata.modified().ok()?, metadata.len())))
+        })
+        .collect()
+}
+
+pub fn diff_snapshots(before: &Snapshot, after: &Snapshot) -> Vec<FileEvent> {
+    let mut events: Vec<FileEvent> = after
+        .iter()
+        .filter_map(|(path, stamp)| match before.get(path) {
+            None => Some(FileEvent::Created(path.clone())),
+            Some(old) if old != stamp => Some(FileEvent::Modified(path.clone())),
+            _ => None,
+        })
+        .chain(
+            before
+                .keys()
+                .filter(|path| !after.contains_key(*path))
+                .map(|path| FileEvent::Deleted(path.clone())),
+        )
+        .collect();
+    events.sort_by(|a, b| a.path().cmp(b.path()));
+    events
+}
+
+// ============================================
+// 파일 → 상대 경로로 import한 파일 목록
+// ============================================
+#[derive(Default)]
+pub struct ImportGraph {
+    imports: HashMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl ImportGraph {
+    pub fn set(&mut self, path: PathBuf, imports: Vec<PathBuf>) {
+        self.imports.insert(path, imports);
+    }
+
+    pub fn remove(&mut self, path: &PathBuf) {
+        self.imports.remove(path);
+    }
+
+    // 바뀐 파일 + 그 파일을 직접 import하는 파일
+    pub fn affected(&self, changed: &BTreeSet<PathBuf>) -> BTreeSet<PathBuf> {
+        let mut affected = changed.clone();
+        for (path, imports) in &self.imports {
+            if imports.iter().any(|import| changed.contains(import)) {
+                affected.insert(path.clone());
+            }
+        }
+        affected
+    }
+}
+
+// ============================================
+// 메인 루프: Ctrl-C까지 계속 실행
+// ============================================
+pub async fn watch(project: &Config, patterns: &[String], options: &ProcessOptions, watch: &WatchOptions) {
+    let mut graph = ImportGraph::default();
+    let mut snapshot = scan(project, patterns);
+
+    // 시작할 때 한 번 전체 처리해서 import 그래프와 카탈로그를 맞춤
+    let mut files: Vec<PathBuf> = snapshot.keys().cloned().collect();
+    files.sort();
+    println!("watch: 파일 {}개 감시 시작 (Ctrl-C로 종료)", files.len());
+    let (handle, cancel) = pipeline::cancel_pair();
+    pipeline::cancel_on_ctrl_c(handle);
+    let written = run(options, files, &mut graph, &cancel).await;
+    refresh(&mut snapshot, &written);
+
+    // interval은 0이면 panic
+    let mut ticker = time::interval(watch.poll_interval.max(Duration::from_millis(1)));
+    let deadline = time::sleep(Duration::ZERO);
+    tokio::pin!(deadline);
+    let mut pending: BTreeSet<PathBuf> = BTreeSet::new();
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                let current = scan(project, patterns);
+                let events = diff_snapshots(&snapshot, &current);
+                for event in &events {
+                    if options.verbose {
+                        println!("  {:?}", event);
+                    }
+                    if let FileEvent::Deleted(path) = event {
+                        graph.remove(path);
+                    }
+                    pending.insert(event.path().clone());
+                }
+                if !events.is_empty() {
+                    // 새 변경이 들어올 때마다 debounce 타이머를 다시 맞춤
+                    deadline.as_mut().reset(Instant::now() + watch.debounce);
+                }
+                snapshot = current;
+            }
+            _ = &mut deadline, if !pending.is_empty() => {
+                let changed = std::mem::take(&mut pending);
+                let files: Vec<PathBuf> = graph
+                    .affected(&changed)
+                    .into_iter()
+                    .filter(|path| snapshot.contains_key(path))
+                    .collect();
+                println!("\nwatch: 변경 {}개, 다시 처리할 파일 {}개", changed.len(), files.len());
+                let written = run(options, files, &mut graph, &cancel).await;
+                refresh(&mut snapshot, &written);
+            }
+            _ = cancel.cancelled() => {
+                println!("\nwatch 종료");
+                break;
+            }
+        }
+    }
+}
+
+// 분석 → 키 할당 → 저장. 실제로 쓴 파일 목록 반환
+async fn run(options: &ProcessOptions, files: Vec<PathBuf>, graph: &mut ImportGraph, cancel: &CancelToken) -> Vec<PathBuf> {
+    if files.is_empty() {
+        return Vec::new();
+    }
+    // 캐시는 한 실행 동안 파일 해시를 기억하므로 실행마다 비운 사본을 씀
+    let cache = options.cache.as_ref().map(Cache::fresh);
+    let analyzed = pipeline::analyze_all(&files, options.jobs, cache, None, options.file_timeout, cancel.clone()).await;
+    if cancel.is_cancelled() {
+        return Vec::new();
+    }
+    for (path, result) in &analyzed {
+        if let Ok((_, analysis)) = result {
+            graph.set(path.clone(), cache::resolve_imports(path, &analysis.imports));
+        }
+    }
+
+    // 카탈로그는 매번 디스크에서 읽어서 새 키만 추가 (증분 갱신)
+    let mut report = process::plan_analyzed(options, analyzed);
+    if options.dry_run {
+        process::print_preview(&report, options);
+        return Vec::new();
+    }
+    let outcome = pipeline::write_all(&report.changes, options.jobs, None, cancel.clone()).await;
+    let written = report
+        .changes
+        .iter()
+        .filter(|change| !outcome.skipped.contains(&change.path))
+        .filter(|change| !outcome.failed.iter().any(|d| d.path == change.path))
+        .map(|change| glob::normalize(&change.path))
+        .collect();
+    report.errors.extend(outcome.failed);
+    report.cancelled = !outcome.skipped.is_empty();
+    report.skipped = outcome.skipped;
+    process::print_summary(&report);
+    written
+}
+
+// 우리가 쓴 파일은 다음 폴링에서 "변경"으로 잡히지 않도록 기록 갱신
+fn refresh(snapshot: &mut Snapshot, written: &[PathBuf]) {
+    for path in written {
+        if let Some(stamp) = snapshot.get_mut(path) {
+            if let Ok(metadata) = std::fs::metadata(path) {
+                if let Ok(modified) = metadata.modified() {
+                    *stamp = (modified, metadata.len());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_and_importers() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+        let before: Snapshot = [
+            (PathBuf::from("src/App.tsx"), (t0, 10)),
+            (PathBuf::from("src/Button.tsx"), (t0, 10)),
+            (PathBuf::from("src/Old.tsx"), (t0, 10)),
+        ]
+        .into_iter()
+        .collect();
+        let after: Snapshot = [
+            (PathBuf::from("src/App.tsx"), (t0, 10)),
+            (PathBuf::from("src/Button.tsx"), (t1, 12)),
+            (PathBuf::from("src/New.tsx"), (t1, 5)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            diff_snapshots(&before, &after),
+            vec![
+                FileEvent::Modified(PathBuf::from("src/Button.tsx")),
+                FileEvent::Created(PathBuf::from("src/New.tsx")),
+                FileEvent::Deleted(PathBuf::from("src/Old.tsx")),
+            ]
+        );
+
+        let mut graph = ImportGraph::default();
+        graph.set(PathBuf::from("src/App.tsx"), vec![PathBuf::from("src/Button.tsx")]);
+        let changed: BTreeSet<PathBuf> = [PathBuf::from("src/Button.tsx")].into_iter().collect();
+        let affected: Vec<PathBuf> = graph.affected(&changed).into_iter().collect();
+        assert_eq!(affected, vec![PathBuf::from("src/App.tsx"), PathBuf::from("src/Button.tsx")]);
+    }
+}